@@ -1,5 +1,5 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use fast_utc::Timestamp;
+use fast_utc::{Timestamp, TimestampFormatterPool};
 
 fn bench_now_chrono_fallback(c: &mut Criterion) {
     c.bench_function("Timestamp::now() (chrono fallback)", |b| {
@@ -9,6 +9,7 @@ fn bench_now_chrono_fallback(c: &mut Criterion) {
     });
 }
 
+#[allow(unused_must_use)]
 fn bench_chrono_now(c: &mut Criterion) {
     c.bench_function("chrono::Utc::now()", |b| {
         b.iter(|| {
@@ -17,5 +18,29 @@ fn bench_chrono_now(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_now_chrono_fallback, bench_chrono_now);
+fn bench_format_rfc3339(c: &mut Criterion) {
+    let ts = Timestamp::from_nanoseconds(1_714_570_200_123_456_789);
+    c.bench_function("Timestamp::format_rfc3339()", |b| {
+        let mut buf = [0; Timestamp::RFC3339_LEN];
+        b.iter(|| {
+            let _ = std::hint::black_box(ts).format_rfc3339(&mut buf).len();
+        })
+    });
+    c.bench_function("Timestamp::to_string()", |b| {
+        b.iter(|| {
+            let _ = std::hint::black_box(ts).to_string();
+        })
+    });
+    c.bench_function("TimestampFormatterPool::push()", |b| {
+        let mut pool = TimestampFormatterPool::new();
+        b.iter(|| {
+            if pool.len() == 10_000 {
+                pool.clear();
+            }
+            let _ = pool.push(std::hint::black_box(ts));
+        })
+    });
+}
+
+criterion_group!(benches, bench_now_chrono_fallback, bench_chrono_now, bench_format_rfc3339);
 criterion_main!(benches);
\ No newline at end of file
@@ -9,6 +9,7 @@ fn bench_now_coarsetime(c: &mut Criterion) {
     });
 }
 
+#[allow(unused_must_use)]
 fn bench_chrono_now(c: &mut Criterion) {
     c.bench_function("chrono::Utc::now()", |b| {
         b.iter(|| {
@@ -1,4 +1,4 @@
 #!/bin/bash
 
-cargo bench --bench timestamp_bench_chrono -- --warm-up-time 0.2 --measurement-time 0.5
-cargo bench --bench timestamp_bench_coarsetime -- --warm-up-time 0.2 --measurement-time 0.5
+cargo bench --bench timestamp_bench_chrono --features chrono-support -- --warm-up-time 0.2 --measurement-time 0.5
+cargo bench --bench timestamp_bench_coarsetime --features coarsetime-support,chrono-support -- --warm-up-time 0.2 --measurement-time 0.5
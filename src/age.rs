@@ -0,0 +1,122 @@
+//! Classifying timestamps by their age.
+
+use crate::{TimeDelta, Timestamp};
+
+/// Units used for bucket labels, largest first.
+const LABEL_UNITS: [(i64, &str); 8] = [
+    (7 * 24 * 3_600_000_000_000, "w"),
+    (24 * 3_600_000_000_000, "d"),
+    (3_600_000_000_000, "h"),
+    (60_000_000_000, "m"),
+    (1_000_000_000, "s"),
+    (1_000_000, "ms"),
+    (1_000, "us"),
+    (1, "ns"),
+];
+
+// ============================================================================================== //
+// [AgeBuckets]                                                                                   //
+// ============================================================================================== //
+
+/// Sorts ages into buckets separated by a list of upper bounds.
+///
+/// With bounds `[1m, 1h, 1d]` there are four buckets: `<1m`, `<1h`, `<1d` and `>=1d`.
+///
+/// ```
+/// use fast_utc::{AgeBuckets, TimeDelta, Timestamp};
+///
+/// let buckets = AgeBuckets::new(&[TimeDelta::from_minutes(1), TimeDelta::from_hours(1)]);
+/// let now = Timestamp::from_seconds(10_000);
+///
+/// let bucket = buckets.classify(Timestamp::from_seconds(9_000), now);
+/// assert_eq!(bucket, 1);
+/// assert_eq!(buckets.label(bucket), "<1h");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AgeBuckets {
+    bounds: Vec<TimeDelta>,
+}
+
+impl AgeBuckets {
+    /// Create buckets from their upper bounds, which are sorted and deduplicated.
+    pub fn new(bounds: &[TimeDelta]) -> Self {
+        let mut bounds = bounds.to_vec();
+        bounds.sort();
+        bounds.dedup();
+        AgeBuckets { bounds }
+    }
+
+    /// Number of buckets, including the final one for everything older than the last bound.
+    pub fn len(&self) -> usize {
+        self.bounds.len() + 1
+    }
+
+    /// Always `false`, there is at least the catch-all bucket.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Index of the bucket holding the age of `ts` at `now`. Timestamps from the future count
+    /// as age zero.
+    pub fn classify(&self, ts: Timestamp, now: Timestamp) -> usize {
+        self.classify_age(now - ts)
+    }
+
+    /// Index of the bucket holding `age`: the first bucket whose bound exceeds it.
+    pub fn classify_age(&self, age: TimeDelta) -> usize {
+        let age = age.max(TimeDelta::zero());
+        self.bounds.partition_point(|&bound| bound <= age)
+    }
+
+    /// Short label of a bucket, e.g. `<1m`, or `>=1d` for the last one.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is not less than [`len`](Self::len).
+    pub fn label(&self, index: usize) -> String {
+        assert!(index < self.len(), "bucket index out of range");
+        match (self.bounds.get(index), self.bounds.last()) {
+            (Some(&bound), _) => format!("<{}", short_label(bound)),
+            (None, Some(&last)) => format!(">={}", short_label(last)),
+            (None, None) => String::from("any"),
+        }
+    }
+}
+
+/// Render a delta in the largest unit that represents it exactly, e.g. `90s` or `2h`.
+fn short_label(delta: TimeDelta) -> String {
+    let nanos = delta.as_nanoseconds();
+    let &(unit, suffix) = LABEL_UNITS
+        .iter()
+        .find(|&&(unit, _)| nanos % unit == 0)
+        .expect("every value is a multiple of 1ns");
+    format!("{}{}", nanos / unit, suffix)
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn age_buckets() {
+        let buckets = AgeBuckets::new(&[
+            TimeDelta::from_hours(24),
+            TimeDelta::from_minutes(1),
+            TimeDelta::from_hours(1),
+        ]);
+        let now = Timestamp::from_seconds(1_000_000);
+        let age = |secs: i64| buckets.classify(now - TimeDelta::from_seconds(secs), now);
+
+        assert_eq!(buckets.len(), 4);
+        assert_eq!([age(-5), age(0), age(59), age(60), age(3_599), age(3_600), age(86_400)], [0, 0, 0, 1, 1, 2, 3]);
+
+        let labels: Vec<_> = (0..buckets.len()).map(|i| buckets.label(i)).collect();
+        assert_eq!(labels, ["<1m", "<1h", "<1d", ">=1d"]);
+        assert_eq!(AgeBuckets::new(&[TimeDelta::from_seconds(90)]).label(0), "<90s");
+        assert_eq!(AgeBuckets::new(&[]).label(0), "any");
+    }
+}
@@ -0,0 +1,83 @@
+//! Helpers for removing identifying detail from timestamps before sharing data.
+//!
+//! Which helper fits depends on what the recipient needs: [`coarsen`] hides the exact time
+//! behind a bucket, [`jitter`] adds independent noise to each timestamp, which destroys the
+//! exact spacing between events, and [`shift_series_preserving_deltas`] hides the absolute
+//! time while keeping the spacing intact. Keep in mind that exact spacing alone can be enough
+//! to re-identify a series if the original is known elsewhere.
+
+use crate::{TimeDelta, Timestamp};
+
+/// Floor `ts` to the start of its `freq` sized bucket, aligned to the epoch.
+#[inline]
+pub fn coarsen(ts: Timestamp, freq: TimeDelta) -> Timestamp {
+    ts.align_to(freq)
+}
+
+/// Move `ts` by a uniformly random offset in `[-max_delta, max_delta]`.
+///
+/// `rng` supplies uniformly distributed `u64`s, e.g. `|| rng.next_u64()` for a `rand` RNG.
+/// The result is clamped to the epoch like other arithmetic on [`Timestamp`].
+pub fn jitter(ts: Timestamp, max_delta: TimeDelta, rng: &mut impl FnMut() -> u64) -> Timestamp {
+    let max = max_delta.as_nanoseconds().unsigned_abs() as u128;
+    let span = 2 * max + 1;
+    // Multiply-shift maps the random bits onto `[0, span)`.
+    let offset = ((rng() as u128 * span) >> 64) as i128 - max as i128;
+    ts + TimeDelta::from_nanoseconds(offset as i64)
+}
+
+/// Shift all timestamps by the same amount so that the earliest one becomes `start`.
+///
+/// The differences between any two timestamps of the series are preserved exactly.
+pub fn shift_series_preserving_deltas(series: &mut [Timestamp], start: Timestamp) {
+    let Some(&earliest) = series.iter().min() else {
+        return;
+    };
+    let shift = start - earliest;
+    for ts in series {
+        *ts += shift;
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::anonymize::*;
+    use crate::*;
+
+    #[test]
+    fn coarsen_and_jitter() {
+        let ts = Timestamp::from_seconds(3_725);
+        assert_eq!(coarsen(ts, TimeDelta::from_hours(1)), Timestamp::from_seconds(3_600));
+
+        let max = TimeDelta::from_seconds(30);
+        assert_eq!(jitter(ts, max, &mut || 0), ts - max);
+        assert_eq!(jitter(ts, max, &mut || u64::MAX), ts + max);
+        assert_eq!(jitter(ts, max, &mut || 1 << 63), ts);
+
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        let mut xorshift = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        for _ in 0..1_000 {
+            let jittered = jitter(ts, max, &mut xorshift);
+            assert!(jittered.approx_eq(ts, max));
+        }
+    }
+
+    #[test]
+    fn shift_series() {
+        let secs = Timestamp::from_seconds;
+        let mut series = [secs(1_000), secs(990), secs(1_500)];
+
+        shift_series_preserving_deltas(&mut series, secs(10));
+        assert_eq!(series, [secs(20), secs(10), secs(520)]);
+        shift_series_preserving_deltas(&mut [], secs(10));
+    }
+}
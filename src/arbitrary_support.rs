@@ -0,0 +1,51 @@
+//! `arbitrary` implementations turning fuzzer input into timestamps and deltas.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{TimeDelta, Timestamp};
+
+/// Timestamps up to `2262-04-11`, the ones converting to `chrono::DateTime` and back.
+impl<'a> Arbitrary<'a> for Timestamp {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.int_in_range(0..=Timestamp::MAX_CHRONO.0).map(Timestamp)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u64::size_hint(depth)
+    }
+}
+
+/// Deltas of either sign without `i64::MIN`, so that negating them never overflows.
+impl<'a> Arbitrary<'a> for TimeDelta {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.int_in_range(-i64::MAX..=i64::MAX).map(TimeDelta)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        i64::size_hint(depth)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn arbitrary_from_bytes() {
+        let bytes = [0xff; 16];
+        let mut u = Unstructured::new(&bytes);
+        let ts = Timestamp::arbitrary(&mut u).unwrap();
+        assert!(ts.is_representable_as_chrono());
+        assert_ne!(TimeDelta::arbitrary(&mut u).unwrap(), TimeDelta(i64::MIN));
+
+        let mut empty = Unstructured::new(&[]);
+        assert_eq!(Timestamp::arbitrary(&mut empty).unwrap(), Timestamp::MIN);
+    }
+}
@@ -0,0 +1,76 @@
+//! Conversions between timestamps and Arrow arrays of nanosecond timestamps.
+
+use arrow_array::{Array, TimestampNanosecondArray};
+
+use crate::{TimeError, Timestamp};
+
+/// Convert to signed nanoseconds, failing past `2262-04-11` where `i64` ends.
+#[inline]
+fn to_nanos(ts: Timestamp) -> Result<i64, TimeError> {
+    i64::try_from(ts.0).map_err(|_| TimeError::Overflow)
+}
+
+impl Timestamp {
+    /// Copy timestamps into an array without nulls in the `UTC` time zone.
+    ///
+    /// Fails with [`TimeError::Overflow`] for timestamps past `2262-04-11`, the end of Arrow's
+    /// `i64` range.
+    pub fn to_arrow_array(timestamps: &[Timestamp]) -> Result<TimestampNanosecondArray, TimeError> {
+        let nanos: Vec<i64> = timestamps.iter().map(|&ts| to_nanos(ts)).collect::<Result<_, _>>()?;
+        Ok(TimestampNanosecondArray::from(nanos).with_timezone_utc())
+    }
+
+    /// Like [`to_arrow_array`](Self::to_arrow_array), but consuming the vector, whose allocation
+    /// the standard library can then reuse for the array's buffer instead of copying.
+    pub fn into_arrow_array(timestamps: Vec<Timestamp>) -> Result<TimestampNanosecondArray, TimeError> {
+        let nanos: Vec<i64> = timestamps.into_iter().map(to_nanos).collect::<Result<_, _>>()?;
+        Ok(TimestampNanosecondArray::from(nanos).with_timezone_utc())
+    }
+
+    /// Copy the values of an array, reading arrays in any time zone or none at all as UTC.
+    ///
+    /// Fails with [`TimeError::BeforeEpoch`] for negative values and with
+    /// [`TimeError::InvalidFormat`] if the array contains nulls.
+    pub fn from_arrow_array(array: &TimestampNanosecondArray) -> Result<Vec<Timestamp>, TimeError> {
+        if array.null_count() != 0 {
+            return Err(TimeError::InvalidFormat);
+        }
+        let values = array.values().iter();
+        values.map(|&nanos| u64::try_from(nanos).map(Timestamp).map_err(|_| TimeError::BeforeEpoch)).collect()
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use arrow_array::TimestampNanosecondArray;
+
+    #[test]
+    fn arrow_round_trip() {
+        let timestamps: Vec<_> = (0..100).map(|i| Timestamp::from_seconds(1_714_570_200 + i)).collect();
+        let array = Timestamp::to_arrow_array(&timestamps).unwrap();
+        assert_eq!(array.len(), 100);
+        assert_eq!(array.timezone(), Some("+00:00"));
+        assert_eq!(array.value(1), 1_714_570_201_000_000_000);
+        assert_eq!(Timestamp::from_arrow_array(&array), Ok(timestamps.clone()));
+
+        let owned = Timestamp::into_arrow_array(timestamps.clone()).unwrap();
+        assert_eq!(owned, array);
+        assert_eq!(Timestamp::from_arrow_array(&owned), Ok(timestamps));
+    }
+
+    #[test]
+    fn arrow_out_of_range() {
+        let timestamps = [Timestamp::zero(), Timestamp::MAX];
+        assert_eq!(Timestamp::to_arrow_array(&timestamps), Err(TimeError::Overflow));
+
+        let array = TimestampNanosecondArray::from(vec![1, -1]);
+        assert_eq!(Timestamp::from_arrow_array(&array), Err(TimeError::BeforeEpoch));
+        let array = TimestampNanosecondArray::from(vec![Some(1), None]);
+        assert_eq!(Timestamp::from_arrow_array(&array), Err(TimeError::InvalidFormat));
+    }
+}
@@ -0,0 +1,61 @@
+//! Lock-free shared timestamps.
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::Timestamp;
+
+// ============================================================================================== //
+// [AtomicTimestamp]                                                                              //
+// ============================================================================================== //
+
+/// A [`Timestamp`] that can be shared between threads, backed by an [`AtomicU64`].
+#[repr(transparent)]
+#[derive(Debug, Default)]
+pub struct AtomicTimestamp(AtomicU64);
+
+impl AtomicTimestamp {
+    /// Create a new atomic timestamp.
+    #[inline]
+    pub const fn new(ts: Timestamp) -> Self {
+        AtomicTimestamp(AtomicU64::new(ts.0))
+    }
+
+    /// Load the current value.
+    #[inline]
+    pub fn load(&self, order: Ordering) -> Timestamp {
+        Timestamp(self.0.load(order))
+    }
+
+    /// Store a new value.
+    #[inline]
+    pub fn store(&self, ts: Timestamp, order: Ordering) {
+        self.0.store(ts.0, order)
+    }
+
+    /// Store a new value, returning the previous one.
+    #[inline]
+    pub fn swap(&self, ts: Timestamp, order: Ordering) -> Timestamp {
+        Timestamp(self.0.swap(ts.0, order))
+    }
+
+    /// Store the later of the current value and `ts`, returning the previous value.
+    ///
+    /// Lets concurrent writers record the latest event without ever moving backwards.
+    #[inline]
+    pub fn fetch_max(&self, ts: Timestamp, order: Ordering) -> Timestamp {
+        Timestamp(self.0.fetch_max(ts.0, order))
+    }
+
+    /// Consume the atomic and return the contained value.
+    #[inline]
+    pub fn into_inner(self) -> Timestamp {
+        Timestamp(self.0.into_inner())
+    }
+}
+
+impl From<Timestamp> for AtomicTimestamp {
+    #[inline]
+    fn from(ts: Timestamp) -> Self {
+        Self::new(ts)
+    }
+}
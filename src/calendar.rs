@@ -0,0 +1,405 @@
+//! Durations measured in calendar units, whose length depends on where they are applied.
+
+use core::{fmt, ops, str::FromStr};
+
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+use crate::civil::{days_from_civil, days_in_month, NANOS_PER_DAY};
+use crate::{TimeDelta, TimeError, Timestamp};
+
+// ============================================================================================== //
+// [CalendarDelta]                                                                                //
+// ============================================================================================== //
+
+/// A duration of months, days and nanoseconds, like a Postgres `interval`.
+///
+/// Unlike [`TimeDelta`], a month isn't a fixed number of nanoseconds: adding `P1M` to January
+/// 31st yields the last day of February. When added to a timestamp, months are applied first,
+/// clamping the day to the length of the resulting month, then days, then nanoseconds. Equality
+/// compares the components, so `P1M` and `P30D` differ even where they'd end up at the same time.
+///
+/// ```
+/// use fast_utc::{CalendarDelta, Timestamp};
+///
+/// let jan_31 = Timestamp::from_epoch_days(19_753); // 2024-01-31
+/// let delta: CalendarDelta = "P1M".parse().unwrap();
+/// assert_eq!((jan_31 + delta).to_epoch_days(), 19_782); // 2024-02-29
+/// assert_eq!(delta.to_string(), "P1M");
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+pub struct CalendarDelta {
+    pub months: i32,
+    pub days: i32,
+    pub nanos: i64,
+}
+
+impl CalendarDelta {
+    /// Create a delta from its components.
+    #[inline]
+    pub const fn new(months: i32, days: i32, nanos: i64) -> Self {
+        CalendarDelta { months, days, nanos }
+    }
+
+    /// Initialize an empty delta.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::new(0, 0, 0)
+    }
+
+    #[inline]
+    pub const fn from_months(months: i32) -> Self {
+        Self::new(months, 0, 0)
+    }
+
+    #[inline]
+    pub const fn from_days(days: i32) -> Self {
+        Self::new(0, days, 0)
+    }
+
+    /// Check whether all components are zero.
+    #[inline]
+    pub const fn is_zero(self) -> bool {
+        self.months == 0 && self.days == 0 && self.nanos == 0
+    }
+}
+
+/// A fixed duration, kept entirely in the nanosecond component.
+impl From<TimeDelta> for CalendarDelta {
+    fn from(delta: TimeDelta) -> Self {
+        Self::new(0, 0, delta.0)
+    }
+}
+
+impl ops::Neg for CalendarDelta {
+    type Output = CalendarDelta;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.months, -self.days, -self.nanos)
+    }
+}
+
+// ============================================================================================== //
+// [Timestamp arithmetic]                                                                         //
+// ============================================================================================== //
+
+impl Timestamp {
+    /// Advance by a calendar delta, `None` if the result isn't representable.
+    pub fn checked_add_calendar(self, delta: CalendarDelta) -> Option<Timestamp> {
+        u64::try_from(self.calendar_shifted(delta, 1)).ok().map(Timestamp)
+    }
+
+    /// Go back by a calendar delta, `None` if the result isn't representable.
+    pub fn checked_sub_calendar(self, delta: CalendarDelta) -> Option<Timestamp> {
+        u64::try_from(self.calendar_shifted(delta, -1)).ok().map(Timestamp)
+    }
+
+    /// Nanoseconds since the epoch after applying `sign * delta`, possibly out of range.
+    fn calendar_shifted(self, delta: CalendarDelta, sign: i64) -> i128 {
+        let (year, month, day) = self.civil_date();
+        let month_index = year * 12 + (month as i64 - 1) + sign * delta.months as i64;
+        let (year, month) = (month_index.div_euclid(12), month_index.rem_euclid(12) as u32 + 1);
+        let day = day.min(days_in_month(year, month));
+
+        let days = days_from_civil(year, month, day) + sign * delta.days as i64;
+        let nanos = sign as i128 * delta.nanos as i128;
+        days as i128 * NANOS_PER_DAY as i128 + self.nanos_of_day() as i128 + nanos
+    }
+}
+
+/// Advance by a calendar delta, clamping to the representable range.
+impl ops::Add<CalendarDelta> for Timestamp {
+    type Output = Timestamp;
+
+    fn add(self, rhs: CalendarDelta) -> Self::Output {
+        Timestamp(self.calendar_shifted(rhs, 1).clamp(0, u64::MAX as i128) as u64)
+    }
+}
+
+/// Go back by a calendar delta, clamping to the representable range.
+impl ops::Sub<CalendarDelta> for Timestamp {
+    type Output = Timestamp;
+
+    fn sub(self, rhs: CalendarDelta) -> Self::Output {
+        Timestamp(self.calendar_shifted(rhs, -1).clamp(0, u64::MAX as i128) as u64)
+    }
+}
+
+// ============================================================================================== //
+// [ISO 8601]                                                                                     //
+// ============================================================================================== //
+
+const NANOS_PER_SEC: i64 = 1_000_000_000;
+
+/// Display as an ISO 8601 duration, e.g. `P1Y2M3DT4H5M6.5S`. Components are signed individually.
+impl fmt::Display for CalendarDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("PT0S");
+        }
+
+        f.write_str("P")?;
+        let (years, months) = (self.months / 12, self.months % 12);
+        if years != 0 {
+            write!(f, "{}Y", years)?;
+        }
+        if months != 0 {
+            write!(f, "{}M", months)?;
+        }
+        if self.days != 0 {
+            write!(f, "{}D", self.days)?;
+        }
+        if self.nanos == 0 {
+            return Ok(());
+        }
+
+        f.write_str("T")?;
+        let hours = self.nanos / (3_600 * NANOS_PER_SEC);
+        let minutes = self.nanos / (60 * NANOS_PER_SEC) % 60;
+        let secs = self.nanos % (60 * NANOS_PER_SEC);
+        if hours != 0 {
+            write!(f, "{}H", hours)?;
+        }
+        if minutes != 0 {
+            write!(f, "{}M", minutes)?;
+        }
+        if secs != 0 {
+            let sign = if secs < 0 { "-" } else { "" };
+            let secs = secs.unsigned_abs();
+            write!(f, "{}{}", sign, secs / NANOS_PER_SEC as u64)?;
+
+            let (mut frac, mut width) = (secs % NANOS_PER_SEC as u64, 9);
+            if frac != 0 {
+                while frac % 10 == 0 {
+                    frac /= 10;
+                    width -= 1;
+                }
+                write!(f, ".{:0width$}", frac, width = width)?;
+            }
+            f.write_str("S")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse an ISO 8601 duration such as `P1M`, `P2W`, `PT1.5S` or `-P1Y2M10DT2H30M`.
+///
+/// Years count as 12 months and weeks as 7 days. Components may carry their own sign and only
+/// seconds may have a fraction, which is truncated to nanoseconds.
+impl FromStr for CalendarDelta {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negate, rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let rest = rest.strip_prefix('P').ok_or(TimeError::InvalidFormat)?;
+        let (date, time) = match rest.split_once('T') {
+            Some((date, time)) if !time.is_empty() => (date, time),
+            Some(_) => return Err(TimeError::InvalidFormat),
+            None if !rest.is_empty() => (rest, ""),
+            None => return Err(TimeError::InvalidFormat),
+        };
+
+        let (mut months, mut days, mut nanos) = (0i64, 0i64, 0i64);
+        for_each_component(date, b"YMWD", |unit, value, frac| {
+            if frac.is_some() {
+                return Err(TimeError::InvalidFormat);
+            }
+            let (total, factor) = match unit {
+                b'Y' => (&mut months, 12),
+                b'M' => (&mut months, 1),
+                b'W' => (&mut days, 7),
+                _ => (&mut days, 1),
+            };
+            accumulate(total, value, factor)
+        })?;
+        for_each_component(time, b"HMS", |unit, value, frac| {
+            match (unit, frac) {
+                (b'S', Some(frac)) => {
+                    accumulate(&mut nanos, value, NANOS_PER_SEC)?;
+                    let sign = if frac.negative { -1 } else { 1 };
+                    accumulate(&mut nanos, frac.nanos, sign)
+                }
+                (_, Some(_)) => Err(TimeError::InvalidFormat),
+                (b'H', None) => accumulate(&mut nanos, value, 3_600 * NANOS_PER_SEC),
+                (b'M', None) => accumulate(&mut nanos, value, 60 * NANOS_PER_SEC),
+                (_, None) => accumulate(&mut nanos, value, NANOS_PER_SEC),
+            }
+        })?;
+
+        let delta = CalendarDelta::new(
+            i32::try_from(months).map_err(|_| TimeError::Overflow)?,
+            i32::try_from(days).map_err(|_| TimeError::Overflow)?,
+            nanos,
+        );
+        match negate {
+            true if delta.months == i32::MIN || delta.days == i32::MIN || delta.nanos == i64::MIN => {
+                Err(TimeError::Overflow)
+            }
+            true => Ok(-delta),
+            false => Ok(delta),
+        }
+    }
+}
+
+impl TimeDelta {
+    /// Parse an ISO 8601 duration with fixed length components, e.g. `PT1H30M` or `P1DT0.5S`.
+    ///
+    /// Days count as 24 hours and weeks as 7 days. Years and months have no fixed length and
+    /// are rejected unless zero, use [`CalendarDelta`] for those.
+    pub fn parse_iso8601(s: &str) -> Result<TimeDelta, TimeError> {
+        let delta: CalendarDelta = s.parse()?;
+        if delta.months != 0 {
+            return Err(TimeError::InvalidFormat);
+        }
+        (delta.days as i64)
+            .checked_mul(NANOS_PER_DAY as i64)
+            .and_then(|days| days.checked_add(delta.nanos))
+            .map(TimeDelta)
+            .ok_or(TimeError::Overflow)
+    }
+
+    /// Format as an ISO 8601 duration, e.g. `PT1H30M`.
+    ///
+    /// Hours aren't folded into days, so a day reads `PT24H`.
+    #[cfg(feature = "std")]
+    pub fn to_iso8601(self) -> String {
+        CalendarDelta::from(self).to_string()
+    }
+}
+
+/// Fractional part of a component.
+#[derive(Copy, Clone)]
+struct Fraction {
+    nanos: i64,
+    /// Whether the component had a minus sign, needed for `-0.5S`.
+    negative: bool,
+}
+
+/// Walk the `<number><unit>` components of `s`, whose units must appear in the order of `units`.
+fn for_each_component(
+    mut s: &str,
+    units: &[u8],
+    mut f: impl FnMut(u8, i64, Option<Fraction>) -> Result<(), TimeError>,
+) -> Result<(), TimeError> {
+    let mut allowed = units;
+    while !s.is_empty() {
+        let end = s.find(|c: char| c.is_ascii_alphabetic()).ok_or(TimeError::InvalidFormat)?;
+        let (number, unit) = (&s[..end], s.as_bytes()[end]);
+        let pos = allowed.iter().position(|&u| u == unit).ok_or(TimeError::InvalidFormat)?;
+        allowed = &allowed[pos + 1..];
+        s = &s[end + 1..];
+
+        let negative = number.starts_with('-');
+        let (int, frac) = match number.split_once(['.', ',']) {
+            Some((int, frac)) => (int, Some(frac)),
+            None => (number, None),
+        };
+        let digits = int.strip_prefix('-').unwrap_or(int);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(TimeError::InvalidFormat);
+        }
+        let value = int.parse::<i64>().map_err(|_| TimeError::Overflow)?;
+
+        let frac = match frac {
+            Some(frac) if !frac.is_empty() && frac.bytes().all(|b| b.is_ascii_digit()) => {
+                // Pad or truncate to exactly nine digits.
+                let digits = frac.bytes().chain(core::iter::repeat(b'0')).take(9);
+                let nanos = digits.fold(0, |n, b| n * 10 + (b - b'0') as i64);
+                Some(Fraction { nanos, negative })
+            }
+            Some(_) => return Err(TimeError::InvalidFormat),
+            None => None,
+        };
+        f(unit, value, frac)?;
+    }
+    Ok(())
+}
+
+fn accumulate(total: &mut i64, value: i64, factor: i64) -> Result<(), TimeError> {
+    *total = value.checked_mul(factor).and_then(|v| total.checked_add(v)).ok_or(TimeError::Overflow)?;
+    Ok(())
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn date(year: i32, month: u32, day: u32) -> Timestamp {
+        let days = super::days_from_civil(year as i64, month, day) as u64;
+        Timestamp::from_seconds(days * 86_400 + 12 * 3_600)
+    }
+
+    #[test]
+    fn calendar_addition() {
+        let month = CalendarDelta::from_months(1);
+        assert_eq!(date(2024, 1, 31) + month, date(2024, 2, 29));
+        assert_eq!(date(2023, 1, 31) + month, date(2023, 2, 28));
+        assert_eq!(date(2024, 3, 31) - month, date(2024, 2, 29));
+        assert_eq!(date(2024, 12, 15) + month, date(2025, 1, 15));
+        assert_eq!(date(2024, 1, 15) - CalendarDelta::from_months(13), date(2022, 12, 15));
+
+        // Months before days: Jan 31 + 1 month = Feb 29, + 1 day = Mar 1.
+        let delta = CalendarDelta::new(1, 1, TimeDelta::from_hours(-12).0);
+        assert_eq!(date(2024, 1, 31) + delta, date(2024, 3, 1) - TimeDelta::from_hours(12));
+
+        assert_eq!(Timestamp::zero().checked_sub_calendar(CalendarDelta::from_days(1)), None);
+        assert_eq!(Timestamp::zero() - CalendarDelta::from_days(1), Timestamp::zero());
+        assert_eq!(Timestamp::MAX.checked_add_calendar(CalendarDelta::from_months(1)), None);
+
+        let most_negative = CalendarDelta::new(i32::MIN, i32::MIN, i64::MIN);
+        assert_eq!(Timestamp::MAX.checked_sub_calendar(most_negative), None);
+        assert_eq!(Timestamp::MAX - most_negative, Timestamp::MAX);
+        assert_eq!(Timestamp::zero() + most_negative, Timestamp::zero());
+    }
+
+    #[test]
+    fn iso8601_round_trip() {
+        let cases = [
+            ("P1M", CalendarDelta::from_months(1)),
+            ("P1Y2M10DT2H30M", CalendarDelta::new(14, 10, TimeDelta::from_minutes(150).0)),
+            ("PT0.5S", CalendarDelta::new(0, 0, 500_000_000)),
+            ("P-1M-3D", CalendarDelta::new(-1, -3, 0)),
+            ("PT-0.000000001S", CalendarDelta::new(0, 0, -1)),
+            ("PT0S", CalendarDelta::zero()),
+        ];
+        for (s, delta) in cases {
+            assert_eq!(s.parse(), Ok(delta), "{}", s);
+            assert_eq!(delta.to_string(), s);
+        }
+
+        assert_eq!("-P1Y2W".parse(), Ok(CalendarDelta::new(-12, -14, 0)));
+        assert_eq!("PT1,25S".parse(), Ok(CalendarDelta::new(0, 0, 1_250_000_000)));
+        assert_eq!("PT90M".parse::<CalendarDelta>().unwrap().to_string(), "PT1H30M");
+        let invalid = ["", "P", "PT", "1M", "P1", "P1S", "P1M1Y", "P1.5M", "PT1.S", "PT1H1H", "P-M", "PT1.5H"];
+        for invalid in invalid {
+            assert_eq!(invalid.parse::<CalendarDelta>(), Err(TimeError::InvalidFormat), "{}", invalid);
+        }
+        assert_eq!("P999999999999Y".parse::<CalendarDelta>(), Err(TimeError::Overflow));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn time_delta_iso8601() {
+        assert_eq!(TimeDelta::parse_iso8601("PT1H30M"), Ok(TimeDelta::from_minutes(90)));
+        let expected = TimeDelta::from_hours(192) + TimeDelta::from_milliseconds(250);
+        assert_eq!(TimeDelta::parse_iso8601("P1W1DT0.25S"), Ok(expected));
+        assert_eq!(TimeDelta::parse_iso8601("-P0Y1D"), Ok(TimeDelta::from_hours(-24)));
+        assert_eq!(TimeDelta::parse_iso8601("P1M"), Err(TimeError::InvalidFormat));
+        assert_eq!(TimeDelta::parse_iso8601("P200000DT1S"), Err(TimeError::Overflow));
+
+        assert_eq!(TimeDelta::from_minutes(90).to_iso8601(), "PT1H30M");
+        assert_eq!(TimeDelta::from_hours(-36).to_iso8601(), "PT-36H");
+        let deltas = [TimeDelta::zero(), TimeDelta::from_nanoseconds(-1_500), TimeDelta::from_hours(1_000_000)];
+        for delta in deltas {
+            assert_eq!(TimeDelta::parse_iso8601(&delta.to_iso8601()), Ok(delta));
+        }
+    }
+}
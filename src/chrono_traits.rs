@@ -0,0 +1,260 @@
+//! chrono's calendar traits and naive types, implemented on top of the native civil decomposition.
+
+use chrono::{Datelike, IsoWeek, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+
+use crate::civil::{civil_from_days, days_from_civil, days_in_month, is_leap_year, NANOS_PER_DAY};
+use crate::{TimeOfDay, Timestamp};
+
+// ============================================================================================== //
+// [Datelike]                                                                                     //
+// ============================================================================================== //
+
+impl Timestamp {
+    /// Same time of day on another date, `None` if the date is invalid or unrepresentable.
+    fn with_civil_date(self, year: i64, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Timestamp::from_day_and_nanos(days_from_civil(year, month, day), self.nanos_of_day())
+    }
+}
+
+/// Calendar date of the timestamp in UTC.
+impl Datelike for Timestamp {
+    #[inline]
+    fn year(&self) -> i32 {
+        // Representable timestamps end in the year 2554.
+        self.civil_date().0 as i32
+    }
+
+    #[inline]
+    fn month(&self) -> u32 {
+        self.civil_date().1
+    }
+
+    #[inline]
+    fn month0(&self) -> u32 {
+        self.month() - 1
+    }
+
+    #[inline]
+    fn day(&self) -> u32 {
+        self.civil_date().2
+    }
+
+    #[inline]
+    fn day0(&self) -> u32 {
+        self.day() - 1
+    }
+
+    fn ordinal(&self) -> u32 {
+        let days = (self.0 / NANOS_PER_DAY) as i64;
+        let (year, _, _) = civil_from_days(days);
+        (days - days_from_civil(year, 1, 1)) as u32 + 1
+    }
+
+    #[inline]
+    fn ordinal0(&self) -> u32 {
+        self.ordinal() - 1
+    }
+
+    fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday, the fourth day counting from Monday.
+        let days_from_monday = ((self.0 / NANOS_PER_DAY + 3) % 7) as u8;
+        Weekday::try_from(days_from_monday).expect("value is less than 7")
+    }
+
+    fn iso_week(&self) -> IsoWeek {
+        // `IsoWeek` can't be constructed outside of chrono.
+        let (year, month, day) = self.civil_date();
+        chrono::NaiveDate::from_ymd_opt(year as i32, month, day)
+            .expect("civil dates of timestamps are valid")
+            .iso_week()
+    }
+
+    fn with_year(&self, year: i32) -> Option<Self> {
+        let (_, month, day) = self.civil_date();
+        self.with_civil_date(year as i64, month, day)
+    }
+
+    fn with_month(&self, month: u32) -> Option<Self> {
+        let (year, _, day) = self.civil_date();
+        self.with_civil_date(year, month, day)
+    }
+
+    fn with_month0(&self, month0: u32) -> Option<Self> {
+        self.with_month(month0.checked_add(1)?)
+    }
+
+    fn with_day(&self, day: u32) -> Option<Self> {
+        let (year, month, _) = self.civil_date();
+        self.with_civil_date(year, month, day)
+    }
+
+    fn with_day0(&self, day0: u32) -> Option<Self> {
+        self.with_day(day0.checked_add(1)?)
+    }
+
+    fn with_ordinal(&self, ordinal: u32) -> Option<Self> {
+        let (year, _, _) = self.civil_date();
+        let days_in_year = if is_leap_year(year) { 366 } else { 365 };
+        if ordinal == 0 || ordinal > days_in_year {
+            return None;
+        }
+        let days = days_from_civil(year, 1, 1) + ordinal as i64 - 1;
+        Timestamp::from_day_and_nanos(days, self.nanos_of_day())
+    }
+
+    fn with_ordinal0(&self, ordinal0: u32) -> Option<Self> {
+        self.with_ordinal(ordinal0.checked_add(1)?)
+    }
+}
+
+// ============================================================================================== //
+// [Timelike]                                                                                     //
+// ============================================================================================== //
+
+/// Wall clock time of the timestamp in UTC. Leap seconds are not represented.
+impl Timelike for Timestamp {
+    #[inline]
+    fn hour(&self) -> u32 {
+        self.time_of_day().hour()
+    }
+
+    #[inline]
+    fn minute(&self) -> u32 {
+        self.time_of_day().minute()
+    }
+
+    #[inline]
+    fn second(&self) -> u32 {
+        self.time_of_day().second()
+    }
+
+    #[inline]
+    fn nanosecond(&self) -> u32 {
+        self.time_of_day().nanosecond()
+    }
+
+    fn with_hour(&self, hour: u32) -> Option<Self> {
+        let t = self.time_of_day();
+        let time = TimeOfDay::from_hms_nano(hour, t.minute(), t.second(), t.nanosecond())?;
+        Some(self.with_time_of_day(time))
+    }
+
+    fn with_minute(&self, minute: u32) -> Option<Self> {
+        let t = self.time_of_day();
+        let time = TimeOfDay::from_hms_nano(t.hour(), minute, t.second(), t.nanosecond())?;
+        Some(self.with_time_of_day(time))
+    }
+
+    fn with_second(&self, second: u32) -> Option<Self> {
+        let t = self.time_of_day();
+        let time = TimeOfDay::from_hms_nano(t.hour(), t.minute(), second, t.nanosecond())?;
+        Some(self.with_time_of_day(time))
+    }
+
+    fn with_nanosecond(&self, nano: u32) -> Option<Self> {
+        let t = self.time_of_day();
+        let time = TimeOfDay::from_hms_nano(t.hour(), t.minute(), t.second(), nano)?;
+        Some(self.with_time_of_day(time))
+    }
+}
+
+// ============================================================================================== //
+// [Naive date times]                                                                             //
+// ============================================================================================== //
+
+impl Timestamp {
+    /// Interpret a naive date time as UTC. Clamps like the `From<DateTime>` conversion.
+    #[track_caller]
+    pub fn from_naive_utc(naive: NaiveDateTime) -> Self {
+        naive.and_utc().into()
+    }
+
+    /// UTC date and time of the timestamp without time zone.
+    ///
+    /// Unlike the conversion to `chrono::DateTime`, this covers timestamps past `2262-04-11` too.
+    pub fn to_naive_utc(self) -> NaiveDateTime {
+        let (secs, nanos) = (self.seconds_of_day(), (self.0 % 1_000_000_000) as u32);
+        let time = NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos).expect("valid time of day");
+        self.to_naive_date().and_time(time)
+    }
+
+    /// UTC date of the timestamp.
+    pub fn to_naive_date(self) -> NaiveDate {
+        let (year, month, day) = self.civil_date();
+        NaiveDate::from_ymd_opt(year as i32, month, day).expect("civil dates of timestamps are valid")
+    }
+}
+
+/// Midnight UTC of the date. Clamps like the `From<DateTime>` conversion.
+impl From<NaiveDate> for Timestamp {
+    #[track_caller]
+    fn from(date: NaiveDate) -> Self {
+        Timestamp::from_naive_utc(date.and_time(NaiveTime::MIN))
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use chrono::{offset::TimeZone, Datelike, Timelike, Utc};
+
+    #[test]
+    fn datelike_timelike_vs_chrono() {
+        let start = Utc.with_ymd_and_hms(1970, 1, 1, 0, 0, 0).unwrap();
+        for hours in (0..2_000_000).step_by(3_917) {
+            let dt = start + chrono::Duration::hours(hours) + chrono::Duration::nanoseconds(hours * 13);
+            let ts = Timestamp::from(dt);
+
+            assert_eq!(
+                (ts.year(), ts.month(), ts.day(), ts.ordinal(), ts.weekday(), ts.iso_week()),
+                (dt.year(), dt.month(), dt.day(), dt.ordinal(), dt.weekday(), dt.iso_week()),
+            );
+            assert_eq!(
+                (ts.hour(), ts.minute(), ts.second(), ts.nanosecond()),
+                (dt.hour(), dt.minute(), dt.second(), dt.nanosecond()),
+            );
+        }
+    }
+
+    #[test]
+    fn datelike_with() {
+        let ts: Timestamp = Utc.with_ymd_and_hms(2024, 2, 29, 13, 45, 10).unwrap().into();
+
+        assert_eq!(ts.with_year(2028), Some(Utc.with_ymd_and_hms(2028, 2, 29, 13, 45, 10).unwrap().into()));
+        assert_eq!(ts.with_year(2023), None);
+        assert_eq!(ts.with_year(1969), None);
+        assert_eq!(ts.with_month(3), Some(Utc.with_ymd_and_hms(2024, 3, 29, 13, 45, 10).unwrap().into()));
+        assert_eq!(ts.with_month(13), None);
+        assert_eq!(ts.with_day0(0), Some(Utc.with_ymd_and_hms(2024, 2, 1, 13, 45, 10).unwrap().into()));
+        assert_eq!(ts.with_ordinal(366), Some(Utc.with_ymd_and_hms(2024, 12, 31, 13, 45, 10).unwrap().into()));
+        assert_eq!(ts.with_ordinal(367), None);
+        assert_eq!(ts.with_hour(0), Some(Utc.with_ymd_and_hms(2024, 2, 29, 0, 45, 10).unwrap().into()));
+        assert_eq!(ts.with_nanosecond(1_000_000_000), None);
+    }
+
+    #[test]
+    fn naive_conversions() {
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let naive = date.and_hms_milli_opt(13, 30, 0, 500).unwrap();
+        let ts = Timestamp::from_milliseconds(1_714_570_200_500);
+
+        assert_eq!(Timestamp::from_naive_utc(naive), ts);
+        assert_eq!(ts.to_naive_utc(), naive);
+        assert_eq!(ts.to_naive_date(), date);
+        assert_eq!(Timestamp::from(date), Timestamp::from_seconds(1_714_521_600));
+        assert_eq!(Timestamp::MAX.to_naive_date(), chrono::NaiveDate::from_ymd_opt(2554, 7, 21).unwrap());
+
+        let max = chrono::NaiveDate::from_ymd_opt(2554, 7, 21).unwrap();
+        assert_eq!(Timestamp::MAX.to_naive_utc(), max.and_hms_nano_opt(23, 34, 33, 709_551_615).unwrap());
+        let past_chrono = Timestamp::from_seconds(10_000_000_000);
+        assert_eq!(past_chrono.to_naive_utc().date(), past_chrono.to_naive_date());
+        assert_eq!(past_chrono.to_naive_utc().and_utc().timestamp(), 10_000_000_000);
+    }
+}
@@ -0,0 +1,333 @@
+//! Calendar related helpers operating directly on the nanosecond representation.
+
+use core::fmt;
+
+#[cfg(feature = "serde-support")]
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+use crate::Timestamp;
+
+/// Nanoseconds in a UTC day (leap seconds don't exist in Unix time).
+pub(crate) const NANOS_PER_DAY: u64 = 24 * 60 * 60 * 1_000_000_000;
+
+/// Largest day number that still fits the `u64` nanosecond representation.
+const MAX_EPOCH_DAY: u64 = u64::MAX / NANOS_PER_DAY;
+
+/// Convert days since `1970-01-01` to a proleptic Gregorian `(year, month, day)`.
+///
+/// Howard Hinnant's `civil_from_days`, valid for the whole `i64` day range we can produce.
+pub(crate) const fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097) as u64;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe as i64 + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Convert a proleptic Gregorian date to days since `1970-01-01`. Inverse of [`civil_from_days`].
+pub(crate) const fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400) as u64;
+    let mp = if month > 2 { month - 3 } else { month + 9 } as u64;
+    let doy = (153 * mp + 2) / 5 + day as u64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe as i64 - 719_468
+}
+
+/// Check whether `year` has a February 29th.
+pub(crate) const fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Number of days in the given month, `month` being in `1..=12`.
+pub(crate) const fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl Timestamp {
+    /// UTC `(year, month, day)` of the timestamp.
+    #[inline]
+    pub(crate) const fn civil_date(self) -> (i64, u32, u32) {
+        civil_from_days((self.0 / NANOS_PER_DAY) as i64)
+    }
+
+    /// Assemble a timestamp from a day since the epoch and the nanoseconds within that day.
+    ///
+    /// `None` if the result isn't representable.
+    #[cfg(feature = "std")]
+    pub(crate) const fn from_day_and_nanos(days: i64, nanos_of_day: u64) -> Option<Self> {
+        if days < 0 || days as u64 > MAX_EPOCH_DAY {
+            return None;
+        }
+        match (days as u64 * NANOS_PER_DAY).checked_add(nanos_of_day) {
+            Some(nanos) => Some(Timestamp(nanos)),
+            None => None,
+        }
+    }
+}
+
+// ============================================================================================== //
+// [Epoch days]                                                                                   //
+// ============================================================================================== //
+
+impl Timestamp {
+    /// Number of whole days since `1970-01-01`, as used by Arrow `Date32` and Parquet `DATE`.
+    #[inline]
+    pub const fn to_epoch_days(self) -> i32 {
+        // At most `MAX_EPOCH_DAY` (213_503), so the cast is lossless.
+        (self.0 / NANOS_PER_DAY) as i32
+    }
+
+    /// Initialize a timestamp at midnight UTC of the given day since `1970-01-01`.
+    ///
+    /// Days before the epoch clamp to 0, days past the representable range saturate.
+    #[inline]
+    pub const fn from_epoch_days(days: i32) -> Self {
+        if days < 0 {
+            Timestamp(0)
+        } else if days as u64 > MAX_EPOCH_DAY {
+            Timestamp(u64::MAX)
+        } else {
+            Timestamp(days as u64 * NANOS_PER_DAY)
+        }
+    }
+}
+
+// ============================================================================================== //
+// [Time of day]                                                                                  //
+// ============================================================================================== //
+
+/// A wall clock time within a UTC day, stored as nanoseconds since midnight.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
+pub struct TimeOfDay(u64);
+
+/// Rejects nanoseconds beyond the day, unlike a derived impl.
+#[cfg(feature = "serde-support")]
+impl<'de> Deserialize<'de> for TimeOfDay {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// Same wire format as the derived impl.
+        #[derive(Deserialize)]
+        #[serde(rename = "TimeOfDay")]
+        struct Raw(u64);
+
+        let Raw(nanos) = Raw::deserialize(deserializer)?;
+        TimeOfDay::from_nanoseconds(nanos).ok_or_else(|| {
+            de::Error::invalid_value(de::Unexpected::Unsigned(nanos), &"nanoseconds within a day")
+        })
+    }
+}
+
+impl TimeOfDay {
+    /// `00:00:00`.
+    pub const MIDNIGHT: TimeOfDay = TimeOfDay(0);
+
+    /// Create a time of day from hours, minutes and seconds. `None` if any is out of range.
+    #[inline]
+    pub const fn from_hms(hour: u32, minute: u32, second: u32) -> Option<Self> {
+        Self::from_hms_nano(hour, minute, second, 0)
+    }
+
+    /// Create a time of day with nanosecond precision. `None` if any component is out of range.
+    pub const fn from_hms_nano(hour: u32, minute: u32, second: u32, nano: u32) -> Option<Self> {
+        if hour >= 24 || minute >= 60 || second >= 60 || nano >= 1_000_000_000 {
+            return None;
+        }
+        let secs = hour as u64 * 3_600 + minute as u64 * 60 + second as u64;
+        Some(TimeOfDay(secs * 1_000_000_000 + nano as u64))
+    }
+
+    /// Create a time of day from nanoseconds since midnight. `None` if not within a day.
+    #[inline]
+    pub const fn from_nanoseconds(nanos: u64) -> Option<Self> {
+        if nanos < NANOS_PER_DAY { Some(TimeOfDay(nanos)) } else { None }
+    }
+
+    /// Nanoseconds since midnight.
+    #[inline]
+    pub const fn as_nanoseconds(self) -> u64 {
+        self.0
+    }
+
+    /// Hour of the day, `0..24`.
+    #[inline]
+    pub const fn hour(self) -> u32 {
+        (self.0 / 3_600_000_000_000) as u32
+    }
+
+    /// Minute of the hour, `0..60`.
+    #[inline]
+    pub const fn minute(self) -> u32 {
+        (self.0 / 60_000_000_000 % 60) as u32
+    }
+
+    /// Second of the minute, `0..60`.
+    #[inline]
+    pub const fn second(self) -> u32 {
+        (self.0 / 1_000_000_000 % 60) as u32
+    }
+
+    /// Nanosecond of the second, `0..1_000_000_000`.
+    #[inline]
+    pub const fn nanosecond(self) -> u32 {
+        (self.0 % 1_000_000_000) as u32
+    }
+}
+
+/// Display as `HH:MM:SS`, followed by the nanoseconds if there are any.
+impl fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour(), self.minute(), self.second())?;
+        match self.nanosecond() {
+            0 => Ok(()),
+            nanos => write!(f, ".{:09}", nanos),
+        }
+    }
+}
+
+impl Timestamp {
+    /// Nanoseconds elapsed since midnight UTC.
+    #[inline]
+    pub const fn nanos_of_day(self) -> u64 {
+        self.0 % NANOS_PER_DAY
+    }
+
+    /// Whole seconds elapsed since midnight UTC.
+    #[inline]
+    pub const fn seconds_of_day(self) -> u32 {
+        (self.nanos_of_day() / 1_000_000_000) as u32
+    }
+
+    /// UTC wall clock time of the timestamp.
+    #[inline]
+    pub const fn time_of_day(self) -> TimeOfDay {
+        TimeOfDay(self.nanos_of_day())
+    }
+
+    /// Replace the time of day, keeping the UTC date.
+    ///
+    /// Saturates on the last representable day if the result would overflow.
+    #[inline]
+    pub const fn with_time_of_day(self, time: TimeOfDay) -> Self {
+        Timestamp((self.0 - self.nanos_of_day()).saturating_add(time.0))
+    }
+
+    /// Check whether both timestamps fall on the same UTC day.
+    #[inline]
+    pub const fn is_same_day(self, other: Timestamp) -> bool {
+        self.0 / NANOS_PER_DAY == other.0 / NANOS_PER_DAY
+    }
+
+    /// Check whether both timestamps fall within the same UTC hour.
+    #[inline]
+    pub const fn is_same_hour(self, other: Timestamp) -> bool {
+        self.0 / 3_600_000_000_000 == other.0 / 3_600_000_000_000
+    }
+
+    /// Check whether both timestamps fall within the same UTC minute.
+    #[inline]
+    pub const fn is_same_minute(self, other: Timestamp) -> bool {
+        self.0 / 60_000_000_000 == other.0 / 60_000_000_000
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use super::NANOS_PER_DAY;
+    use crate::*;
+    #[cfg(feature = "chrono-support")]
+    use chrono::Datelike;
+
+    fn utc(year: i64, month: u32, day: u32, hour: u64, minute: u64, second: u64) -> Timestamp {
+        let days = super::days_from_civil(year, month, day) as u64;
+        Timestamp::from_seconds(days * 86_400 + hour * 3_600 + minute * 60 + second)
+    }
+
+    #[cfg(feature = "chrono-support")]
+    #[test]
+    fn civil_from_days_vs_chrono() {
+        let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        for days in (-800_000..800_000).step_by(97) {
+            let date = epoch + chrono::Duration::days(days);
+            let expected = (date.year() as i64, date.month(), date.day());
+            assert_eq!(super::civil_from_days(days), expected, "days = {}", days);
+        }
+    }
+
+    #[test]
+    fn days_from_civil_round_trip() {
+        for days in (-800_000..800_000).step_by(89) {
+            let (year, month, day) = super::civil_from_days(days);
+            assert_eq!(super::days_from_civil(year, month, day), days);
+            assert!(day <= super::days_in_month(year, month));
+        }
+    }
+
+    #[test]
+    fn epoch_days() {
+        let ts: Timestamp = utc(2024, 5, 1, 13, 45, 10);
+        assert_eq!(ts.to_epoch_days(), 19_844);
+
+        let midnight: Timestamp = utc(2024, 5, 1, 0, 0, 0);
+        assert_eq!(Timestamp::from_epoch_days(19_844), midnight);
+        assert_eq!(Timestamp::from_epoch_days(0), Timestamp::zero());
+        assert_eq!(Timestamp::from_epoch_days(-1), Timestamp::zero());
+        assert_eq!(Timestamp::from_epoch_days(i32::MAX).as_nanoseconds(), u64::MAX);
+    }
+
+    #[test]
+    fn time_of_day() {
+        let ts: Timestamp = utc(2024, 5, 1, 13, 45, 10);
+        let ts = ts + TimeDelta::from_milliseconds(250);
+
+        assert_eq!(ts.seconds_of_day(), 13 * 3_600 + 45 * 60 + 10);
+        assert_eq!(ts.nanos_of_day(), (13 * 3_600 + 45 * 60 + 10) * 1_000_000_000 + 250_000_000);
+        assert_eq!(ts.time_of_day(), TimeOfDay::from_hms_nano(13, 45, 10, 250_000_000).unwrap());
+        assert!(ts.time_of_day() < TimeOfDay::from_hms(14, 30, 0).unwrap());
+        assert_eq!(ts.time_of_day().to_string(), "13:45:10.250000000");
+
+        let session_open: Timestamp = utc(2024, 5, 1, 9, 30, 0);
+        assert_eq!(ts.with_time_of_day(TimeOfDay::from_hms(9, 30, 0).unwrap()), session_open);
+        assert_eq!(TimeOfDay::from_hms(24, 0, 0), None);
+        assert_eq!(TimeOfDay::from_nanoseconds(NANOS_PER_DAY), None);
+    }
+
+    #[test]
+    fn same_period() {
+        let ts: Timestamp = utc(2024, 5, 1, 13, 59, 59);
+
+        assert!(ts.is_same_minute(ts + TimeDelta::from_milliseconds(999)));
+        assert!(!ts.is_same_minute(ts + TimeDelta::from_seconds(1)));
+        assert!(ts.is_same_hour(ts - TimeDelta::from_minutes(59)));
+        assert!(!ts.is_same_hour(ts + TimeDelta::from_seconds(1)));
+        assert!(ts.is_same_day(ts + TimeDelta::from_hours(10)));
+        assert!(!ts.is_same_day(ts + TimeDelta::from_hours(11)));
+    }
+
+    #[cfg(feature = "serde-support")]
+    #[test]
+    fn time_of_day_serde() {
+        let time = TimeOfDay::from_hms(13, 45, 10).unwrap();
+        let json = serde_json::to_string(&time).unwrap();
+        assert_eq!(json, "49510000000000");
+        assert_eq!(serde_json::from_str::<TimeOfDay>(&json).unwrap(), time);
+        assert!(serde_json::from_str::<TimeOfDay>(&(NANOS_PER_DAY - 1).to_string()).is_ok());
+        assert!(serde_json::from_str::<TimeOfDay>(&NANOS_PER_DAY.to_string()).is_err());
+    }
+}
@@ -0,0 +1,124 @@
+//! A precise clock reading the system clock only occasionally.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+
+use crate::clock::{Clock, SystemClock};
+use crate::{TimeDelta, Timestamp};
+
+/// A clock reading the system clock once and then advancing it with [`Instant`], re-anchoring
+/// to the system clock periodically, every second by default.
+///
+/// The monotonic clock behind `Instant` doesn't follow NTP steps and drifts slightly from the
+/// system clock, so the time may be off by that much until the next re-anchoring, when it steps
+/// to the system clock. Reads are lock-free.
+///
+/// ```
+/// use fast_utc::clock::{AnchoredClock, Clock};
+/// use fast_utc::{TimeDelta, Timestamp};
+///
+/// let clock = AnchoredClock::new();
+/// clock.set_reanchor_interval(TimeDelta::from_milliseconds(100));
+/// let ts = Timestamp::now_with(&clock);
+/// ```
+#[derive(Debug)]
+pub struct AnchoredClock {
+    base: Instant,
+    /// Nanoseconds since the epoch at `base`, as of the last anchoring.
+    wall_at_base: AtomicU64,
+    /// Nanoseconds after `base` of the last anchoring.
+    anchored_at: AtomicU64,
+    /// Nanoseconds between anchorings.
+    interval: AtomicU64,
+}
+
+impl AnchoredClock {
+    /// The default interval between re-anchoring to the system clock.
+    pub const DEFAULT_REANCHOR_INTERVAL: TimeDelta = TimeDelta::from_seconds(1);
+
+    /// Anchor to the system clock now.
+    pub fn new() -> Self {
+        let base = Instant::now();
+        let wall = SystemClock.now();
+        AnchoredClock {
+            base,
+            wall_at_base: AtomicU64::new(wall.0),
+            anchored_at: AtomicU64::new(0),
+            interval: AtomicU64::new(Self::DEFAULT_REANCHOR_INTERVAL.0 as u64),
+        }
+    }
+
+    /// Change how often the clock is re-anchored, never if zero or negative.
+    pub fn set_reanchor_interval(&self, interval: TimeDelta) {
+        let nanos = u64::try_from(interval.0).ok().filter(|&n| n > 0);
+        self.interval.store(nanos.unwrap_or(u64::MAX), Ordering::Relaxed);
+    }
+
+    /// Re-anchor to the system clock now, e.g. after learning of a clock step.
+    pub fn reanchor(&self) {
+        let elapsed = self.elapsed();
+        self.anchored_at.store(elapsed, Ordering::Relaxed);
+        self.anchor(elapsed);
+    }
+
+    /// Nanoseconds since `base`.
+    #[inline]
+    fn elapsed(&self) -> u64 {
+        self.base.elapsed().as_nanos() as u64
+    }
+
+    fn anchor(&self, elapsed: u64) {
+        let wall = SystemClock.now().0;
+        self.wall_at_base.store(wall.saturating_sub(elapsed), Ordering::Release);
+    }
+}
+
+impl Default for AnchoredClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for AnchoredClock {
+    #[inline]
+    fn now(&self) -> Timestamp {
+        let elapsed = self.elapsed();
+        let last = self.anchored_at.load(Ordering::Relaxed);
+        if elapsed.saturating_sub(last) > self.interval.load(Ordering::Relaxed)
+            && self.anchored_at.compare_exchange(last, elapsed, Ordering::Relaxed, Ordering::Relaxed).is_ok()
+        {
+            // Only the thread winning the exchange re-anchors, the others use the old anchor.
+            self.anchor(elapsed);
+        }
+        Timestamp(self.wall_at_base.load(Ordering::Acquire).saturating_add(elapsed))
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::clock::{AnchoredClock, Clock};
+
+    #[test]
+    fn anchored_tracks_system_clock() {
+        let clock = AnchoredClock::new();
+        let tolerance = TimeDelta::from_milliseconds(5);
+        let mut prev = clock.now();
+        assert!((prev - Timestamp::now_system()).abs() < tolerance);
+        for _ in 0..10_000 {
+            let ts = clock.now();
+            assert!(ts >= prev);
+            prev = ts;
+        }
+
+        clock.set_reanchor_interval(TimeDelta::from_nanoseconds(1));
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        assert!((clock.now() - Timestamp::now_system()).abs() < tolerance);
+        clock.reanchor();
+        assert!((clock.now() - Timestamp::now_system()).abs() < tolerance);
+    }
+}
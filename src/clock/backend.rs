@@ -0,0 +1,192 @@
+//! Runtime selection of the clock behind [`Timestamp::now`].
+
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicPtr, AtomicU8, Ordering};
+
+use crate::Timestamp;
+use crate::clock::{AnchoredClock, Clock};
+
+const PRECISE: u8 = 0;
+const COARSE: u8 = 1;
+const CUSTOM: u8 = 2;
+const ANCHORED: u8 = 3;
+
+/// Precise with `tsc-clock`, which is both, coarse if any coarse backend is compiled in.
+const DEFAULT: u8 = if cfg!(all(feature = "tsc-clock", target_arch = "x86_64")) {
+    PRECISE
+} else if cfg!(any(
+    all(feature = "coarsetime-support", not(all(target_arch = "wasm32", target_os = "unknown"))),
+    all(feature = "coarse-clock", any(target_os = "linux", target_os = "android", windows))
+)) {
+    COARSE
+} else {
+    PRECISE
+};
+
+/// Checked on every read, the custom clock is only loaded when selected.
+static SOURCE: AtomicU8 = AtomicU8::new(DEFAULT);
+/// Leaked on every switch to a custom clock, which should be rare.
+static CUSTOM_CLOCK: AtomicPtr<&'static (dyn Clock + Sync)> = AtomicPtr::new(core::ptr::null_mut());
+
+/// The clock read by [`Timestamp::now`] in the whole process, see [`set_source`].
+///
+/// Which backend the precise and coarse sources use is still decided by the enabled features,
+/// so a binary built with `coarsetime-support` and `tsc-clock` can pick either at startup:
+///
+/// ```
+/// use fast_utc::clock::{self, ClockSource};
+///
+/// if std::env::var_os("PRECISE_TIMESTAMPS").is_some() {
+///     clock::set_source(ClockSource::Precise);
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub enum ClockSource {
+    /// The system clock, or the TSC with `tsc-clock` on x86_64.
+    Precise,
+    /// The kernel's coarse clock with `coarse-clock`, else `coarsetime` with
+    /// `coarsetime-support`, else the same as `Precise`.
+    Coarse,
+    /// The system clock read once and advanced with `Instant`, see [`AnchoredClock`].
+    Anchored,
+    /// Any other clock.
+    Custom(&'static (dyn Clock + Sync)),
+}
+
+impl ClockSource {
+    /// The source used until [`set_source`] is called: `Precise` with `tsc-clock`, `Coarse` with
+    /// `coarse-clock` or `coarsetime-support`, `Precise` otherwise.
+    pub fn default_source() -> Self {
+        Self::from_u8(DEFAULT)
+    }
+
+    fn from_u8(source: u8) -> Self {
+        match source {
+            PRECISE => ClockSource::Precise,
+            COARSE => ClockSource::Coarse,
+            ANCHORED => ClockSource::Anchored,
+            _ => {
+                // SAFETY: `CUSTOM` is only stored after a pointer to a leaked reference.
+                ClockSource::Custom(unsafe { *CUSTOM_CLOCK.load(Ordering::Acquire) })
+            }
+        }
+    }
+}
+
+impl core::fmt::Debug for ClockSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ClockSource::Precise => f.write_str("Precise"),
+            ClockSource::Coarse => f.write_str("Coarse"),
+            ClockSource::Anchored => f.write_str("Anchored"),
+            ClockSource::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Select the clock read by [`Timestamp::now`] for the whole process.
+pub fn set_source(source: ClockSource) {
+    let source = match source {
+        ClockSource::Precise => PRECISE,
+        ClockSource::Coarse => COARSE,
+        ClockSource::Anchored => ANCHORED,
+        ClockSource::Custom(clock) => {
+            CUSTOM_CLOCK.store(Box::into_raw(Box::new(clock)), Ordering::Release);
+            CUSTOM
+        }
+    };
+    SOURCE.store(source, Ordering::Release);
+}
+
+/// The clock currently read by [`Timestamp::now`].
+pub fn source() -> ClockSource {
+    ClockSource::from_u8(SOURCE.load(Ordering::Acquire))
+}
+
+/// Anchored when first read with [`ClockSource::Anchored`].
+static ANCHORED_CLOCK: LazyLock<AnchoredClock> = LazyLock::new(AnchoredClock::new);
+
+/// Read the selected clock.
+#[inline]
+pub(crate) fn read() -> Timestamp {
+    match SOURCE.load(Ordering::Acquire) {
+        PRECISE => read_precise(),
+        COARSE => read_coarse(),
+        ANCHORED => ANCHORED_CLOCK.now(),
+        // SAFETY: `CUSTOM` is only stored after a pointer to a leaked reference.
+        _ => unsafe { *CUSTOM_CLOCK.load(Ordering::Acquire) }.now(),
+    }
+}
+
+#[inline]
+fn read_precise() -> Timestamp {
+    #[cfg(all(feature = "tsc-clock", target_arch = "x86_64"))]
+    if let Some(tsc) = crate::clock::tsc::global() {
+        return tsc.now();
+    }
+    crate::clock::SystemClock.now()
+}
+
+/// Read the coarse clock, or the precise one without a coarse backend.
+#[inline]
+pub(crate) fn read_coarse() -> Timestamp {
+    #[cfg(all(feature = "coarse-clock", any(target_os = "linux", target_os = "android", windows)))]
+    return crate::clock::coarse::read();
+    #[cfg(all(
+        feature = "coarsetime-support",
+        not(all(feature = "coarse-clock", any(target_os = "linux", target_os = "android", windows))),
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
+    return Timestamp(coarsetime::Clock::recent_since_epoch().as_nanos());
+    #[allow(unreachable_code)]
+    read_precise()
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::clock::{Clock, ClockSource, set_source, source};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Reads the system clock, so that other tests running meanwhile aren't disturbed.
+    struct Counting(AtomicUsize);
+
+    impl Clock for Counting {
+        fn now(&self) -> Timestamp {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            Timestamp::now_system()
+        }
+    }
+
+    #[test]
+    fn runtime_source() {
+        static COUNTING: Counting = Counting(AtomicUsize::new(0));
+
+        let previous = source();
+        set_source(ClockSource::Custom(&COUNTING));
+        Timestamp::now();
+        assert!(COUNTING.0.load(Ordering::Relaxed) >= 1);
+        assert!(matches!(source(), ClockSource::Custom(_)));
+
+        for (precise, name) in [(ClockSource::Precise, "Precise"), (ClockSource::Anchored, "Anchored")] {
+            set_source(precise);
+            let (before, ts) = (Timestamp::now_system(), Timestamp::now());
+            assert!((ts - before).abs() < TimeDelta::from_milliseconds(50));
+            assert_eq!(format!("{:?}", source()), name);
+        }
+
+        let (millis, seconds) = (Timestamp::now_millis(), Timestamp::now_seconds());
+        assert_eq!(millis.as_nanoseconds() % 1_000_000, 0);
+        assert_eq!(seconds.as_nanoseconds() % 1_000_000_000, 0);
+        #[cfg(feature = "coarsetime-support")]
+        coarsetime_update();
+        assert!((Timestamp::now_system() - Timestamp::recent()).abs() < TimeDelta::from_milliseconds(50));
+
+        set_source(previous);
+        assert_eq!(format!("{:?}", source()), format!("{:?}", ClockSource::default_source()));
+    }
+}
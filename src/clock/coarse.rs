@@ -0,0 +1,63 @@
+//! Clock backend reading the kernel's coarse wall clock directly, for `coarse-clock`.
+//!
+//! Like `coarsetime`, this returns the time as of the last scheduler tick, typically 1 to 4 ms
+//! old, but it's kept up to date by the kernel instead of a background thread.
+
+use crate::Timestamp;
+
+/// Read `CLOCK_REALTIME_COARSE`, served from the vDSO without a system call.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[inline]
+pub(crate) fn read() -> Timestamp {
+    let mut ts = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+    // SAFETY: `ts` is a valid `timespec` to write to, and the clock id is supported since
+    // Linux 2.6.32.
+    let ret = unsafe { libc::clock_gettime(libc::CLOCK_REALTIME_COARSE, &mut ts) };
+    debug_assert_eq!(ret, 0, "clock_gettime(CLOCK_REALTIME_COARSE) failed");
+    // Clamps times before the epoch to the epoch.
+    let secs = u64::try_from(ts.tv_sec).unwrap_or(0);
+    Timestamp(secs.saturating_mul(1_000_000_000).saturating_add(ts.tv_nsec as u64))
+}
+
+/// Read `GetSystemTimeAsFileTime`, the system time as of the last timer interrupt.
+#[cfg(windows)]
+#[inline]
+pub(crate) fn read() -> Timestamp {
+    /// 100 ns intervals between 1601-01-01 and the Unix epoch.
+    const EPOCH_INTERVALS: u64 = 116_444_736_000_000_000;
+
+    #[repr(C)]
+    struct FileTime {
+        low: u32,
+        high: u32,
+    }
+
+    #[link(name = "kernel32")]
+    unsafe extern "system" {
+        fn GetSystemTimeAsFileTime(time: *mut FileTime);
+    }
+
+    let mut time = FileTime { low: 0, high: 0 };
+    // SAFETY: `time` is a valid `FILETIME` to write to.
+    unsafe { GetSystemTimeAsFileTime(&mut time) };
+    let intervals = (u64::from(time.high) << 32) | u64::from(time.low);
+    Timestamp(intervals.saturating_sub(EPOCH_INTERVALS).saturating_mul(100))
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn coarse_close_to_system() {
+        let before = Timestamp::now_system();
+        let coarse = super::read();
+        let after = Timestamp::now_system();
+        // The coarse clock lags by at most a few scheduler ticks.
+        assert!(coarse > before - TimeDelta::from_milliseconds(50) && coarse <= after);
+    }
+}
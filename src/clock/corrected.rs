@@ -0,0 +1,193 @@
+//! A clock corrected by an external time synchronization daemon.
+
+use std::sync::{Mutex, PoisonError};
+
+use crate::clock::{Clock, SystemClock};
+use crate::{TimeDelta, Timestamp};
+
+/// The state of a [`CorrectedClock`], relative to the reading of the underlying clock at `anchor`.
+#[derive(Debug)]
+struct Correction {
+    /// Reading of the underlying clock the correction was last updated at.
+    anchor: u64,
+    /// Nanoseconds added at `anchor`.
+    offset: i64,
+    /// Nanoseconds to be added once slewing has finished.
+    target: i64,
+    /// Extra nanoseconds per second of the underlying clock.
+    drift_ppb: i64,
+    /// Most nanoseconds per millisecond the offset moves towards the target.
+    slew_ppm: i64,
+}
+
+impl Correction {
+    /// Nanoseconds elapsed on the underlying clock since `anchor`, and those gained by drift.
+    fn elapsed(&self, raw: u64) -> (i128, i128) {
+        let elapsed = raw.saturating_sub(self.anchor) as i128;
+        (elapsed, elapsed * self.drift_ppb as i128 / 1_000_000_000)
+    }
+
+    /// Nanoseconds to add to the reading `raw` of the underlying clock.
+    fn offset_at(&self, raw: u64) -> i64 {
+        let (elapsed, drift) = self.elapsed(raw);
+        let max_slew = elapsed * self.slew_ppm as i128 / 1_000_000;
+        let slewed = (self.target as i128 - self.offset as i128).clamp(-max_slew, max_slew);
+        (self.offset as i128 + slewed + drift).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+
+    /// Move the anchor to `raw`, keeping the offset and the remaining slew as they are there.
+    fn reanchor(&mut self, raw: u64) {
+        let (_, drift) = self.elapsed(raw);
+        let target = self.target as i128 + drift;
+        self.offset = self.offset_at(raw);
+        self.target = target.clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+        self.anchor = raw;
+    }
+}
+
+/// A clock applying an adjustable offset and drift rate to another clock, e.g. fed by an NTP
+/// or PTP daemon, so that the whole process shares one corrected timebase.
+///
+/// Like `adjtime`, corrections reported with [`adjust`](Self::adjust) are slewed in gradually
+/// rather than stepping the time, by default at most 500 µs per second, which keeps the clock
+/// from ever going backwards.
+///
+/// ```
+/// use fast_utc::clock::{Clock, CorrectedClock, SystemClock};
+/// use fast_utc::{TimeDelta, Timestamp};
+///
+/// static CLOCK: CorrectedClock = CorrectedClock::new(SystemClock);
+///
+/// // Reported by the daemon: the local clock is 2 ms behind and runs 15 ppm slow.
+/// CLOCK.adjust(TimeDelta::from_milliseconds(2));
+/// CLOCK.set_drift_ppb(15_000);
+/// let ts = Timestamp::now_with(&CLOCK);
+/// ```
+#[derive(Debug)]
+pub struct CorrectedClock<C = SystemClock> {
+    clock: C,
+    correction: Mutex<Correction>,
+}
+
+impl<C: Clock> CorrectedClock<C> {
+    /// The default slew rate, 500 µs per second.
+    pub const DEFAULT_SLEW_RATE_PPM: u32 = 500;
+
+    /// Wrap `clock`, initially without any correction.
+    pub const fn new(clock: C) -> Self {
+        let correction = Correction {
+            anchor: 0,
+            offset: 0,
+            target: 0,
+            drift_ppb: 0,
+            slew_ppm: Self::DEFAULT_SLEW_RATE_PPM as i64,
+        };
+        CorrectedClock { clock, correction: Mutex::new(correction) }
+    }
+
+    /// Read the underlying clock and update the correction at that reading.
+    fn update(&self, update: impl FnOnce(&mut Correction)) {
+        let raw = self.clock.now().0;
+        let mut correction = self.correction.lock().unwrap_or_else(PoisonError::into_inner);
+        correction.reanchor(raw);
+        update(&mut correction);
+    }
+
+    /// Correct the clock by `error` gradually, where a positive `error` means the corrected clock
+    /// is behind true time. Replaces whatever remained to be slewed of earlier adjustments.
+    pub fn adjust(&self, error: TimeDelta) {
+        self.update(|c| c.target = c.offset.saturating_add(error.0));
+    }
+
+    /// Correct the clock by `error` at once, which may move it backwards.
+    pub fn step(&self, error: TimeDelta) {
+        self.update(|c| {
+            c.offset = c.offset.saturating_add(error.0);
+            c.target = c.target.saturating_add(error.0);
+        });
+    }
+
+    /// Let the corrected clock run `ppb` nanoseconds per second faster than the underlying one,
+    /// or slower if negative.
+    pub fn set_drift_ppb(&self, ppb: i64) {
+        self.update(|c| c.drift_ppb = ppb);
+    }
+
+    /// Slew at most `ppm` microseconds per second, zero only allowing [`step`](Self::step).
+    pub fn set_slew_rate_ppm(&self, ppm: u32) {
+        self.update(|c| c.slew_ppm = ppm as i64);
+    }
+
+    /// The correction currently applied.
+    pub fn offset(&self) -> TimeDelta {
+        let raw = self.clock.now().0;
+        TimeDelta(self.correction.lock().unwrap_or_else(PoisonError::into_inner).offset_at(raw))
+    }
+
+    /// The correction still to be slewed in.
+    pub fn remaining(&self) -> TimeDelta {
+        let raw = self.clock.now().0;
+        let mut correction = self.correction.lock().unwrap_or_else(PoisonError::into_inner);
+        correction.reanchor(raw);
+        TimeDelta(correction.target.saturating_sub(correction.offset))
+    }
+
+    /// The underlying clock.
+    pub fn inner(&self) -> &C {
+        &self.clock
+    }
+}
+
+impl<C: Clock> Clock for CorrectedClock<C> {
+    fn now(&self) -> Timestamp {
+        let raw = self.clock.now();
+        let offset = self.correction.lock().unwrap_or_else(PoisonError::into_inner).offset_at(raw.0);
+        raw.saturating_add(TimeDelta(offset))
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::clock::{Clock, CorrectedClock, MockClock};
+
+    #[test]
+    fn slew_and_drift() {
+        let start = Timestamp::from_seconds(1_714_570_200);
+        let raw = MockClock::new(start);
+        let clock = CorrectedClock::new(raw.clone());
+        assert_eq!(clock.now(), start);
+
+        // 1 ms behind takes 2 s to slew in at 500 ppm.
+        clock.adjust(TimeDelta::from_milliseconds(1));
+        assert_eq!(clock.remaining(), TimeDelta::from_milliseconds(1));
+        raw.advance(TimeDelta::from_seconds(1));
+        assert_eq!(clock.offset(), TimeDelta::from_microseconds(500));
+        assert_eq!(clock.remaining(), TimeDelta::from_microseconds(500));
+        raw.advance(TimeDelta::from_seconds(5));
+        assert_eq!(clock.offset(), TimeDelta::from_milliseconds(1));
+        assert_eq!(clock.now(), raw.now() + TimeDelta::from_milliseconds(1));
+
+        // Slewing backwards still moves forward, only slower.
+        clock.adjust(TimeDelta::from_milliseconds(-2));
+        let before = clock.now();
+        raw.advance(TimeDelta::from_milliseconds(1));
+        assert_eq!(clock.now() - before, TimeDelta::from_nanoseconds(999_500));
+
+        clock.step(TimeDelta::from_seconds(-1));
+        assert_eq!(clock.remaining(), TimeDelta::from_nanoseconds(-1_999_500));
+        clock.set_slew_rate_ppm(0);
+        let offset = clock.offset();
+        raw.advance(TimeDelta::from_seconds(10));
+        assert_eq!(clock.offset(), offset);
+
+        clock.set_drift_ppb(15_000);
+        raw.advance(TimeDelta::from_seconds(10));
+        assert_eq!(clock.offset(), offset + TimeDelta::from_microseconds(150));
+        assert_eq!(clock.inner().now(), raw.now());
+    }
+}
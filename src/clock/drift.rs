@@ -0,0 +1,161 @@
+//! Monitoring how far the coarse clock lags behind the precise one.
+
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::TimeDelta;
+use crate::clock::{Clock, SystemClock, backend};
+
+/// Measure how far the coarse clock, see [`Timestamp::recent`], is behind the precise system
+/// clock right now. Negative if it's ahead, e.g. after the system clock was stepped back.
+///
+/// Without a coarse backend, both are the same clock and this only measures the time between
+/// the two reads.
+///
+/// [`Timestamp::recent`]: crate::Timestamp::recent
+pub fn measure_drift() -> TimeDelta {
+    let coarse = backend::read_coarse();
+    SystemClock.now() - coarse
+}
+
+/// Statistics of the drift measured by a [`DriftSampler`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DriftStats {
+    /// Number of measurements.
+    pub samples: u64,
+    /// The latest measurement.
+    pub last: TimeDelta,
+    /// The measurement furthest from zero.
+    pub max: TimeDelta,
+    /// The sum of all measurements, see [`mean`](Self::mean).
+    pub sum: TimeDelta,
+}
+
+impl Default for DriftStats {
+    fn default() -> Self {
+        let zero = TimeDelta::zero();
+        DriftStats { samples: 0, last: zero, max: zero, sum: zero }
+    }
+}
+
+impl DriftStats {
+    /// Add a measurement.
+    pub fn record(&mut self, drift: TimeDelta) {
+        self.samples += 1;
+        self.last = drift;
+        if drift.abs() > self.max.abs() {
+            self.max = drift;
+        }
+        self.sum = self.sum.saturating_add(drift);
+    }
+
+    /// The mean of all measurements, zero without any.
+    pub fn mean(&self) -> TimeDelta {
+        match self.samples {
+            0 => TimeDelta::zero(),
+            n => TimeDelta(self.sum.0 / n as i64),
+        }
+    }
+}
+
+/// A background thread calling [`measure_drift`] periodically, stopped and joined when dropped.
+///
+/// ```no_run
+/// use fast_utc::clock::DriftSampler;
+/// use fast_utc::TimeDelta;
+///
+/// let sampler = DriftSampler::start(TimeDelta::from_seconds(1)).unwrap();
+/// // Later, e.g. from a metrics exporter:
+/// if sampler.take_stats().max > TimeDelta::from_milliseconds(10) {
+///     eprintln!("coarse clock updater is falling behind");
+/// }
+/// ```
+#[derive(Debug)]
+pub struct DriftSampler {
+    stats: Arc<Mutex<DriftStats>>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl DriftSampler {
+    /// Start sampling every `interval` on a thread named `fast_utc-drift-sampler`.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidInput`] unless the interval is positive, or if the
+    /// thread can't be spawned.
+    pub fn start(interval: TimeDelta) -> io::Result<Self> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidInput, "sampling interval must be positive");
+        let interval = Duration::try_from(interval).ok().filter(|i| !i.is_zero()).ok_or_else(invalid)?;
+
+        let stats = Arc::new(Mutex::new(DriftStats::default()));
+        let stop = Arc::new(AtomicBool::new(false));
+        let (thread_stats, stopped) = (Arc::clone(&stats), Arc::clone(&stop));
+        let thread = thread::Builder::new().name("fast_utc-drift-sampler".to_owned()).spawn(move || {
+            while !stopped.load(Ordering::Acquire) {
+                let drift = measure_drift();
+                thread_stats.lock().unwrap_or_else(PoisonError::into_inner).record(drift);
+                thread::park_timeout(interval);
+            }
+        })?;
+        Ok(DriftSampler { stats, stop, thread: Some(thread) })
+    }
+
+    /// The statistics since the start or the last [`take_stats`](Self::take_stats).
+    pub fn stats(&self) -> DriftStats {
+        *self.stats.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// The statistics since the start or the last call, resetting them, e.g. for each interval
+    /// of a metrics exporter.
+    pub fn take_stats(&self) -> DriftStats {
+        core::mem::take(&mut *self.stats.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+impl Drop for DriftSampler {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            // A panic in the thread is not worth a second one here.
+            let _ = thread.join();
+        }
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::clock::{DriftSampler, DriftStats, measure_drift};
+
+    #[test]
+    fn drift_stats() {
+        let mut stats = DriftStats::default();
+        assert_eq!(stats.mean(), TimeDelta::zero());
+        for ms in [2, -5, 3] {
+            stats.record(TimeDelta::from_milliseconds(ms));
+        }
+        assert_eq!(stats.samples, 3);
+        assert_eq!(stats.last, TimeDelta::from_milliseconds(3));
+        assert_eq!(stats.max, TimeDelta::from_milliseconds(-5));
+        assert_eq!(stats.mean(), TimeDelta::zero());
+    }
+
+    #[test]
+    fn drift_sampler() {
+        #[cfg(feature = "coarsetime-support")]
+        coarsetime_update();
+        assert!(measure_drift() < TimeDelta::from_seconds(1));
+
+        let sampler = DriftSampler::start(TimeDelta::from_milliseconds(1)).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        assert!(sampler.take_stats().samples > 0);
+        assert!(DriftSampler::start(TimeDelta::zero()).is_err());
+    }
+}
@@ -0,0 +1,99 @@
+//! Process wide redirection of [`Timestamp::now`] to another clock.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+
+use crate::clock::{Clock, MockClock};
+use crate::{TimeDelta, Timestamp};
+
+type SharedClock = Arc<dyn Clock + Send + Sync>;
+
+/// Checked on every read without locking, so the live clock stays fast.
+static OVERRIDDEN: AtomicBool = AtomicBool::new(false);
+static GLOBAL: RwLock<Option<SharedClock>> = RwLock::new(None);
+
+/// Read the overriding clock, if any.
+#[inline]
+pub(crate) fn read() -> Option<Timestamp> {
+    if !OVERRIDDEN.load(Ordering::Acquire) {
+        return None;
+    }
+    GLOBAL.read().unwrap_or_else(PoisonError::into_inner).as_ref().map(|clock| clock.now())
+}
+
+fn replace(clock: Option<SharedClock>) -> Option<SharedClock> {
+    let mut global = GLOBAL.write().unwrap_or_else(PoisonError::into_inner);
+    OVERRIDDEN.store(clock.is_some(), Ordering::Release);
+    core::mem::replace(&mut global, clock)
+}
+
+/// Redirect [`Timestamp::now`] to `clock` for the whole process, until [`clear_global`].
+///
+/// Code reading the clock through [`Timestamp::now_with`] is not affected.
+pub fn set_global(clock: &'static (dyn Clock + Sync)) {
+    replace(Some(Arc::new(clock)));
+}
+
+/// Let [`Timestamp::now`] read the real clock again.
+pub fn clear_global() {
+    replace(None);
+}
+
+/// Stop [`Timestamp::now`] at `ts` for the whole process until the returned guard is dropped,
+/// which restores the previous override, if any.
+///
+/// As the override is process wide, tests freezing time should not run in parallel with tests
+/// reading the real clock.
+///
+/// ```
+/// # #[cfg(feature = "clock-override")] {
+/// use fast_utc::{clock, TimeDelta, Timestamp};
+///
+/// let frozen = clock::freeze_time_at(Timestamp::from_seconds(1_714_570_200));
+/// assert_eq!(Timestamp::now(), Timestamp::from_seconds(1_714_570_200));
+/// frozen.advance(TimeDelta::from_seconds(1));
+/// assert_eq!(Timestamp::now(), Timestamp::from_seconds(1_714_570_201));
+/// # }
+/// ```
+#[must_use = "time is only frozen until the guard is dropped"]
+pub fn freeze_time_at(ts: Timestamp) -> FrozenTime {
+    let clock = MockClock::new(ts);
+    let previous = replace(Some(Arc::new(clock.clone())));
+    FrozenTime { clock, previous }
+}
+
+/// Keeps [`Timestamp::now`] frozen, see [`freeze_time_at`].
+pub struct FrozenTime {
+    clock: MockClock,
+    previous: Option<SharedClock>,
+}
+
+impl FrozenTime {
+    /// Move the frozen time to `ts`.
+    pub fn set(&self, ts: Timestamp) {
+        self.clock.set(ts);
+    }
+
+    /// Move the frozen time by `delta`, returning the new time, see [`MockClock::advance`].
+    pub fn advance(&self, delta: TimeDelta) -> Timestamp {
+        self.clock.advance(delta)
+    }
+
+    /// The clock standing in for the real one, e.g. to pass to [`Timestamp::now_with`].
+    pub fn clock(&self) -> &MockClock {
+        &self.clock
+    }
+}
+
+impl fmt::Debug for FrozenTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrozenTime").field("now", &self.clock.now()).finish_non_exhaustive()
+    }
+}
+
+impl Drop for FrozenTime {
+    fn drop(&mut self) {
+        replace(self.previous.take());
+    }
+}
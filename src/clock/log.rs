@@ -0,0 +1,142 @@
+//! Compact storage of a sequence of clock reads.
+
+use crate::{TimeError, Timestamp};
+
+// ============================================================================================== //
+// [ClockLog]                                                                                     //
+// ============================================================================================== //
+
+/// A sequence of timestamps returned by the clock, in the order they were read.
+///
+/// [`encode`](Self::encode) stores the differences between consecutive reads as zigzag
+/// varints, which takes one to four bytes per read for typical clock reads.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ClockLog {
+    reads: Vec<Timestamp>,
+}
+
+impl ClockLog {
+    /// Create an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a read.
+    pub fn push(&mut self, ts: Timestamp) {
+        self.reads.push(ts);
+    }
+
+    /// The reads in order.
+    pub fn reads(&self) -> &[Timestamp] {
+        &self.reads
+    }
+
+    /// Number of reads in the log.
+    pub fn len(&self) -> usize {
+        self.reads.len()
+    }
+
+    /// Check whether the log holds no reads.
+    pub fn is_empty(&self) -> bool {
+        self.reads.is_empty()
+    }
+
+    /// Serialize the log into its compact binary form.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.reads.len() * 3);
+        let mut prev = 0u64;
+        for ts in &self.reads {
+            let delta = ts.0.wrapping_sub(prev) as i64;
+            let mut zigzag = ((delta << 1) ^ (delta >> 63)) as u64;
+            while zigzag >= 0x80 {
+                out.push(zigzag as u8 | 0x80);
+                zigzag >>= 7;
+            }
+            out.push(zigzag as u8);
+            prev = ts.0;
+        }
+        out
+    }
+
+    /// Deserialize a log produced by [`encode`](Self::encode).
+    pub fn decode(bytes: &[u8]) -> Result<Self, TimeError> {
+        let mut log = ClockLog::new();
+        let (mut prev, mut zigzag, mut shift) = (0u64, 0u64, 0);
+        for &byte in bytes {
+            // Only the lowest bit of a tenth byte fits into 64 bits, and no eleventh byte may follow.
+            if shift == 63 && byte > 1 {
+                return Err(TimeError::InvalidFormat);
+            }
+            zigzag |= u64::from(byte & 0x7f) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                let delta = (zigzag >> 1) as i64 ^ -((zigzag & 1) as i64);
+                prev = prev.wrapping_add(delta as u64);
+                log.push(Timestamp(prev));
+                (zigzag, shift) = (0, 0);
+            }
+        }
+        if shift != 0 {
+            return Err(TimeError::InvalidFormat);
+        }
+        Ok(log)
+    }
+}
+
+impl FromIterator<Timestamp> for ClockLog {
+    fn from_iter<I: IntoIterator<Item = Timestamp>>(iter: I) -> Self {
+        ClockLog { reads: iter.into_iter().collect() }
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::clock::ClockLog;
+    use crate::*;
+
+    #[test]
+    fn encode_round_trip() {
+        let base = Timestamp::from_seconds(1_714_570_200);
+        let log: ClockLog = [0, 1_000, 999, 2_000_000, 1_500, 1_500]
+            .into_iter()
+            .map(|offset| base + TimeDelta::from_nanoseconds(offset))
+            .chain([Timestamp::MAX, Timestamp::zero(), Timestamp::MAX])
+            .collect();
+
+        let bytes = log.encode();
+        assert_eq!(ClockLog::decode(&bytes), Ok(log.clone()));
+        // The first read is a full timestamp, the rest are small deltas apart.
+        let typical: ClockLog = log.reads()[..6].iter().copied().collect();
+        assert_eq!(typical.encode().len(), 9 + 2 + 1 + 4 + 4 + 1);
+
+        assert_eq!(ClockLog::decode(&[]), Ok(ClockLog::new()));
+        // Truncated in the middle of the first read.
+        assert_eq!(ClockLog::decode(&bytes[..4]), Err(TimeError::InvalidFormat));
+        assert_eq!(ClockLog::decode(&[0xff; 11]), Err(TimeError::InvalidFormat));
+    }
+
+    #[test]
+    fn decode_rejects_corrupt_input() {
+        let log: ClockLog = [Timestamp::from_seconds(1), Timestamp::MAX].into_iter().collect();
+        let bytes = log.encode();
+        for len in 1..bytes.len() {
+            let decoded = ClockLog::decode(&bytes[..len]);
+            assert!(decoded.is_err() || decoded.unwrap().len() == 1, "len = {}", len);
+        }
+        assert_eq!(ClockLog::decode(&bytes[..bytes.len() - 1]), Err(TimeError::InvalidFormat));
+
+        // The longest varint holds a single bit in its tenth byte.
+        let mut longest = [0xff; 10];
+        longest[9] = 0x01;
+        let half: ClockLog = [Timestamp::from_nanoseconds(1 << 63)].into_iter().collect();
+        assert_eq!(ClockLog::decode(&longest), Ok(half));
+        longest[9] = 0x02;
+        assert_eq!(ClockLog::decode(&longest), Err(TimeError::InvalidFormat));
+        longest[9] = 0x81;
+        assert_eq!(ClockLog::decode(&longest), Err(TimeError::InvalidFormat));
+    }
+}
@@ -0,0 +1,102 @@
+//! A manually driven clock for deterministic tests.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::clock::Clock;
+use crate::{TimeDelta, Timestamp};
+
+/// A clock that only moves when told to.
+///
+/// Clones share the same time, so a test can keep one handle to drive the clock and hand clones
+/// to the code under test, also on other threads:
+///
+/// ```
+/// use fast_utc::clock::{Clock, MockClock};
+/// use fast_utc::{TimeDelta, Timestamp};
+///
+/// let clock = MockClock::new(Timestamp::from_seconds(1_714_570_200));
+/// let handle = clock.clone();
+/// clock.advance(TimeDelta::from_seconds(30));
+/// assert_eq!(handle.now(), Timestamp::from_seconds(1_714_570_230));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MockClock(Arc<AtomicU64>);
+
+impl MockClock {
+    /// Create a clock standing at `ts`.
+    pub fn new(ts: Timestamp) -> Self {
+        MockClock(Arc::new(AtomicU64::new(ts.0)))
+    }
+
+    /// Move the clock to `ts`, also backwards.
+    pub fn set(&self, ts: Timestamp) {
+        self.0.store(ts.0, Ordering::Release);
+    }
+
+    /// Move the clock by `delta`, also backwards, returning the new time.
+    ///
+    /// # Panics
+    ///
+    /// If the time would leave the range of [`Timestamp`].
+    pub fn advance(&self, delta: TimeDelta) -> Timestamp {
+        let advance = |nanos| Timestamp(nanos).checked_add(delta).map(|ts| ts.0);
+        let prev = self.0.fetch_update(Ordering::AcqRel, Ordering::Acquire, advance);
+        let prev = prev.expect("mock clock advanced out of the timestamp range");
+        Timestamp(prev).checked_add(delta).unwrap()
+    }
+}
+
+impl Clock for MockClock {
+    #[inline]
+    fn now(&self) -> Timestamp {
+        Timestamp(self.0.load(Ordering::Acquire))
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::clock::{Clock, MockClock};
+    use std::thread;
+
+    #[test]
+    fn manual_advance() {
+        let clock = MockClock::new(Timestamp::from_seconds(100));
+        assert_eq!(clock.advance(TimeDelta::from_seconds(-40)), Timestamp::from_seconds(60));
+        clock.set(Timestamp::from_seconds(10));
+        assert_eq!(clock.now(), Timestamp::from_seconds(10));
+        assert_eq!(MockClock::default().now(), Timestamp::zero());
+
+        let tick = TimeDelta::from_nanoseconds(1);
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let clock = clock.clone();
+                thread::spawn(move || (0..1_000).for_each(|_| _ = clock.advance(tick)))
+            })
+            .collect();
+        handles.into_iter().for_each(|h| h.join().unwrap());
+        assert_eq!(clock.now(), Timestamp::from_seconds(10) + TimeDelta::from_nanoseconds(4_000));
+    }
+
+    #[test]
+    fn advance_near_max() {
+        let clock = MockClock::new(Timestamp::from_nanoseconds(u64::MAX - 10));
+        assert_eq!(clock.advance(TimeDelta::from_nanoseconds(10)), Timestamp::MAX);
+        assert_eq!(clock.now(), Timestamp::MAX);
+        assert_eq!(
+            clock.advance(TimeDelta::from_nanoseconds(-4)),
+            Timestamp::from_nanoseconds(u64::MAX - 4),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "out of the timestamp range")]
+    fn advance_before_epoch() {
+        MockClock::default().advance(TimeDelta::from_nanoseconds(-1));
+    }
+}
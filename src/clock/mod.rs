@@ -0,0 +1,63 @@
+//! The clock behind [`Timestamp::now`](crate::Timestamp::now), and the [`Clock`] trait for
+//! passing clocks explicitly with [`Timestamp::now_with`](crate::Timestamp::now_with).
+//!
+//! The clock read by `now()` can be selected for the whole process at runtime with
+//! [`set_source`], e.g. to use the precise clock in one deployment and the coarse one in another.
+//!
+//! With the `clock-replay` feature, every read of the clock can be recorded into a
+//! [`ClockLog`] and fed back in a later run, so that a whole process sees exactly the same
+//! timestamps again, e.g. to reproduce a bug depending on exact times:
+//!
+//! ```
+//! # #[cfg(feature = "clock-replay")] {
+//! use fast_utc::{clock, Timestamp};
+//!
+//! clock::start_recording();
+//! let first = Timestamp::now();
+//! let log = clock::stop_recording();
+//! let bytes = log.encode(); // Persist somewhere.
+//!
+//! clock::start_replay(clock::ClockLog::decode(&bytes).unwrap());
+//! assert_eq!(Timestamp::now(), first);
+//! assert!(clock::stop_replay().is_empty());
+//! # }
+//! ```
+
+mod anchored;
+pub(crate) mod backend;
+#[cfg(all(feature = "coarse-clock", any(target_os = "linux", target_os = "android", windows)))]
+pub(crate) mod coarse;
+mod corrected;
+mod drift;
+#[cfg(feature = "clock-override")]
+pub(crate) mod global;
+mod log;
+mod mock;
+mod monotonic;
+#[cfg(feature = "clock-replay")]
+pub(crate) mod replay;
+mod source;
+#[cfg(all(feature = "tsc-clock", target_arch = "x86_64"))]
+pub(crate) mod tsc;
+#[cfg(all(feature = "coarsetime-support", not(target_arch = "wasm32")))]
+mod updater;
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+pub(crate) mod wasm;
+
+pub use anchored::AnchoredClock;
+pub use backend::{ClockSource, set_source, source};
+pub use corrected::CorrectedClock;
+pub use drift::{DriftSampler, DriftStats, measure_drift};
+#[cfg(feature = "clock-override")]
+pub use global::{FrozenTime, clear_global, freeze_time_at, set_global};
+pub use log::ClockLog;
+pub use mock::MockClock;
+#[cfg(feature = "clock-replay")]
+pub use replay::{start_recording, start_replay, stop_recording, stop_replay};
+#[cfg(feature = "coarsetime-support")]
+pub use source::CoarseClock;
+pub use source::{Clock, SystemClock};
+#[cfg(all(feature = "tsc-clock", target_arch = "x86_64"))]
+pub use tsc::TscClock;
+#[cfg(all(feature = "coarsetime-support", not(target_arch = "wasm32")))]
+pub use updater::{CoarseUpdater, UpdaterGuard};
@@ -0,0 +1,54 @@
+//! A process wide non-decreasing view of the clock.
+
+use core::sync::atomic::Ordering;
+
+use crate::{AtomicTimestamp, Timestamp};
+
+/// The latest time handed out by [`Timestamp::now_monotonic`].
+static LATEST: AtomicTimestamp = AtomicTimestamp::new(Timestamp::MIN);
+
+/// Clamp `ts` to no earlier than anything handed out before through `latest`.
+#[inline]
+fn never_backwards(latest: &AtomicTimestamp, ts: Timestamp) -> Timestamp {
+    latest.fetch_max(ts, Ordering::AcqRel).max(ts)
+}
+
+impl Timestamp {
+    /// Read the current time like [`Timestamp::now`], but never earlier than any previous call
+    /// in this process, on any thread.
+    ///
+    /// When the system clock is stepped back, e.g. by NTP, the time stands still until the clock
+    /// has caught up again, so that events ordered by these timestamps stay in order. Successive
+    /// calls may return equal timestamps.
+    #[inline]
+    pub fn now_monotonic() -> Self {
+        never_backwards(&LATEST, Self::now())
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use super::never_backwards;
+    use crate::*;
+
+    #[test]
+    fn stepped_back_clock() {
+        let latest = AtomicTimestamp::default();
+        let ts = Timestamp::from_seconds(1_714_570_200);
+        assert_eq!(never_backwards(&latest, ts), ts);
+        assert_eq!(never_backwards(&latest, ts - TimeDelta::from_seconds(1)), ts);
+        let later = ts + TimeDelta::from_nanoseconds(1);
+        assert_eq!(never_backwards(&latest, later), later);
+
+        let mut prev = Timestamp::now_monotonic();
+        for _ in 0..1_000 {
+            let ts = Timestamp::now_monotonic();
+            assert!(ts >= prev);
+            prev = ts;
+        }
+    }
+}
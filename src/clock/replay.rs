@@ -0,0 +1,77 @@
+//! Process wide recording and replaying of clock reads.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use crate::clock::ClockLog;
+use crate::Timestamp;
+
+const LIVE: u8 = 0;
+const RECORDING: u8 = 1;
+const REPLAYING: u8 = 2;
+
+/// Checked on every read without locking, so the live clock stays fast.
+static MODE: AtomicU8 = AtomicU8::new(LIVE);
+/// Reads recorded so far, or reads still to be replayed.
+static READS: Mutex<VecDeque<Timestamp>> = Mutex::new(VecDeque::new());
+
+fn reads() -> MutexGuard<'static, VecDeque<Timestamp>> {
+    // The queue stays consistent even if a holder panicked.
+    READS.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Read the clock through `live`, recording or replaying according to the current mode.
+pub(crate) fn intercept(live: fn() -> Timestamp) -> Timestamp {
+    if MODE.load(Ordering::Acquire) == LIVE {
+        return live();
+    }
+
+    // Read the clock under the lock, so the log order matches the order of the reads.
+    let mut reads = reads();
+    match MODE.load(Ordering::Relaxed) {
+        RECORDING => {
+            let ts = live();
+            reads.push_back(ts);
+            ts
+        }
+        REPLAYING => reads.pop_front().expect("clock replay log exhausted"),
+        _ => live(),
+    }
+}
+
+/// Start recording every clock read, discarding an unfinished recording or replay.
+pub fn start_recording() {
+    let mut reads = reads();
+    reads.clear();
+    MODE.store(RECORDING, Ordering::Release);
+}
+
+/// Stop recording and return the reads since [`start_recording`].
+pub fn stop_recording() -> ClockLog {
+    let mut reads = reads();
+    MODE.store(LIVE, Ordering::Release);
+    reads.drain(..).collect()
+}
+
+/// Answer clock reads from `log` instead of the clock, in order.
+///
+/// Replays are only deterministic if the process reads the clock in the same order as during
+/// recording, which multi-threaded processes may not.
+///
+/// # Panics
+///
+/// Reading the clock panics once the log is exhausted, since the run has diverged from the
+/// recorded one.
+pub fn start_replay(log: ClockLog) {
+    let mut reads = reads();
+    *reads = log.reads().iter().copied().collect();
+    MODE.store(REPLAYING, Ordering::Release);
+}
+
+/// Return to the live clock, returning the reads that weren't replayed.
+pub fn stop_replay() -> ClockLog {
+    let mut reads = reads();
+    MODE.store(LIVE, Ordering::Release);
+    reads.drain(..).collect()
+}
@@ -0,0 +1,121 @@
+//! Clocks to pass to code that reads the time, instead of calling [`Timestamp::now`] directly.
+
+use std::sync::Arc;
+
+use crate::Timestamp;
+
+/// A source of the current time.
+///
+/// Take a clock as a parameter so that callers pick the clock, and tests can pass one they
+/// control:
+///
+/// ```
+/// use fast_utc::clock::{Clock, SystemClock};
+/// use fast_utc::{TimeDelta, Timestamp};
+///
+/// fn is_expired(deadline: Timestamp, clock: &impl Clock) -> bool {
+///     Timestamp::now_with(clock) >= deadline
+/// }
+///
+/// assert!(is_expired(Timestamp::from_seconds(1_714_570_200), &SystemClock));
+/// ```
+pub trait Clock {
+    /// Read the current time.
+    fn now(&self) -> Timestamp;
+}
+
+/// The precise system clock, `SystemTime::now()`, or `Date.now()` on `wasm32-unknown-unknown`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now(&self) -> Timestamp {
+        #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+        return crate::clock::wasm::read();
+        #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+        return Timestamp::now_system();
+    }
+}
+
+/// The cached coarse clock of `coarsetime`, see [`Timestamp::coarse_last_update`].
+///
+/// It only advances when `coarsetime_update()` is called or an updater thread is running.
+#[cfg(feature = "coarsetime-support")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CoarseClock;
+
+#[cfg(feature = "coarsetime-support")]
+impl Clock for CoarseClock {
+    #[inline]
+    fn now(&self) -> Timestamp {
+        Timestamp::coarse_last_update()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    #[inline]
+    fn now(&self) -> Timestamp {
+        (**self).now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Box<C> {
+    #[inline]
+    fn now(&self) -> Timestamp {
+        (**self).now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    #[inline]
+    fn now(&self) -> Timestamp {
+        (**self).now()
+    }
+}
+
+impl Timestamp {
+    /// Read the current time from `clock`.
+    ///
+    /// Unlike [`Timestamp::now`], reads aren't recorded or replayed with `clock-replay`.
+    #[inline]
+    pub fn now_with(clock: &impl Clock) -> Self {
+        clock.now()
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::clock::{Clock, SystemClock};
+    use std::sync::Arc;
+
+    struct Fixed(Timestamp);
+
+    impl Clock for Fixed {
+        fn now(&self) -> Timestamp {
+            self.0
+        }
+    }
+
+    #[test]
+    fn pluggable_clocks() {
+        let ts = Timestamp::from_seconds(1_714_570_200);
+        assert_eq!(Timestamp::now_with(&Fixed(ts)), ts);
+        let shared: Arc<dyn Clock + Send + Sync> = Arc::new(Fixed(ts));
+        assert_eq!(Timestamp::now_with(&shared), ts);
+        let boxed: Box<dyn Clock> = Box::new(Fixed(ts));
+        assert_eq!(Timestamp::now_with(&&*boxed), ts);
+
+        assert!(Timestamp::now_with(&SystemClock) > ts);
+        #[cfg(feature = "coarsetime-support")]
+        {
+            coarsetime_update();
+            assert_eq!(Timestamp::now_with(&clock::CoarseClock), Timestamp::coarse_last_update());
+        }
+    }
+}
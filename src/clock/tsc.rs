@@ -0,0 +1,206 @@
+//! Clock synthesizing wall clock time from the CPU's time stamp counter, for `tsc-clock`.
+//!
+//! Reading the TSC takes a few nanoseconds, far less than even a vDSO `clock_gettime`. The
+//! counter is calibrated against the system clock once over a millisecond, then periodically
+//! re-anchored to it, refining the frequency over the longer interval each time.
+
+use std::sync::atomic::{AtomicU64, Ordering, fence};
+use std::sync::{Mutex, OnceLock};
+
+use crate::clock::Clock;
+use crate::{TimeDelta, Timestamp};
+
+/// Read the time stamp counter.
+#[inline]
+fn rdtsc() -> u64 {
+    // SAFETY: `rdtsc` is available on every x86_64 CPU.
+    unsafe { core::arch::x86_64::_rdtsc() }
+}
+
+/// Check whether the TSC ticks at a constant rate in all power states, and so measures time.
+fn has_invariant_tsc() -> bool {
+    use core::arch::x86_64::__cpuid;
+
+    __cpuid(0x8000_0000).eax >= 0x8000_0007 && __cpuid(0x8000_0007).edx & (1 << 8) != 0
+}
+
+/// Read the system clock together with the TSC at about the same instant.
+fn read_pair() -> (u64, u64) {
+    let before = rdtsc();
+    let ns = Timestamp::now_system().0;
+    let after = rdtsc();
+    (before + (after - before) / 2, ns)
+}
+
+/// Nanoseconds per tick as a 32.32 fixed point number.
+fn mult(ticks: u64, nanos: u64) -> u64 {
+    ((u128::from(nanos) << 32) / u128::from(ticks.max(1))) as u64
+}
+
+/// A clock synthesizing nanosecond UTC timestamps from the TSC of x86_64 CPUs.
+///
+/// The time is re-anchored to the system clock every second by default, during a read on
+/// whichever thread notices first, so the clock steps by the error accumulated since, usually
+/// well below a microsecond. Use [`Timestamp::now_monotonic`] if that must never go backwards.
+///
+/// Reads are lock-free. Only CPUs with an invariant TSC are supported, see
+/// [`calibrate`](Self::calibrate).
+#[derive(Debug)]
+pub struct TscClock {
+    /// Odd while the anchor is being replaced.
+    seq: AtomicU64,
+    anchor_tsc: AtomicU64,
+    anchor_ns: AtomicU64,
+    mult: AtomicU64,
+    interval_ticks: AtomicU64,
+    recalibrating: Mutex<()>,
+}
+
+impl TscClock {
+    /// The default interval between re-anchoring to the system clock.
+    pub const DEFAULT_RECALIBRATION_INTERVAL: TimeDelta = TimeDelta::from_seconds(1);
+
+    /// Calibrate the TSC against the system clock, busy waiting for about a millisecond.
+    ///
+    /// Returns `None` if the CPU lacks an invariant TSC, e.g. on old CPUs or in some virtual
+    /// machines, where its rate changes with the power state.
+    pub fn calibrate() -> Option<Self> {
+        if !has_invariant_tsc() {
+            return None;
+        }
+        let (tsc0, ns0) = read_pair();
+        let (mut tsc1, mut ns1) = read_pair();
+        while ns1 < ns0 + 1_000_000 {
+            (tsc1, ns1) = read_pair();
+        }
+        let mult = mult(tsc1 - tsc0, ns1 - ns0);
+        let clock = TscClock {
+            seq: AtomicU64::new(0),
+            anchor_tsc: AtomicU64::new(tsc1),
+            anchor_ns: AtomicU64::new(ns1),
+            mult: AtomicU64::new(mult),
+            interval_ticks: AtomicU64::new(0),
+            recalibrating: Mutex::new(()),
+        };
+        clock.set_recalibration_interval(Self::DEFAULT_RECALIBRATION_INTERVAL);
+        Some(clock)
+    }
+
+    /// Change how often the clock is re-anchored to the system clock, never if zero or negative.
+    pub fn set_recalibration_interval(&self, interval: TimeDelta) {
+        let nanos = u64::try_from(interval.0).ok().filter(|&n| n > 0);
+        let ticks = nanos.map_or(u64::MAX, |n| ((u128::from(n) << 32) / u128::from(self.load().2)) as u64);
+        self.interval_ticks.store(ticks, Ordering::Relaxed);
+    }
+
+    /// The calibrated frequency of the TSC.
+    pub fn frequency_hz(&self) -> u64 {
+        ((1_000_000_000u128 << 32) / u128::from(self.load().2)) as u64
+    }
+
+    /// Re-anchor to the system clock now, refining the frequency over the time since the last
+    /// anchor.
+    pub fn recalibrate(&self) {
+        let _guard = self.recalibrating.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.reanchor();
+    }
+
+    /// Replace the anchor, with `recalibrating` held.
+    fn reanchor(&self) {
+        let (anchor_tsc, anchor_ns, _) = self.load();
+        let (tsc, ns) = read_pair();
+        if tsc <= anchor_tsc || ns <= anchor_ns {
+            // The system clock was stepped back, keep the frequency and only move the anchor.
+            self.store(tsc, ns, self.load().2);
+        } else {
+            self.store(tsc, ns, mult(tsc - anchor_tsc, ns - anchor_ns));
+        }
+    }
+
+    /// Load a consistent anchor and multiplier.
+    #[inline]
+    fn load(&self) -> (u64, u64, u64) {
+        loop {
+            let seq = self.seq.load(Ordering::Acquire);
+            if seq % 2 == 1 {
+                core::hint::spin_loop();
+                continue;
+            }
+            let anchor = (
+                self.anchor_tsc.load(Ordering::Relaxed),
+                self.anchor_ns.load(Ordering::Relaxed),
+                self.mult.load(Ordering::Relaxed),
+            );
+            fence(Ordering::Acquire);
+            if self.seq.load(Ordering::Relaxed) == seq {
+                return anchor;
+            }
+        }
+    }
+
+    /// Store a new anchor and multiplier, with `recalibrating` held.
+    fn store(&self, anchor_tsc: u64, anchor_ns: u64, mult: u64) {
+        let seq = self.seq.load(Ordering::Relaxed);
+        self.seq.store(seq + 1, Ordering::Relaxed);
+        fence(Ordering::Release);
+        self.anchor_tsc.store(anchor_tsc, Ordering::Relaxed);
+        self.anchor_ns.store(anchor_ns, Ordering::Relaxed);
+        self.mult.store(mult, Ordering::Relaxed);
+        self.seq.store(seq + 2, Ordering::Release);
+    }
+}
+
+impl Clock for TscClock {
+    #[inline]
+    fn now(&self) -> Timestamp {
+        let tsc = rdtsc();
+        let (anchor_tsc, anchor_ns, mult) = self.load();
+        let ticks = tsc.saturating_sub(anchor_tsc);
+        if ticks > self.interval_ticks.load(Ordering::Relaxed)
+            && let Ok(_guard) = self.recalibrating.try_lock()
+        {
+            self.reanchor();
+        }
+        let elapsed = (u128::from(ticks) * u128::from(mult)) >> 32;
+        Timestamp(anchor_ns.saturating_add(elapsed as u64))
+    }
+}
+
+/// The clock behind [`Timestamp::now`] with `tsc-clock`, calibrated on first use, or `None`
+/// without an invariant TSC.
+pub(crate) fn global() -> Option<&'static TscClock> {
+    static CLOCK: OnceLock<Option<TscClock>> = OnceLock::new();
+    CLOCK.get_or_init(TscClock::calibrate).as_ref()
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::clock::{Clock, TscClock};
+
+    #[test]
+    fn tsc_tracks_system_clock() {
+        // Without an invariant TSC, e.g. in some virtual machines, there is nothing to test.
+        let Some(clock) = TscClock::calibrate() else { return };
+        assert!(clock.frequency_hz() > 100_000_000);
+
+        let tolerance = TimeDelta::from_milliseconds(5);
+        let mut prev = clock.now();
+        assert!((prev - Timestamp::now_system()).abs() < tolerance);
+        for _ in 0..10_000 {
+            let ts = clock.now();
+            assert!(ts >= prev);
+            prev = ts;
+        }
+
+        clock.set_recalibration_interval(TimeDelta::from_nanoseconds(1));
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        assert!((clock.now() - Timestamp::now_system()).abs() < tolerance);
+        clock.recalibrate();
+        assert!((clock.now() - Timestamp::now_system()).abs() < tolerance);
+    }
+}
@@ -0,0 +1,109 @@
+//! A background thread refreshing the coarse clock of `coarsetime`, stopped when dropped.
+
+use std::io;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::TimeDelta;
+
+/// Configures the thread refreshing `coarsetime`'s clock, which [`Timestamp::now`] reads with
+/// the coarse clock source and `coarsetime-support`.
+///
+/// ```
+/// use fast_utc::clock::CoarseUpdater;
+/// use fast_utc::TimeDelta;
+///
+/// let updater = CoarseUpdater::new(TimeDelta::from_milliseconds(5))
+///     .thread_name("clock-updater")
+///     .start()
+///     .unwrap();
+/// // The thread is stopped and joined once `updater` is dropped.
+/// ```
+///
+/// [`Timestamp::now`]: crate::Timestamp::now
+#[derive(Clone, Debug)]
+pub struct CoarseUpdater {
+    interval: TimeDelta,
+    name: String,
+}
+
+impl CoarseUpdater {
+    /// Refresh the clock every `interval`, which bounds how far it lags behind.
+    pub fn new(interval: TimeDelta) -> Self {
+        CoarseUpdater { interval, name: "fast_utc-coarse-updater".to_owned() }
+    }
+
+    /// Name the thread, `fast_utc-coarse-updater` by default.
+    pub fn thread_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Refresh the clock once and start the thread.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidInput`] unless the interval is positive, or if the
+    /// thread can't be spawned.
+    pub fn start(self) -> io::Result<UpdaterGuard> {
+        let interval = Duration::try_from(self.interval)
+            .ok()
+            .filter(|interval| !interval.is_zero())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "updater interval must be positive"))?;
+        coarsetime::Clock::update();
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let stopped = Arc::clone(&stop);
+        let thread = thread::Builder::new().name(self.name).spawn(move || {
+            while !stopped.load(Ordering::Acquire) {
+                thread::park_timeout(interval);
+                coarsetime::Clock::update();
+            }
+        })?;
+        Ok(UpdaterGuard { stop, thread: Some(thread) })
+    }
+}
+
+/// Keeps the thread started by [`CoarseUpdater::start`] running, stopping and joining it when
+/// dropped.
+#[derive(Debug)]
+#[must_use = "the updater thread stops when the guard is dropped"]
+pub struct UpdaterGuard {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for UpdaterGuard {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            // A panic in the thread is not worth a second one here.
+            let _ = thread.join();
+        }
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::clock::CoarseUpdater;
+
+    #[test]
+    fn updater_guard() {
+        let updater = CoarseUpdater::new(TimeDelta::from_milliseconds(1)).thread_name("test-updater");
+        let guard = updater.start().unwrap();
+        assert_eq!(guard.thread.as_ref().unwrap().thread().name(), Some("test-updater"));
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        let lag = Timestamp::now_system() - Timestamp::coarse_last_update();
+        assert!(lag < TimeDelta::from_milliseconds(20));
+        drop(guard);
+
+        assert!(CoarseUpdater::new(TimeDelta::zero()).start().is_err());
+        assert!(CoarseUpdater::new(TimeDelta::from_seconds(-1)).start().is_err());
+    }
+}
@@ -0,0 +1,61 @@
+//! Clock backend for `wasm32-unknown-unknown`, where `SystemTime::now()` panics.
+//!
+//! `Date.now()` only has millisecond resolution, so the wall clock is anchored to
+//! `performance.now()`, which counts fractional milliseconds. The monotonic clock drifts from the
+//! wall clock, e.g. when it pauses in a suspended tab or the system time is adjusted, so the
+//! anchor is renewed whenever the two disagree by more than a millisecond. Without a
+//! `performance` global, e.g. in some embedded runtimes, every read falls back to `Date.now()`.
+
+use core::cell::Cell;
+
+use js_sys::{Date, Function, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+
+use crate::{TimeDelta, Timestamp};
+
+/// How far the anchored time may stray from `Date.now()` before the anchor is renewed.
+const MAX_DRIFT: TimeDelta = TimeDelta::from_milliseconds(1);
+
+thread_local! {
+    static ANCHOR: Option<Anchor> = Anchor::new();
+}
+
+/// A wall clock reading paired with the monotonic clock at the same instant.
+struct Anchor {
+    performance: JsValue,
+    now: Function,
+    date: Cell<Timestamp>,
+    origin: Cell<f64>,
+}
+
+impl Anchor {
+    fn new() -> Option<Self> {
+        let performance = Reflect::get(&js_sys::global(), &JsValue::from_str("performance")).ok()?;
+        let now = Reflect::get(&performance, &JsValue::from_str("now")).ok()?.dyn_into::<Function>().ok()?;
+        let origin = now.call0(&performance).ok()?.as_f64()?;
+        let date = Timestamp::from_milliseconds(Date::now() as u64);
+        Some(Anchor { performance, now, date: Cell::new(date), origin: Cell::new(origin) })
+    }
+
+    fn read(&self) -> Option<Timestamp> {
+        let perf = self.now.call0(&self.performance).ok()?.as_f64()?;
+        let elapsed = TimeDelta(((perf - self.origin.get()) * 1e6) as i64);
+        let anchored = self.date.get().saturating_add(elapsed);
+
+        // `Date.now()` truncates, so the anchored time is normally up to a millisecond ahead.
+        let wall = Timestamp::from_milliseconds(Date::now() as u64);
+        let ahead = anchored - wall;
+        if ahead < -MAX_DRIFT || ahead > MAX_DRIFT * 2 {
+            self.date.set(wall);
+            self.origin.set(perf);
+            return Some(wall);
+        }
+        Some(anchored)
+    }
+}
+
+/// Read the current time, with sub-millisecond resolution where `performance.now()` exists.
+pub(crate) fn read() -> Timestamp {
+    let anchored = ANCHOR.with(|anchor| anchor.as_ref().and_then(Anchor::read));
+    anchored.unwrap_or_else(|| Timestamp::from_milliseconds(Date::now() as u64))
+}
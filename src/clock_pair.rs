@@ -0,0 +1,185 @@
+//! Reconciling timestamps taken by two different clocks.
+
+use std::collections::VecDeque;
+
+use crate::{TimeDelta, Timestamp};
+
+// ============================================================================================== //
+// [ClockPairTracker]                                                                             //
+// ============================================================================================== //
+
+/// Estimates offset and drift between a remote clock and the local one.
+///
+/// Feed it pairs of the same event's timestamp on both clocks, e.g. an exchange's send time and
+/// the gateway's receive time. The tracker fits `local - remote` as a linear function of the
+/// remote time by least squares over the pairs within a trailing window, and maps timestamps
+/// between the two time bases with that fit. One way latency is indistinguishable from offset,
+/// so the estimated offset includes the average latency within the window.
+///
+/// ```
+/// use fast_utc::{ClockPairTracker, TimeDelta, Timestamp};
+///
+/// let mut tracker = ClockPairTracker::new(TimeDelta::from_minutes(1));
+/// for secs in 0..10 {
+///     let remote = Timestamp::from_seconds(1_000 + secs);
+///     tracker.push(remote, remote + TimeDelta::from_microseconds(250));
+/// }
+/// assert_eq!(tracker.offset(), Some(TimeDelta::from_microseconds(250)));
+/// let local = Timestamp::from_seconds(2_000);
+/// assert_eq!(tracker.to_remote(local), Some(Timestamp::from_nanoseconds(1_999_999_750_000)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ClockPairTracker {
+    window: TimeDelta,
+    /// `(remote, local)` pairs within the window, ordered by remote time.
+    pairs: VecDeque<(Timestamp, Timestamp)>,
+}
+
+/// Least squares fit of `local - remote = offset + drift * (remote - origin)`.
+#[derive(Copy, Clone, Debug)]
+struct Fit {
+    origin: Timestamp,
+    /// Nanoseconds.
+    offset: f64,
+    /// Nanoseconds per nanosecond.
+    drift: f64,
+}
+
+impl ClockPairTracker {
+    /// Create a tracker fitting the pairs within `window` of remote time.
+    pub fn new(window: TimeDelta) -> Self {
+        ClockPairTracker { window, pairs: VecDeque::new() }
+    }
+
+    /// Add a pair of timestamps of the same event. Pairs older than the newest one are ignored.
+    pub fn push(&mut self, remote: Timestamp, local: Timestamp) {
+        if self.pairs.back().is_some_and(|&(newest, _)| remote < newest) {
+            return;
+        }
+        self.pairs.push_back((remote, local));
+
+        let horizon = remote.saturating_sub(self.window);
+        while self.pairs.front().is_some_and(|&(oldest, _)| oldest < horizon) {
+            self.pairs.pop_front();
+        }
+    }
+
+    /// Estimated `local - remote` at the newest pair, `None` before the first pair.
+    pub fn offset(&self) -> Option<TimeDelta> {
+        self.offset_at(self.pairs.back()?.0)
+    }
+
+    /// Estimated `local - remote` at the given remote time.
+    pub fn offset_at(&self, remote: Timestamp) -> Option<TimeDelta> {
+        let fit = self.fit()?;
+        Some(TimeDelta::from_nanoseconds(fit.offset_at(remote).round() as i64))
+    }
+
+    /// Rate at which the local clock gains on the remote one, in nanoseconds per second.
+    ///
+    /// `None` until two pairs with distinct remote times were seen.
+    pub fn drift(&self) -> Option<f64> {
+        let (&(first, _), &(last, _)) = (self.pairs.front()?, self.pairs.back()?);
+        if first == last {
+            return None;
+        }
+        Some(self.fit()?.drift * 1e9)
+    }
+
+    /// Map a remote timestamp to the local time base.
+    pub fn to_local(&self, remote: Timestamp) -> Option<Timestamp> {
+        let fit = self.fit()?;
+        Some(remote.saturating_add(TimeDelta::from_nanoseconds(fit.offset_at(remote).round() as i64)))
+    }
+
+    /// Map a local timestamp to the remote time base.
+    pub fn to_remote(&self, local: Timestamp) -> Option<Timestamp> {
+        let fit = self.fit()?;
+        // Solve `local = remote + offset + drift * (remote - origin)` for `remote`.
+        let since_origin = (local.0 as i128 - fit.origin.0 as i128) as f64;
+        let remote_since_origin = (since_origin - fit.offset) / (1.0 + fit.drift);
+        Some(fit.origin.saturating_add(TimeDelta::from_nanoseconds(remote_since_origin.round() as i64)))
+    }
+
+    /// Number of pairs currently within the window.
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Check whether no pairs are within the window.
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    fn fit(&self) -> Option<Fit> {
+        let origin = self.pairs.front()?.0;
+        // Relative to the oldest pair, so the sums stay well within f64 precision.
+        let points = self.pairs.iter().map(|&(remote, local)| {
+            let x = (remote.0 - origin.0) as f64;
+            let y = (local.0 as i128 - remote.0 as i128) as f64;
+            (x, y)
+        });
+
+        let n = self.pairs.len() as f64;
+        let (sum_x, sum_y) = points.clone().fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+        let (mean_x, mean_y) = (sum_x / n, sum_y / n);
+        let (cov, var) = points.fold((0.0, 0.0), |(cov, var), (x, y)| {
+            (cov + (x - mean_x) * (y - mean_y), var + (x - mean_x) * (x - mean_x))
+        });
+
+        let drift = if var > 0.0 { cov / var } else { 0.0 };
+        Some(Fit { origin, offset: mean_y - drift * mean_x, drift })
+    }
+}
+
+impl Fit {
+    fn offset_at(self, remote: Timestamp) -> f64 {
+        self.offset + self.drift * (remote.0 as i128 - self.origin.0 as i128) as f64
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn clock_pair_offset_and_drift() {
+        let mut tracker = ClockPairTracker::new(TimeDelta::from_seconds(100));
+        assert_eq!(tracker.offset(), None);
+
+        // Local clock 2ms ahead and gaining 10µs per second, with ±1µs of alternating jitter.
+        let start = Timestamp::from_seconds(1_700_000_000);
+        for secs in 0..=200 {
+            let remote = start + TimeDelta::from_seconds(secs);
+            let jitter = if secs % 2 == 0 { 1_000 } else { -1_000 };
+            let local = remote + TimeDelta::from_nanoseconds(2_000_000 + secs * 10_000 + jitter);
+            tracker.push(remote, local);
+        }
+        assert_eq!(tracker.len(), 101);
+
+        let drift = tracker.drift().unwrap();
+        assert!((drift - 10_000.0).abs() < 1.0, "drift = {}", drift);
+
+        let remote = start + TimeDelta::from_seconds(250);
+        let local = tracker.to_local(remote).unwrap();
+        let expected = remote + TimeDelta::from_microseconds(4_500);
+        assert_ts_approx_eq!(local, expected, TimeDelta::from_microseconds(2));
+        assert_ts_approx_eq!(tracker.to_remote(local).unwrap(), remote, TimeDelta::from_nanoseconds(1));
+    }
+
+    #[test]
+    fn clock_pair_single_pair() {
+        let mut tracker = ClockPairTracker::new(TimeDelta::from_seconds(10));
+        let remote = Timestamp::from_seconds(50);
+        tracker.push(remote, Timestamp::from_seconds(49));
+
+        assert_eq!(tracker.drift(), None);
+        assert_eq!(tracker.offset(), Some(TimeDelta::from_seconds(-1)));
+        assert_eq!(tracker.to_local(Timestamp::from_seconds(60)), Some(Timestamp::from_seconds(59)));
+        assert_eq!(tracker.to_remote(Timestamp::from_seconds(0)), Some(Timestamp::from_seconds(1)));
+    }
+}
@@ -0,0 +1,112 @@
+//! Recency weighting with exponential time decay, e.g. for ranking or alert fatigue scores.
+//!
+//! An event's weight halves every `half_life`: an event exactly one half-life old counts half
+//! as much as a fresh one, two half-lives old a quarter, and so on.
+
+use crate::{TimeDelta, Timestamp};
+
+/// Weight of an event at `event_ts` as seen at `now`, in `(0, 1]`.
+///
+/// Events at or after `now` weigh 1.
+///
+/// # Panics
+///
+/// If `half_life` isn't positive.
+pub fn exponential(event_ts: Timestamp, now: Timestamp, half_life: TimeDelta) -> f64 {
+    assert!(half_life.as_nanoseconds() > 0, "half-life must be positive");
+    let age = now.as_nanoseconds().saturating_sub(event_ts.as_nanoseconds());
+    (-(age as f64) / half_life.as_nanoseconds() as f64).exp2()
+}
+
+/// A sum of event amounts, each decaying with the same half-life.
+///
+/// Only the sum as of the newest event is stored. Reading decays it to the requested time
+/// without modifying the accumulator, so reads are cheap and never lose precision.
+///
+/// ```
+/// use fast_utc::decay::DecayedSum;
+/// use fast_utc::{TimeDelta, Timestamp};
+///
+/// let mut sum = DecayedSum::new(TimeDelta::from_hours(1));
+/// sum.add(Timestamp::from_seconds(0), 8.0);
+/// sum.add(Timestamp::from_seconds(3_600), 1.0);
+/// assert_eq!(sum.value(Timestamp::from_seconds(7_200)), 2.5);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DecayedSum {
+    half_life: TimeDelta,
+    /// Decayed sum as of `at`.
+    sum: f64,
+    at: Timestamp,
+}
+
+impl DecayedSum {
+    /// An empty sum with the given half-life.
+    ///
+    /// # Panics
+    ///
+    /// If `half_life` isn't positive.
+    pub fn new(half_life: TimeDelta) -> Self {
+        assert!(half_life.as_nanoseconds() > 0, "half-life must be positive");
+        DecayedSum { half_life, sum: 0.0, at: Timestamp::zero() }
+    }
+
+    /// The half-life of the events.
+    pub fn half_life(&self) -> TimeDelta {
+        self.half_life
+    }
+
+    /// Add an event of weight `amount` at `ts`. Events may arrive out of order.
+    pub fn add(&mut self, ts: Timestamp, amount: f64) {
+        if ts > self.at {
+            self.sum *= exponential(self.at, ts, self.half_life);
+            self.at = ts;
+            self.sum += amount;
+        } else {
+            self.sum += amount * exponential(ts, self.at, self.half_life);
+        }
+    }
+
+    /// The decayed sum as seen at `now`. Events after `now` count fully.
+    pub fn value(&self, now: Timestamp) -> f64 {
+        self.sum * exponential(self.at, now, self.half_life)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::decay::*;
+    use crate::*;
+
+    #[test]
+    fn exponential_weights() {
+        let half_life = TimeDelta::from_minutes(10);
+        let now = Timestamp::from_seconds(10_000);
+
+        assert_eq!(exponential(now, now, half_life), 1.0);
+        assert_eq!(exponential(now + TimeDelta::from_minutes(5), now, half_life), 1.0);
+        assert_eq!(exponential(now - TimeDelta::from_minutes(10), now, half_life), 0.5);
+        assert_eq!(exponential(now - TimeDelta::from_minutes(30), now, half_life), 0.125);
+    }
+
+    #[test]
+    fn decayed_sum_out_of_order() {
+        let half_life = TimeDelta::from_seconds(10);
+        let events = [(40, 3.0), (10, 1.0), (30, 2.0), (0, 5.0)];
+
+        let mut sum = DecayedSum::new(half_life);
+        for (secs, amount) in events {
+            sum.add(Timestamp::from_seconds(secs), amount);
+        }
+
+        let now = Timestamp::from_seconds(50);
+        let weight = |secs| exponential(Timestamp::from_seconds(secs), now, half_life);
+        let expected: f64 = events.iter().map(|&(secs, amount)| amount * weight(secs)).sum();
+        assert!((sum.value(now) - expected).abs() < 1e-12, "{} != {}", sum.value(now), expected);
+        assert_eq!(sum.value(Timestamp::from_seconds(40)), sum.value(Timestamp::zero()));
+    }
+}
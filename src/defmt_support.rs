@@ -0,0 +1,84 @@
+//! `defmt::Format` for logging timestamps and deltas from embedded targets.
+
+use defmt::{Format, Formatter};
+
+use crate::{TimeDelta, Timestamp};
+
+/// A timestamp or delta logged as seconds with nine fraction digits, e.g. `1714570200.500000000`
+/// or `-1.500000000`, see [`Timestamp::split_seconds`] and [`TimeDelta::split_seconds`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SplitSeconds {
+    negative: bool,
+    seconds: u64,
+    nanos: u32,
+}
+
+impl SplitSeconds {
+    #[inline]
+    const fn new(negative: bool, nanos: u64) -> Self {
+        SplitSeconds { negative, seconds: nanos / 1_000_000_000, nanos: (nanos % 1_000_000_000) as u32 }
+    }
+}
+
+/// Log the raw nanoseconds since the epoch, the cheapest representation on the wire.
+impl Format for Timestamp {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=u64}", self.0)
+    }
+}
+
+/// Log the raw nanoseconds with an `ns` suffix.
+impl Format for TimeDelta {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=i64}ns", self.0)
+    }
+}
+
+impl Format for SplitSeconds {
+    fn format(&self, f: Formatter<'_>) {
+        let sign = if self.negative { "-" } else { "" };
+        defmt::write!(f, "{=str}{=u64}.{=u32:09}", sign, self.seconds, self.nanos)
+    }
+}
+
+impl Timestamp {
+    /// Log as seconds and nanoseconds since the epoch rather than raw nanoseconds.
+    #[inline]
+    pub const fn split_seconds(self) -> SplitSeconds {
+        SplitSeconds::new(false, self.0)
+    }
+}
+
+impl TimeDelta {
+    /// Log as seconds and nanoseconds rather than raw nanoseconds.
+    #[inline]
+    pub const fn split_seconds(self) -> SplitSeconds {
+        SplitSeconds::new(self.0 < 0, self.0.unsigned_abs())
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn assert_format<T: defmt::Format>(_: T) {}
+
+    #[test]
+    fn split_seconds() {
+        let ts = Timestamp::from_seconds(1_714_570_200) + TimeDelta::from_milliseconds(500);
+        let split = SplitSeconds { negative: false, seconds: 1_714_570_200, nanos: 500_000_000 };
+        assert_eq!(ts.split_seconds(), split);
+        let delta = TimeDelta::from_milliseconds(-1_500);
+        assert_eq!(delta.split_seconds(), SplitSeconds { negative: true, seconds: 1, nanos: 500_000_000 });
+        let min = TimeDelta(i64::MIN).split_seconds();
+        assert_eq!((min.seconds, min.nanos), (9_223_372_036, 854_775_808));
+
+        assert_format(ts);
+        assert_format(delta);
+        assert_format(ts.split_seconds());
+    }
+}
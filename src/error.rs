@@ -0,0 +1,34 @@
+//! The error type shared by all fallible conversions.
+
+use core::fmt;
+
+// ============================================================================================== //
+// [TimeError]                                                                                    //
+// ============================================================================================== //
+
+/// Why a value couldn't be converted to or from one of the crate's time types.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TimeError {
+    /// The value doesn't fit the target representation.
+    Overflow,
+    /// The value lies before the Unix epoch, which a [`Timestamp`](crate::Timestamp) can't hold.
+    BeforeEpoch,
+    /// The input text isn't in the expected format.
+    InvalidFormat,
+    /// A negative [`TimeDelta`](crate::TimeDelta) where only non-negative ones are allowed.
+    Negative,
+}
+
+impl fmt::Display for TimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TimeError::Overflow => "value out of range for the target time type",
+            TimeError::BeforeEpoch => "time lies before the Unix epoch",
+            TimeError::InvalidFormat => "input is not in the expected time format",
+            TimeError::Negative => "negative duration where a non-negative one is required",
+        })
+    }
+}
+
+impl core::error::Error for TimeError {}
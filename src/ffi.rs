@@ -0,0 +1,84 @@
+//! C ABI for sharing clock reads, alignment and formatting with C and C++ code.
+//!
+//! [`Timestamp`] and [`TimeDelta`] are `#[repr(transparent)]`, so across the ABI they are plain
+//! `uint64_t` nanoseconds since the epoch and `int64_t` nanoseconds respectively:
+//!
+//! ```c
+//! uint64_t fast_utc_now(void);
+//! uint64_t fast_utc_align(uint64_t ts, int64_t freq);
+//! size_t fast_utc_format_rfc3339(uint64_t ts, char *buf, size_t len);
+//! ```
+//!
+//! Link against the crate built with `--features ffi` as a `staticlib` or `cdylib`, e.g.
+//! `cargo rustc --release --features ffi --crate-type staticlib`.
+
+use core::ffi::c_char;
+
+use crate::{TimeDelta, Timestamp};
+
+/// Size of the buffer [`fast_utc_format_rfc3339`] needs, including the terminating NUL.
+pub const FAST_UTC_RFC3339_BUF_LEN: usize = Timestamp::RFC3339_LEN + 1;
+
+/// Read the current time, see [`Timestamp::now`].
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_utc_now() -> Timestamp {
+    Timestamp::now()
+}
+
+/// Floor `ts` to a multiple of `freq` since the epoch, see [`Timestamp::align_to`].
+///
+/// A zero `freq` returns `ts` unchanged instead of panicking, which would abort the process.
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_utc_align(ts: Timestamp, freq: TimeDelta) -> Timestamp {
+    if freq.is_zero() { ts } else { ts.align_to(freq) }
+}
+
+/// Write `ts` as a NUL terminated RFC 3339 string with nanoseconds into `buf`, returning the
+/// length without the NUL, see [`Timestamp::format_rfc3339`].
+///
+/// Returns 0 without writing anything if `buf` is null or `len` is less than
+/// [`FAST_UTC_RFC3339_BUF_LEN`].
+///
+/// # Safety
+///
+/// Unless null, `buf` must be valid for writes of `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fast_utc_format_rfc3339(ts: Timestamp, buf: *mut c_char, len: usize) -> usize {
+    if buf.is_null() || len < FAST_UTC_RFC3339_BUF_LEN {
+        return 0;
+    }
+    // SAFETY: The caller guarantees `len >= FAST_UTC_RFC3339_BUF_LEN` writable bytes at `buf`.
+    let out = unsafe { core::slice::from_raw_parts_mut(buf.cast::<u8>(), FAST_UTC_RFC3339_BUF_LEN) };
+    let written = ts.format_rfc3339(out).len();
+    out[written] = 0;
+    written
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::ffi::*;
+    use crate::*;
+    use core::ffi::CStr;
+
+    #[test]
+    fn c_abi() {
+        let ts = Timestamp::from_seconds(1_714_570_200) + TimeDelta::from_milliseconds(500);
+        let freq = TimeDelta::from_minutes(15);
+        assert_eq!(fast_utc_align(ts, freq), ts.align_to(freq));
+        assert_eq!(fast_utc_align(ts, TimeDelta::zero()), ts);
+
+        let mut buf = [0x7f as core::ffi::c_char; 40];
+        let len = unsafe { fast_utc_format_rfc3339(ts, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, Timestamp::RFC3339_LEN);
+        let s = unsafe { CStr::from_ptr(buf.as_ptr()) };
+        assert_eq!(s.to_str(), Ok("2024-05-01T13:30:00.500000000Z"));
+
+        let short = FAST_UTC_RFC3339_BUF_LEN - 1;
+        assert_eq!(unsafe { fast_utc_format_rfc3339(ts, buf.as_mut_ptr(), short) }, 0);
+        assert_eq!(unsafe { fast_utc_format_rfc3339(ts, core::ptr::null_mut(), 64) }, 0);
+    }
+}
@@ -0,0 +1,361 @@
+//! Allocation free rendering of timestamps, bypassing chrono.
+
+use core::fmt;
+
+use crate::civil::{days_from_civil, days_in_month, NANOS_PER_DAY};
+use crate::{TimeError, Timestamp};
+
+// ============================================================================================== //
+// [RFC 3339]                                                                                     //
+// ============================================================================================== //
+
+impl Timestamp {
+    /// Length of the output of [`format_rfc3339`](Self::format_rfc3339).
+    pub const RFC3339_LEN: usize = 30;
+
+    /// Format as RFC 3339 with nanosecond precision into `buf`, e.g.
+    /// `2024-05-01T13:30:00.500000000Z`, returning the written part of `buf`.
+    ///
+    /// # Panics
+    ///
+    /// If `buf` is shorter than [`RFC3339_LEN`](Self::RFC3339_LEN).
+    pub fn format_rfc3339<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        let buf = &mut buf[..Self::RFC3339_LEN];
+        buf.copy_from_slice(b"0000-00-00T00:00:00.000000000Z");
+
+        let (year, month, day) = self.civil_date();
+        let nanos = self.0 % NANOS_PER_DAY;
+        let secs = nanos / 1_000_000_000;
+        write_digits(&mut buf[0..4], year as u64);
+        write_digits(&mut buf[5..7], month as u64);
+        write_digits(&mut buf[8..10], day as u64);
+        write_digits(&mut buf[11..13], secs / 3_600);
+        write_digits(&mut buf[14..16], secs / 60 % 60);
+        write_digits(&mut buf[17..19], secs % 60);
+        write_digits(&mut buf[20..29], nanos % 1_000_000_000);
+
+        core::str::from_utf8(buf).expect("output is ASCII")
+    }
+
+    /// Format as RFC 3339 with nanosecond precision, see [`format_rfc3339`](Self::format_rfc3339).
+    #[cfg(feature = "std")]
+    pub fn to_rfc3339(&self) -> String {
+        let mut buf = [0; Self::RFC3339_LEN];
+        self.format_rfc3339(&mut buf).to_owned()
+    }
+}
+
+impl Timestamp {
+    /// Parse an RFC 3339 date time in any offset, e.g. `2024-05-01T09:30:00.5-04:00`, without
+    /// chrono or allocating.
+    ///
+    /// The fraction is optional and may have up to nine digits. Like RFC 3339 allows, `t`, `z`
+    /// and a space in place of `T` are accepted too, but leap seconds are not.
+    pub fn parse_rfc3339(s: &str) -> Result<Self, TimeError> {
+        let mut rest = s.as_bytes();
+        let mut fields = take_date_time(&mut rest, b"Tt ")?;
+        fields.offset_secs = match rest {
+            [b'Z' | b'z'] => 0,
+            [sign @ (b'+' | b'-'), offset @ ..] => {
+                let mut offset = offset;
+                let hours = take_digits(&mut offset, 2)?;
+                take_literal(&mut offset, b":")?;
+                let minutes = take_digits(&mut offset, 2)?;
+                if !offset.is_empty() || hours >= 24 || minutes >= 60 {
+                    return Err(TimeError::InvalidFormat);
+                }
+                let secs = (hours * 3_600 + minutes * 60) as i64;
+                if *sign == b'-' { -secs } else { secs }
+            }
+            _ => return Err(TimeError::InvalidFormat),
+        };
+        fields.to_timestamp()
+    }
+}
+
+/// Write `value` as zero padded decimal filling all of `out`, dropping digits that don't fit.
+#[inline]
+pub(crate) fn write_digits(out: &mut [u8], mut value: u64) {
+    for digit in out.iter_mut().rev() {
+        *digit = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+}
+
+// ============================================================================================== //
+// [Display]                                                                                      //
+// ============================================================================================== //
+
+/// Display as `2024-05-01 13:30:00.5 UTC`, the format of chrono's `DateTime<Utc>`.
+///
+/// By default the fraction has as many of 0, 3, 6 or 9 digits as needed. A precision sets the
+/// number of fractional digits, truncating the rest: `{:.3}` shows milliseconds and `{:.0}`
+/// whole seconds. The alternate flag selects the compact ISO 8601 basic format, e.g.
+/// `{:#.3}` gives `20240501T133000.500Z`.
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.0 % 1_000_000_000;
+        let digits = match f.precision() {
+            Some(precision) => precision.min(9),
+            None if nanos == 0 => 0,
+            None if nanos.is_multiple_of(1_000_000) => 3,
+            None if nanos.is_multiple_of(1_000) => 6,
+            None => 9,
+        };
+
+        let mut buf = [0; 40];
+        let len = if f.alternate() {
+            buf[..16].copy_from_slice(b"00000000T000000Z");
+            let len = self.write_civil(&mut buf, [0..4, 4..6, 6..8, 9..11, 11..13, 13..15]);
+            len + write_fraction(&mut buf[len..], nanos, digits, "Z")
+        } else {
+            buf[..19].copy_from_slice(b"0000-00-00 00:00:00");
+            let len = self.write_civil(&mut buf, [0..4, 5..7, 8..10, 11..13, 14..16, 17..19]);
+            len + write_fraction(&mut buf[len..], nanos, digits, " UTC")
+        };
+        f.write_str(core::str::from_utf8(&buf[..len]).expect("output is ASCII"))
+    }
+}
+
+impl Timestamp {
+    /// Write year, month, day, hour, minute and second into the given ranges of `buf`,
+    /// returning the end of the last one.
+    fn write_civil(self, buf: &mut [u8], ranges: [core::ops::Range<usize>; 6]) -> usize {
+        let (year, month, day) = self.civil_date();
+        let secs = self.0 % NANOS_PER_DAY / 1_000_000_000;
+        let values = [year as u64, month as u64, day as u64, secs / 3_600, secs / 60 % 60, secs % 60];
+        for (range, value) in ranges.iter().zip(values) {
+            write_digits(&mut buf[range.clone()], value);
+        }
+        ranges[5].end
+    }
+}
+
+/// Write `.` and the leading `digits` digits of `nanos` (if any) followed by `suffix`.
+fn write_fraction(buf: &mut [u8], nanos: u64, digits: usize, suffix: &str) -> usize {
+    let mut len = 0;
+    if digits > 0 {
+        buf[0] = b'.';
+        write_digits(&mut buf[1..=digits], nanos / 10u64.pow(9 - digits as u32));
+        len = digits + 1;
+    }
+    buf[len..len + suffix.len()].copy_from_slice(suffix.as_bytes());
+    len + suffix.len()
+}
+
+// ============================================================================================== //
+// [Naive date times]                                                                             //
+// ============================================================================================== //
+
+impl Timestamp {
+    /// Format as a date time without zone designator, e.g. `2024-05-01 13:30:00.500`.
+    ///
+    /// The shape used by many databases and CSV exports for UTC times.
+    #[cfg(feature = "std")]
+    pub fn format_naive(&self) -> String {
+        let mut buf = *b"0000-00-00 00:00:00.000";
+        let len = self.write_civil(&mut buf, [0..4, 5..7, 8..10, 11..13, 14..16, 17..19]);
+        write_fraction(&mut buf[len..], self.0 % 1_000_000_000, 3, "");
+        String::from_utf8(buf.to_vec()).expect("output is ASCII")
+    }
+
+    /// Parse a date time without zone designator as UTC, e.g. `2024-05-01 13:30:00.500`.
+    ///
+    /// The fraction is optional and may have up to nine digits.
+    pub fn parse_naive(s: &str) -> Result<Self, TimeError> {
+        let mut rest = s.as_bytes();
+        let fields = take_date_time(&mut rest, b" ")?;
+        if !rest.is_empty() {
+            return Err(TimeError::InvalidFormat);
+        }
+        fields.to_timestamp()
+    }
+}
+
+// ============================================================================================== //
+// [Parsing]                                                                                      //
+// ============================================================================================== //
+
+/// Broken down local date and time, as collected by the parsers.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Fields {
+    pub(crate) year: i64,
+    pub(crate) month: u32,
+    pub(crate) day: u32,
+    pub(crate) hour: u64,
+    pub(crate) minute: u64,
+    pub(crate) second: u64,
+    pub(crate) nanos: u64,
+    /// Local time minus UTC.
+    pub(crate) offset_secs: i64,
+}
+
+impl Default for Fields {
+    fn default() -> Self {
+        Fields { year: 1970, month: 1, day: 1, hour: 0, minute: 0, second: 0, nanos: 0, offset_secs: 0 }
+    }
+}
+
+impl Fields {
+    pub(crate) fn to_timestamp(self) -> Result<Timestamp, TimeError> {
+        let Fields { year, month, day, hour, minute, second, nanos, offset_secs } = self;
+        let valid_date = (1..=12).contains(&month) && day != 0 && day <= days_in_month(year, month);
+        if !valid_date || hour >= 24 || minute >= 60 || second >= 60 {
+            return Err(TimeError::InvalidFormat);
+        }
+
+        let secs_of_day = (hour * 3_600 + minute * 60 + second) as i64;
+        let secs = days_from_civil(year, month, day) * 86_400 + secs_of_day;
+        let total = (secs - offset_secs) as i128 * 1_000_000_000 + nanos as i128;
+        if total < 0 {
+            return Err(TimeError::BeforeEpoch);
+        }
+        u64::try_from(total).map(Timestamp).map_err(|_| TimeError::Overflow)
+    }
+}
+
+/// Consume `YYYY-MM-DD`, one of `separators`, `HH:MM:SS` and an optional fraction of up to nine
+/// digits from the front of `input`.
+fn take_date_time(input: &mut &[u8], separators: &[u8]) -> Result<Fields, TimeError> {
+    let year = take_digits(input, 4)? as i64;
+    take_literal(input, b"-")?;
+    let month = take_digits(input, 2)? as u32;
+    take_literal(input, b"-")?;
+    let day = take_digits(input, 2)? as u32;
+    match input.split_first() {
+        Some((separator, rest)) if separators.contains(separator) => *input = rest,
+        _ => return Err(TimeError::InvalidFormat),
+    }
+    let hour = take_digits(input, 2)?;
+    take_literal(input, b":")?;
+    let minute = take_digits(input, 2)?;
+    take_literal(input, b":")?;
+    let second = take_digits(input, 2)?;
+
+    let mut nanos = 0;
+    if let [b'.', frac @ ..] = *input {
+        let digits = frac.iter().take_while(|b| b.is_ascii_digit()).count();
+        if !(1..=9).contains(&digits) {
+            return Err(TimeError::InvalidFormat);
+        }
+        *input = frac;
+        nanos = take_digits(input, digits)? * 10u64.pow(9 - digits as u32);
+    }
+    Ok(Fields { year, month, day, hour, minute, second, nanos, offset_secs: 0 })
+}
+
+/// Consume `literal` from the front of `input`.
+pub(crate) fn take_literal(input: &mut &[u8], literal: &[u8]) -> Result<(), TimeError> {
+    *input = input.strip_prefix(literal).ok_or(TimeError::InvalidFormat)?;
+    Ok(())
+}
+
+/// Consume exactly `width` ASCII digits from the front of `input`.
+pub(crate) fn take_digits(input: &mut &[u8], width: usize) -> Result<u64, TimeError> {
+    if input.len() < width || !input[..width].iter().all(u8::is_ascii_digit) {
+        return Err(TimeError::InvalidFormat);
+    }
+    let value = input[..width].iter().fold(0, |n, &b| n * 10 + (b - b'0') as u64);
+    *input = &input[width..];
+    Ok(value)
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[cfg(all(feature = "std", feature = "chrono-support"))]
+    #[test]
+    fn rfc3339_matches_chrono() {
+        let mut buf = [0; 64];
+        for i in 0..10_000u64 {
+            let ts = Timestamp::from_nanoseconds(i.wrapping_mul(0x9e37_79b9_7f4a_7c15) % (i64::MAX as u64));
+            let dt = chrono::DateTime::<chrono::Utc>::from(ts);
+            let expected = dt.to_rfc3339_opts(chrono::SecondsFormat::Nanos, true);
+            assert_eq!(ts.format_rfc3339(&mut buf), expected);
+        }
+
+        assert_eq!(Timestamp::zero().to_rfc3339(), "1970-01-01T00:00:00.000000000Z");
+        assert_eq!(Timestamp::MAX.to_rfc3339(), "2554-07-21T23:34:33.709551615Z");
+    }
+
+    #[cfg(feature = "chrono-support")]
+    #[test]
+    fn display_matches_chrono() {
+        for i in 0..10_000u64 {
+            // Round to whole nanos, micros, millis and seconds in turn to cover all fraction lengths.
+            let unit = 1_000u64.pow((i % 4) as u32);
+            let nanos = i.wrapping_mul(0x9e37_79b9_7f4a_7c15) % (i64::MAX as u64);
+            let ts = Timestamp::from_nanoseconds(nanos / unit * unit);
+            assert_eq!(ts.to_string(), chrono::DateTime::<chrono::Utc>::from(ts).to_string());
+        }
+    }
+
+    #[test]
+    fn display_precision_and_alternate() {
+        let ts = Timestamp::from_nanoseconds(1_714_570_200_123_456_789);
+        assert_eq!(format!("{}", ts), "2024-05-01 13:30:00.123456789 UTC");
+        assert_eq!(format!("{:.3}", ts), "2024-05-01 13:30:00.123 UTC");
+        assert_eq!(format!("{:.0}", ts), "2024-05-01 13:30:00 UTC");
+        assert_eq!(format!("{:.12}", ts), "2024-05-01 13:30:00.123456789 UTC");
+        assert_eq!(format!("{:#}", ts), "20240501T133000.123456789Z");
+        assert_eq!(format!("{:#.0}", ts), "20240501T133000Z");
+
+        let ms = Timestamp::from_milliseconds(1_714_570_200_500);
+        assert_eq!(format!("{}", ms), "2024-05-01 13:30:00.500 UTC");
+        assert_eq!(format!("{:.6}", ms), "2024-05-01 13:30:00.500000 UTC");
+        assert_eq!(format!("{:#}", Timestamp::zero()), "19700101T000000Z");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn naive_date_times() {
+        let ts = Timestamp::from_nanoseconds(1_714_570_200_500_999_999);
+        assert_eq!(ts.format_naive(), "2024-05-01 13:30:00.500");
+        assert_eq!(Timestamp::zero().format_naive(), "1970-01-01 00:00:00.000");
+
+        let parse = Timestamp::parse_naive;
+        assert_eq!(parse("2024-05-01 13:30:00.500"), Ok(Timestamp::from_milliseconds(1_714_570_200_500)));
+        assert_eq!(parse("2024-05-01 13:30:00"), Ok(Timestamp::from_seconds(1_714_570_200)));
+        assert_eq!(parse("2024-05-01 13:30:00.500999999"), Ok(ts));
+        let invalid = ["2024-05-01T13:30:00", "2024-05-01 13:30:00.", "2024-05-01 13:30:00Z", "2024-05-01 13:30:00.5000000000"];
+        for invalid in invalid {
+            assert_eq!(parse(invalid), Err(TimeError::InvalidFormat), "{}", invalid);
+        }
+        assert_eq!(parse("1969-12-31 23:59:59"), Err(TimeError::BeforeEpoch));
+    }
+
+    #[cfg(feature = "chrono-support")]
+    #[test]
+    fn parse_rfc3339_matches_chrono() {
+        for i in 0..10_000u64 {
+            let ts = Timestamp::from_nanoseconds(i.wrapping_mul(0x9e37_79b9_7f4a_7c15) % (i64::MAX as u64));
+            let offset = chrono::FixedOffset::east_opt((i % 48) as i32 * 1_800 - 43_200).unwrap();
+            let dt = chrono::DateTime::<chrono::Utc>::from(ts).with_timezone(&offset);
+            let digits = [chrono::SecondsFormat::Secs, chrono::SecondsFormat::Millis, chrono::SecondsFormat::Nanos];
+            let s = dt.to_rfc3339_opts(digits[i as usize % 3], true);
+            assert_eq!(Timestamp::parse_rfc3339(&s), s.parse::<Timestamp>(), "{}", s);
+        }
+
+        let parse = Timestamp::parse_rfc3339;
+        let ts = Timestamp::from_milliseconds(1_714_570_200_500);
+        assert_eq!(parse("2024-05-01T09:30:00.5-04:00"), Ok(ts));
+        assert_eq!(parse("2024-05-01 13:30:00.500z"), Ok(ts));
+        assert_eq!(parse("2024-05-01t13:30:00.500000000+00:00"), Ok(ts));
+        let invalid = ["2024-05-01T13:30:00", "2024-05-01T13:30:00.Z", "2024-05-01T13:30:00+0000", "2024-05-01T13:30:60Z"];
+        for invalid in invalid {
+            assert_eq!(parse(invalid), Err(TimeError::InvalidFormat), "{}", invalid);
+        }
+        assert_eq!(parse("1970-01-01T00:00:00+00:01"), Err(TimeError::BeforeEpoch));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rfc3339_short_buffer() {
+        Timestamp::zero().format_rfc3339(&mut [0; 29]);
+    }
+}
@@ -0,0 +1,210 @@
+//! Conversions between [`Timestamp`] and the timestamp encodings of external tools.
+
+use crate::{TimeError, Timestamp};
+
+// ============================================================================================== //
+// [PCAP]                                                                                         //
+// ============================================================================================== //
+
+impl Timestamp {
+    /// Create a timestamp from the `(ts_sec, ts_frac)` pair of a pcap record header.
+    ///
+    /// `frac` is interpreted as nanoseconds for captures written with the nanosecond magic
+    /// number (`0xa1b23c4d`) and as microseconds otherwise. Fails with
+    /// [`TimeError::InvalidFormat`] if `frac` is a whole second or more, which only a corrupt
+    /// record header contains.
+    #[inline]
+    pub const fn from_pcap(sec: u32, frac: u32, nano_resolution: bool) -> Result<Self, TimeError> {
+        let frac_nanos = if nano_resolution { frac as u64 } else { frac as u64 * 1_000 };
+        if frac_nanos >= 1_000_000_000 {
+            return Err(TimeError::InvalidFormat);
+        }
+        Ok(Timestamp(sec as u64 * 1_000_000_000 + frac_nanos))
+    }
+
+    /// Convert the timestamp to the `(ts_sec, ts_frac)` pair of a pcap record header.
+    ///
+    /// Sub-microsecond precision is truncated unless `nano_resolution` is set. Fails with
+    /// [`TimeError::Overflow`] for timestamps past `2106-02-07 06:28:15 UTC`, which don't fit
+    /// the 32 bit seconds field.
+    #[inline]
+    pub const fn to_pcap(self, nano_resolution: bool) -> Result<(u32, u32), TimeError> {
+        let sec = self.0 / 1_000_000_000;
+        let nanos = (self.0 % 1_000_000_000) as u32;
+        if sec > u32::MAX as u64 {
+            return Err(TimeError::Overflow);
+        }
+        let frac = if nano_resolution { nanos } else { nanos / 1_000 };
+        Ok((sec as u32, frac))
+    }
+
+    /// Create a timestamp from the 64 bit timestamp of a pcapng block.
+    ///
+    /// `if_tsresol` is the interface's resolution option as stored in the file: with the most
+    /// significant bit clear, `units` are `10^-n` seconds, otherwise `2^-n` seconds. pcapng's
+    /// default resolution (no option present) is `6`. Values past `u64::MAX` nanoseconds saturate.
+    ///
+    /// Fails with [`TimeError::InvalidFormat`] for decimal resolutions finer than `10^-38` seconds,
+    /// which no capture tool writes.
+    pub fn from_pcapng(units: u64, if_tsresol: u8) -> Result<Self, TimeError> {
+        let per_sec = pcapng_units_per_sec(if_tsresol).ok_or(TimeError::InvalidFormat)?;
+        let nanos = units as u128 * 1_000_000_000 / per_sec;
+        Ok(Timestamp(nanos.min(u64::MAX as u128) as u64))
+    }
+
+    /// Convert the timestamp to a pcapng 64 bit timestamp with the given `if_tsresol`.
+    ///
+    /// Precision finer than the resolution is truncated. Values that don't fit 64 bits saturate.
+    /// Fails with [`TimeError::InvalidFormat`] for resolutions [`from_pcapng`](Self::from_pcapng)
+    /// rejects.
+    pub fn to_pcapng(self, if_tsresol: u8) -> Result<u64, TimeError> {
+        let per_sec = pcapng_units_per_sec(if_tsresol).ok_or(TimeError::InvalidFormat)?;
+        let units = (self.0 as u128).checked_mul(per_sec).map_or(u128::MAX, |scaled| scaled / 1_000_000_000);
+        Ok(units.min(u64::MAX as u128) as u64)
+    }
+}
+
+/// Number of timestamp units per second for a pcapng `if_tsresol` value.
+///
+/// Returns `None` for resolutions too fine to be represented, which no capture tool writes.
+fn pcapng_units_per_sec(if_tsresol: u8) -> Option<u128> {
+    let exp = u32::from(if_tsresol & 0x7f);
+    if if_tsresol & 0x80 == 0 {
+        10u128.checked_pow(exp)
+    } else {
+        2u128.checked_pow(exp)
+    }
+}
+
+// ============================================================================================== //
+// [Syslog & journald]                                                                            //
+// ============================================================================================== //
+
+impl Timestamp {
+    /// Parse the `TIMESTAMP` field of an RFC 5424 syslog message.
+    ///
+    /// The field is a restricted RFC 3339 date time: upper case `T` and `Z` and at most six
+    /// fractional digits. The NILVALUE `-` is rejected like any other malformed input.
+    pub fn parse_rfc5424(s: &str) -> Result<Self, TimeError> {
+        let bytes = s.as_bytes();
+        if bytes.len() < 20 || bytes[10] != b'T' || s.ends_with('z') {
+            return Err(TimeError::InvalidFormat);
+        }
+        if bytes[19] == b'.' {
+            let frac_digits = bytes[20..].iter().take_while(|b| b.is_ascii_digit()).count();
+            if frac_digits == 0 || frac_digits > 6 {
+                return Err(TimeError::InvalidFormat);
+            }
+        }
+
+        let dt = chrono::DateTime::parse_from_rfc3339(s).map_err(|_| TimeError::InvalidFormat)?;
+        let nanos = dt.timestamp_nanos_opt().ok_or(TimeError::Overflow)?;
+        u64::try_from(nanos).map(Timestamp).map_err(|_| TimeError::BeforeEpoch)
+    }
+
+    /// Format the timestamp as an RFC 5424 syslog `TIMESTAMP`, e.g. `2003-10-11T22:14:15.003000Z`.
+    ///
+    /// RFC 5424 caps the precision at microseconds, finer digits are truncated.
+    pub fn format_rfc5424(self) -> String {
+        chrono::DateTime::<chrono::Utc>::from(self)
+            .to_rfc3339_opts(chrono::SecondsFormat::Micros, true)
+    }
+
+    /// Create a timestamp from a systemd journal `__REALTIME_TIMESTAMP` (microseconds).
+    ///
+    /// Saturates at `u64::MAX` nanoseconds.
+    #[inline]
+    pub const fn from_journald_realtime(usec: u64) -> Self {
+        Timestamp(usec.saturating_mul(1_000))
+    }
+
+    /// Parse the decimal string value of a journal `__REALTIME_TIMESTAMP` field.
+    pub fn parse_journald_realtime(s: &str) -> Result<Self, TimeError> {
+        let usec = s.parse().map_err(|_| TimeError::InvalidFormat)?;
+        Ok(Self::from_journald_realtime(usec))
+    }
+
+    /// Convert to a systemd journal `__REALTIME_TIMESTAMP`, truncating to microseconds.
+    #[inline]
+    pub const fn as_journald_realtime(self) -> u64 {
+        self.as_microseconds()
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn pcap_round_trip() {
+        let ts = Timestamp::from_nanoseconds(1_556_064_000_123_456_789);
+
+        assert_eq!(ts.to_pcap(true), Ok((1_556_064_000, 123_456_789)));
+        assert_eq!(ts.to_pcap(false), Ok((1_556_064_000, 123_456)));
+        assert_eq!(Timestamp::from_pcap(1_556_064_000, 123_456_789, true), Ok(ts));
+        assert_eq!(
+            Timestamp::from_pcap(1_556_064_000, 123_456, false),
+            Ok(Timestamp::from_nanoseconds(1_556_064_000_123_456_000)),
+        );
+        assert_eq!(
+            Timestamp::from_pcap(u32::MAX, 999_999_999, true),
+            Ok(Timestamp::from_nanoseconds(u32::MAX as u64 * 1_000_000_000 + 999_999_999)),
+        );
+        assert_eq!(Timestamp::from_pcap(1, 1_000_000_000, true), Err(TimeError::InvalidFormat));
+        assert_eq!(Timestamp::from_pcap(1, 1_000_000, false), Err(TimeError::InvalidFormat));
+        assert_eq!(Timestamp::from_pcap(1, u32::MAX, false), Err(TimeError::InvalidFormat));
+        assert_eq!(Timestamp::from_seconds(u32::MAX as u64 + 1).to_pcap(true), Err(TimeError::Overflow));
+    }
+
+    #[test]
+    fn pcapng_resolutions() {
+        let ts = Timestamp::from_nanoseconds(1_556_064_000_123_456_789);
+
+        assert_eq!(ts.to_pcapng(6), Ok(1_556_064_000_123_456));
+        assert_eq!(ts.to_pcapng(9), Ok(1_556_064_000_123_456_789));
+        assert_eq!(Timestamp::from_pcapng(1_556_064_000_123_456_789, 9), Ok(ts));
+        assert_eq!(
+            Timestamp::from_pcapng(1_556_064_000_123_456, 6),
+            Ok(Timestamp::from_nanoseconds(1_556_064_000_123_456_000)),
+        );
+        // 2^-10 second units, i.e. 1024 ticks per second.
+        let ticks = Timestamp::from_pcapng(3 * 1024 + 512, 0x80 | 10);
+        assert_eq!(ticks, Ok(Timestamp::from_milliseconds(3_500)));
+
+        assert_eq!(ts.to_pcapng(38), Ok(u64::MAX));
+        assert_eq!(Timestamp::from_pcapng(1, 39), Err(TimeError::InvalidFormat));
+        assert_eq!(Timestamp::from_pcapng(1, 0x80 | 127), Ok(Timestamp::zero()));
+        assert_eq!(ts.to_pcapng(39), Err(TimeError::InvalidFormat));
+    }
+
+    #[test]
+    fn rfc5424_timestamps() {
+        let ts = Timestamp::parse_rfc5424("2003-10-11T22:14:15.003Z").unwrap();
+        assert_eq!(ts, Timestamp::from_milliseconds(1_065_910_455_003));
+        assert_eq!(ts.format_rfc5424(), "2003-10-11T22:14:15.003000Z");
+
+        assert_eq!(
+            Timestamp::parse_rfc5424("2003-08-24T05:14:15.000003-07:00"),
+            Ok(Timestamp::from_nanoseconds(1_061_727_255_000_003_000)),
+        );
+        assert_eq!(Timestamp::parse_rfc5424("-"), Err(TimeError::InvalidFormat));
+        assert_eq!(
+            Timestamp::parse_rfc5424("2003-08-24T05:14:15.000000003-07:00"),
+            Err(TimeError::InvalidFormat),
+        );
+        assert_eq!(Timestamp::parse_rfc5424("2003-10-11t22:14:15.003Z"), Err(TimeError::InvalidFormat));
+        assert_eq!(Timestamp::parse_rfc5424("1969-12-31T23:59:59Z"), Err(TimeError::BeforeEpoch));
+    }
+
+    #[test]
+    fn journald_realtime() {
+        let ts = Timestamp::parse_journald_realtime("1556064000123456").unwrap();
+        assert_eq!(ts, Timestamp::from_nanoseconds(1_556_064_000_123_456_000));
+        assert_eq!(ts.as_journald_realtime(), 1_556_064_000_123_456);
+        assert_eq!(Timestamp::parse_journald_realtime("12ab"), Err(TimeError::InvalidFormat));
+    }
+}
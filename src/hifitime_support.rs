@@ -0,0 +1,112 @@
+//! Conversions between [`Timestamp`] and hifitime's `Epoch`, and leap second aware time scales.
+
+use hifitime::{Duration, Epoch};
+
+use crate::{TimeDelta, TimeError, Timestamp};
+
+// ============================================================================================== //
+// [Epoch]                                                                                        //
+// ============================================================================================== //
+
+/// An epoch in the UTC time scale.
+impl From<Timestamp> for Epoch {
+    fn from(ts: Timestamp) -> Self {
+        Epoch::from_unix_duration(Duration::from_total_nanoseconds(ts.0 as i128))
+    }
+}
+
+/// Converts epochs in any time scale to UTC first. Fails with [`TimeError::BeforeEpoch`] or
+/// [`TimeError::Overflow`] for times outside the range of [`Timestamp`].
+impl TryFrom<Epoch> for Timestamp {
+    type Error = TimeError;
+
+    fn try_from(epoch: Epoch) -> Result<Self, Self::Error> {
+        let nanos = epoch.to_unix_duration().total_nanoseconds();
+        if nanos < 0 {
+            return Err(TimeError::BeforeEpoch);
+        }
+        u64::try_from(nanos).map(Timestamp).map_err(|_| TimeError::Overflow)
+    }
+}
+
+// ============================================================================================== //
+// [TAI and GPS]                                                                                  //
+// ============================================================================================== //
+
+impl Timestamp {
+    /// Offset of TAI from UTC at the timestamp, i.e. the number of leap seconds.
+    ///
+    /// 37 seconds since 2017. Zero before 1972, where the table of leap seconds starts at 10.
+    pub fn tai_offset(self) -> TimeDelta {
+        TimeDelta::from_nanoseconds((self.to_tai_nanoseconds() - self.0) as i64)
+    }
+
+    /// Nanoseconds since 1970-01-01T00:00:00 TAI, as used by PTP.
+    ///
+    /// Unlike Unix time, TAI counts leap seconds, so differences are exact elapsed times.
+    /// Saturates at `u64::MAX` for the last few seconds before [`Timestamp::MAX`].
+    pub fn to_tai_nanoseconds(self) -> u64 {
+        let tai = Epoch::from(self).to_tai_duration() - Epoch::from_ptp_nanoseconds(0).to_tai_duration();
+        u64::try_from(tai.total_nanoseconds()).unwrap_or(u64::MAX)
+    }
+
+    /// The UTC timestamp of nanoseconds since 1970-01-01T00:00:00 TAI.
+    ///
+    /// Fails with [`TimeError::Overflow`] past [`Timestamp::MAX`].
+    pub fn from_tai_nanoseconds(nanos: u64) -> Result<Self, TimeError> {
+        Timestamp::try_from(Epoch::from_ptp_nanoseconds(nanos))
+    }
+
+    /// Nanoseconds since the GPS epoch 1980-01-06T00:00:00 UTC in GPS time, which counts leap
+    /// seconds like TAI. `None` before the GPS epoch.
+    pub fn to_gps_nanoseconds(self) -> Option<u64> {
+        Epoch::from(self).to_gpst_nanoseconds().ok()
+    }
+
+    /// The UTC timestamp of nanoseconds since the GPS epoch in GPS time.
+    ///
+    /// Fails with [`TimeError::Overflow`] past [`Timestamp::MAX`].
+    pub fn from_gps_nanoseconds(nanos: u64) -> Result<Self, TimeError> {
+        Timestamp::try_from(Epoch::from_gpst_nanoseconds(nanos))
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use hifitime::Epoch;
+
+    #[test]
+    fn epoch_conversions() {
+        let ts = Timestamp::from_nanoseconds(1_714_570_200_123_456_789);
+        let epoch = Epoch::from(ts);
+        assert_eq!(epoch, Epoch::from_gregorian_utc(2024, 5, 1, 13, 30, 0, 123_456_789));
+        assert_eq!(Timestamp::try_from(epoch), Ok(ts));
+        assert_eq!(Timestamp::try_from(Epoch::from(Timestamp::MAX)), Ok(Timestamp::MAX));
+        let midnight = Epoch::from_gregorian_utc_at_midnight;
+        assert_eq!(Timestamp::try_from(midnight(1969, 12, 31)), Err(TimeError::BeforeEpoch));
+        assert_eq!(Timestamp::try_from(midnight(2600, 1, 1)), Err(TimeError::Overflow));
+    }
+
+    #[test]
+    fn tai_and_gps() {
+        let ts = Timestamp::from_seconds(1_714_570_200);
+        assert_eq!(ts.tai_offset(), TimeDelta::from_seconds(37));
+        assert_eq!(ts.to_tai_nanoseconds(), 1_714_570_237_000_000_000);
+        assert_eq!(Timestamp::from_tai_nanoseconds(1_714_570_237_000_000_000), Ok(ts));
+        assert_eq!(Timestamp::from_seconds(63_072_000).tai_offset(), TimeDelta::from_seconds(10));
+        assert_eq!(Timestamp::zero().tai_offset(), TimeDelta::zero());
+        assert_eq!(Timestamp::MAX.to_tai_nanoseconds(), u64::MAX);
+
+        // GPS time is 19 seconds behind TAI, 18 ahead of UTC by now.
+        let gps_epoch = Timestamp::from_seconds(315_964_800);
+        assert_eq!(gps_epoch.to_gps_nanoseconds(), Some(0));
+        assert_eq!(ts.to_gps_nanoseconds(), Some((1_714_570_200 - 315_964_800 + 18) * 1_000_000_000));
+        assert_eq!(Timestamp::from_gps_nanoseconds(0), Ok(gps_epoch));
+        assert_eq!((gps_epoch - TimeDelta::SECOND).to_gps_nanoseconds(), None);
+    }
+}
@@ -0,0 +1,273 @@
+//! Human readable durations and relative times, e.g. for logs and dashboards.
+
+use core::fmt;
+
+use crate::TimeDelta;
+#[cfg(feature = "std")]
+use crate::Timestamp;
+
+/// Units used for relative times, largest first. Months and years are 30 and 365 days.
+#[cfg(feature = "std")]
+const UNITS: [(i64, &str); 7] = [
+    (365 * 24 * 3_600_000_000_000, "year"),
+    (30 * 24 * 3_600_000_000_000, "month"),
+    (7 * 24 * 3_600_000_000_000, "week"),
+    (24 * 3_600_000_000_000, "day"),
+    (3_600_000_000_000, "hour"),
+    (60_000_000_000, "minute"),
+    (1_000_000_000, "second"),
+];
+
+// ============================================================================================== //
+// [Relative times]                                                                               //
+// ============================================================================================== //
+
+#[cfg(feature = "std")]
+impl Timestamp {
+    /// Describe the timestamp relative to `now`, e.g. `3 minutes ago` or `in 2 hours`.
+    ///
+    /// Uses the largest unit from seconds up to years that fits, truncating the remainder.
+    /// Differences below a second read `just now`. See
+    /// [`humanize_relative_to_with`](Self::humanize_relative_to_with) for coarser output.
+    ///
+    /// ```
+    /// use fast_utc::{TimeDelta, Timestamp};
+    ///
+    /// let now = Timestamp::from_seconds(1_000_000);
+    /// assert_eq!((now - TimeDelta::from_seconds(200)).humanize_relative_to(now), "3 minutes ago");
+    /// assert_eq!((now + TimeDelta::from_hours(2)).humanize_relative_to(now), "in 2 hours");
+    /// assert_eq!(now.humanize_relative_to(now), "just now");
+    /// ```
+    pub fn humanize_relative_to(self, now: Timestamp) -> String {
+        self.humanize_relative_to_with(now, TimeDelta::from_seconds(1))
+    }
+
+    /// Describe the timestamp relative to `now`, using no unit smaller than `granularity`.
+    ///
+    /// Differences below `granularity` read `just now`, so a granularity of a minute turns
+    /// `45 seconds ago` into `just now` and `90 seconds ago` into `1 minute ago`. Granularities
+    /// between two units allow the smaller one, e.g. 15 minutes gives `20 minutes ago`.
+    ///
+    /// # Panics
+    ///
+    /// If `granularity` isn't positive.
+    pub fn humanize_relative_to_with(self, now: Timestamp, granularity: TimeDelta) -> String {
+        assert!(granularity.as_nanoseconds() > 0, "granularity must be positive");
+        let (diff, past) = match self.0.checked_sub(now.0) {
+            Some(ahead) => (ahead, false),
+            None => (now.0 - self.0, true),
+        };
+        if diff < granularity.as_nanoseconds() as u64 {
+            return "just now".to_owned();
+        }
+
+        // The smallest unit allowed is the largest one not exceeding the granularity.
+        let smallest = UNITS.iter().find(|&&(unit_nanos, _)| unit_nanos <= granularity.as_nanoseconds());
+        let smallest = smallest.map_or(1_000_000_000, |&(unit_nanos, _)| unit_nanos);
+        let (unit_nanos, unit) = UNITS
+            .into_iter()
+            .find(|&(unit_nanos, _)| unit_nanos >= smallest && diff >= unit_nanos as u64)
+            .unwrap_or(UNITS[UNITS.len() - 1]);
+        let count = diff / unit_nanos as u64;
+        let plural = if count == 1 { "" } else { "s" };
+
+        if past {
+            format!("{} {}{} ago", count, unit, plural)
+        } else {
+            format!("in {} {}{}", count, unit, plural)
+        }
+    }
+}
+
+// ============================================================================================== //
+// [Durations]                                                                                    //
+// ============================================================================================== //
+
+/// Components of [`HumanizedDelta`], largest first.
+const COMPONENTS: [(u64, &str); 7] = [
+    (24 * 3_600_000_000_000, "d"),
+    (3_600_000_000_000, "h"),
+    (60_000_000_000, "m"),
+    (1_000_000_000, "s"),
+    (1_000_000, "ms"),
+    (1_000, "us"),
+    (1, "ns"),
+];
+
+/// Display as days, hours, minutes and seconds, e.g. `2h15m3.250s` or `-1d30s`.
+///
+/// Zero components are left out and seconds carry a fraction of 3, 6 or 9 digits as needed.
+/// Deltas below a second use the largest fitting sub-second unit instead, e.g. `250ms` or
+/// `1.5us`. The output parses back into the same delta, and the ISO 8601 form is available
+/// through [`TimeDelta::to_iso8601`].
+impl fmt::Display for TimeDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 == 0 {
+            return f.write_str("0s");
+        }
+        if self.0 < 0 {
+            f.write_str("-")?;
+        }
+
+        let nanos = self.0.unsigned_abs();
+        if nanos < 1_000_000_000 {
+            let sub_second = COMPONENTS[4..].iter().find(|&&(unit, _)| nanos >= unit);
+            let &(unit, name) = sub_second.expect("delta is non-zero");
+            return write_scaled(f, nanos, unit, name);
+        }
+
+        let mut larger = u64::MAX;
+        for &(unit, name) in &COMPONENTS[..3] {
+            let value = nanos % larger / unit;
+            if value != 0 {
+                write!(f, "{}{}", value, name)?;
+            }
+            larger = unit;
+        }
+        let secs = nanos % 60_000_000_000;
+        if secs != 0 {
+            write_scaled(f, secs, 1_000_000_000, "s")?;
+        }
+        Ok(())
+    }
+}
+
+/// Write `value / unit` with a fraction of as many groups of three digits as needed.
+fn write_scaled(f: &mut fmt::Formatter<'_>, value: u64, unit: u64, name: &str) -> fmt::Result {
+    write!(f, "{}", value / unit)?;
+    let (mut frac, mut scale) = (value % unit, unit);
+    if frac != 0 {
+        while frac % 1_000 == 0 {
+            frac /= 1_000;
+            scale /= 1_000;
+        }
+        let width = scale.ilog10() as usize;
+        write!(f, ".{:0width$}", frac, width = width)?;
+    }
+    f.write_str(name)
+}
+
+impl TimeDelta {
+    /// Display with at most `units` components, truncating the rest, e.g. `2h15m` for two.
+    ///
+    /// Components are days, hours, minutes, seconds, milli-, micro- and nanoseconds, counted
+    /// from the largest non-zero one. Zero components within the range are left out, so
+    /// `1d0h5m` shows as `1d` with two units.
+    ///
+    /// ```
+    /// use fast_utc::TimeDelta;
+    ///
+    /// let delta = TimeDelta::from_seconds(8_103) + TimeDelta::from_milliseconds(250);
+    /// assert_eq!(delta.to_string(), "2h15m3.250s");
+    /// assert_eq!(delta.humanize(2).to_string(), "2h15m");
+    /// assert_eq!(delta.humanize(4).to_string(), "2h15m3s250ms");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `units` is zero.
+    pub fn humanize(self, units: usize) -> HumanizedDelta {
+        assert!(units > 0, "at least one unit must be shown");
+        HumanizedDelta { delta: self, units }
+    }
+}
+
+/// A [`TimeDelta`] displayed with a limited number of components, see [`TimeDelta::humanize`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HumanizedDelta {
+    delta: TimeDelta,
+    units: usize,
+}
+
+impl fmt::Display for HumanizedDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.delta.0.unsigned_abs();
+        let Some(first) = COMPONENTS.iter().position(|&(unit, _)| nanos >= unit) else {
+            return f.write_str("0s");
+        };
+        if self.delta.0 < 0 {
+            f.write_str("-")?;
+        }
+
+        let mut larger = u64::MAX;
+        for &(unit, name) in &COMPONENTS[first..(first + self.units).min(COMPONENTS.len())] {
+            let value = nanos % larger / unit;
+            if value != 0 {
+                write!(f, "{}{}", value, name)?;
+            }
+            larger = unit;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn humanize_units() {
+        let now = Timestamp::from_seconds(1_700_000_000);
+        let ago = |delta: TimeDelta| (now - delta).humanize_relative_to(now);
+
+        assert_eq!(ago(TimeDelta::from_milliseconds(999)), "just now");
+        assert_eq!(ago(TimeDelta::from_seconds(1)), "1 second ago");
+        assert_eq!(ago(TimeDelta::from_seconds(59)), "59 seconds ago");
+        assert_eq!(ago(TimeDelta::from_minutes(119)), "1 hour ago");
+        assert_eq!(ago(TimeDelta::from_hours(24 * 13)), "1 week ago");
+        assert_eq!(ago(TimeDelta::from_hours(24 * 45)), "1 month ago");
+        assert_eq!(ago(TimeDelta::from_hours(24 * 800)), "2 years ago");
+        assert_eq!((now + TimeDelta::from_seconds(61)).humanize_relative_to(now), "in 1 minute");
+    }
+
+    #[test]
+    fn delta_display() {
+        let cases = [
+            (TimeDelta::zero(), "0s"),
+            (TimeDelta::from_seconds(8_103) + TimeDelta::from_milliseconds(250), "2h15m3.250s"),
+            (TimeDelta::from_seconds(-86_430), "-1d30s"),
+            (TimeDelta::from_hours(49), "2d1h"),
+            (TimeDelta::from_milliseconds(250), "250ms"),
+            (TimeDelta::from_nanoseconds(1_500), "1.500us"),
+            (TimeDelta::from_nanoseconds(-17), "-17ns"),
+            (TimeDelta::from_seconds(61) + TimeDelta::from_nanoseconds(1), "1m1.000000001s"),
+            (TimeDelta::from_nanoseconds(1_000_000_100), "1.000000100s"),
+        ];
+        for (delta, expected) in cases {
+            assert_eq!(delta.to_string(), expected);
+            assert_eq!(expected.parse(), Ok(delta), "{}", expected);
+        }
+        for nanos in [i64::MIN, i64::MAX, -1, 59_999_999_999] {
+            let delta = TimeDelta::from_nanoseconds(nanos);
+            assert_eq!(delta.to_string().parse(), Ok(delta), "{}", delta);
+        }
+
+        let delta = TimeDelta::from_hours(24) + TimeDelta::from_minutes(5) + TimeDelta::from_microseconds(7);
+        assert_eq!(delta.humanize(1).to_string(), "1d");
+        assert_eq!(delta.humanize(2).to_string(), "1d");
+        assert_eq!(delta.humanize(3).to_string(), "1d5m");
+        assert_eq!(delta.humanize(7).to_string(), "1d5m7us");
+        assert_eq!((-delta).humanize(100).to_string(), "-1d5m7us");
+        assert_eq!(TimeDelta::zero().humanize(2).to_string(), "0s");
+        assert_eq!(TimeDelta::from_nanoseconds(i64::MIN).humanize(1).to_string(), "-106751d");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn humanize_granularity() {
+        let now = Timestamp::from_seconds(1_700_000_000);
+        let rel = |delta: TimeDelta, granularity| (now + delta).humanize_relative_to_with(now, granularity);
+        let (minute, day) = (TimeDelta::from_minutes(1), TimeDelta::from_hours(24));
+
+        assert_eq!(rel(TimeDelta::from_seconds(-45), minute), "just now");
+        assert_eq!(rel(TimeDelta::from_seconds(-90), minute), "1 minute ago");
+        assert_eq!(rel(TimeDelta::from_hours(30), day), "in 1 day");
+        assert_eq!(rel(TimeDelta::from_hours(23), day), "just now");
+        assert_eq!(rel(TimeDelta::from_minutes(-20), TimeDelta::from_minutes(15)), "20 minutes ago");
+    }
+}
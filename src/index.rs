@@ -0,0 +1,223 @@
+//! Lookup structures over sorted timestamp columns.
+
+use core::ops::Range;
+
+use crate::{Interval, Timestamp};
+
+/// Default number of entries per sparse index block: one 4 KiB page of encoded timestamps.
+const DEFAULT_BLOCK_SIZE: usize = 512;
+
+// ============================================================================================== //
+// [Columns]                                                                                      //
+// ============================================================================================== //
+
+/// Random access to a column of timestamps, e.g. a slice or a memory mapped file.
+pub trait TimeColumn {
+    /// Number of timestamps in the column.
+    fn len(&self) -> usize;
+
+    /// Timestamp at `index`, which is guaranteed to be less than [`len`](Self::len).
+    fn get(&self, index: usize) -> Timestamp;
+
+    /// Check whether the column holds no timestamps.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl TimeColumn for [Timestamp] {
+    #[inline]
+    fn len(&self) -> usize {
+        <[Timestamp]>::len(self)
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Timestamp {
+        self[index]
+    }
+}
+
+impl TimeColumn for Vec<Timestamp> {
+    #[inline]
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Timestamp {
+        self[index]
+    }
+}
+
+impl<C: TimeColumn + ?Sized> TimeColumn for &C {
+    #[inline]
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Timestamp {
+        (**self).get(index)
+    }
+}
+
+/// A column of timestamps encoded with [`Timestamp::to_be_bytes`], the layout used for on-disk
+/// timestamp columns.
+///
+/// Big endian encoding keeps the byte-wise order equal to the time order, so the same files
+/// can be used as sorted keys. Typically backed by a memory mapped file.
+#[derive(Copy, Clone, Debug)]
+pub struct BeBytesColumn<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> BeBytesColumn<'a> {
+    /// Wrap encoded timestamps. `None` if the length isn't a multiple of 8 bytes.
+    pub fn new(bytes: &'a [u8]) -> Option<Self> {
+        if bytes.len().is_multiple_of(8) { Some(BeBytesColumn { bytes }) } else { None }
+    }
+
+    /// The underlying bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl TimeColumn for BeBytesColumn<'_> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.bytes.len() / 8
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Timestamp {
+        let offset = index * 8;
+        let raw = self.bytes[offset..offset + 8].try_into().expect("slice is 8 bytes long");
+        Timestamp::from_be_bytes(raw)
+    }
+}
+
+// ============================================================================================== //
+// [TimeIndex]                                                                                    //
+// ============================================================================================== //
+
+/// Index over a column of timestamps sorted in ascending order.
+///
+/// The first timestamp of every block of `block_size` entries is kept in memory, so a lookup
+/// touches the column only within a single block. For memory mapped files this keeps the
+/// number of pages faulted in per lookup constant, no matter how large the file is.
+///
+/// ```
+/// use fast_utc::{Interval, TimeIndex, Timestamp};
+///
+/// let column: Vec<_> = (0..1_000).map(|s| Timestamp::from_seconds(s * 10)).collect();
+/// let index = TimeIndex::new(&column[..]);
+///
+/// assert_eq!(index.locate(Timestamp::from_seconds(25)), 3);
+/// let interval = Interval::new(Timestamp::from_seconds(100), Timestamp::from_seconds(150));
+/// assert_eq!(index.range(interval), 10..15);
+/// ```
+#[derive(Debug)]
+pub struct TimeIndex<C> {
+    column: C,
+    block_size: usize,
+    block_starts: Vec<Timestamp>,
+}
+
+impl<C: TimeColumn> TimeIndex<C> {
+    /// Build an index over a sorted column with the default block size of 512 entries.
+    pub fn new(column: C) -> Self {
+        Self::with_block_size(column, DEFAULT_BLOCK_SIZE)
+    }
+
+    /// Build an index over a sorted column, sampling every `block_size`th entry.
+    ///
+    /// # Panics
+    ///
+    /// If `block_size` is 0.
+    pub fn with_block_size(column: C, block_size: usize) -> Self {
+        assert!(block_size > 0, "block size must be positive");
+        let block_starts = (0..column.len()).step_by(block_size).map(|i| column.get(i)).collect();
+        TimeIndex { column, block_size, block_starts }
+    }
+
+    /// The indexed column.
+    pub fn column(&self) -> &C {
+        &self.column
+    }
+
+    /// Number of entries in the indexed column.
+    pub fn len(&self) -> usize {
+        self.column.len()
+    }
+
+    /// Check whether the indexed column is empty.
+    pub fn is_empty(&self) -> bool {
+        self.column.is_empty()
+    }
+
+    /// Position of the first entry not before `ts`, or [`len`](Self::len) if there is none.
+    pub fn locate(&self, ts: Timestamp) -> usize {
+        // The first block starting at or after `ts` may only be preceded by smaller entries
+        // from the block before it, so the answer lies within `[prev block start, block start]`.
+        let block = self.block_starts.partition_point(|&start| start < ts);
+        if block == 0 {
+            return 0;
+        }
+
+        let mut lo = (block - 1) * self.block_size;
+        let mut hi = (block * self.block_size).min(self.column.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.column.get(mid) < ts {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+
+    /// Positions of the entries within `interval`.
+    pub fn range(&self, interval: Interval) -> Range<usize> {
+        self.locate(interval.start())..self.locate(interval.end())
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn locate_matches_partition_point() {
+        // Duplicates spanning block boundaries are the interesting case.
+        let column: Vec<_> = (0..1_000u64).map(|i| Timestamp::from_seconds(i / 3 * 2)).collect();
+        let index = TimeIndex::with_block_size(&column[..], 7);
+
+        for secs in 0..700 {
+            let ts = Timestamp::from_seconds(secs);
+            assert_eq!(index.locate(ts), column.partition_point(|&x| x < ts), "secs = {}", secs);
+        }
+        assert_eq!(index.locate(Timestamp::from_seconds(10_000)), column.len());
+
+        let interval = Interval::new(Timestamp::from_seconds(20), Timestamp::from_seconds(40));
+        assert_eq!(index.range(interval), 30..60);
+    }
+
+    #[test]
+    fn be_bytes_column() {
+        let bytes: Vec<u8> = (0..100).flat_map(|i| Timestamp::from_nanoseconds(i * 1_000).to_be_bytes()).collect();
+        let column = BeBytesColumn::new(&bytes).unwrap();
+        let index = TimeIndex::with_block_size(column, 16);
+
+        assert_eq!(index.len(), 100);
+        assert_eq!(index.column().get(42), Timestamp::from_nanoseconds(42_000));
+        assert_eq!(index.locate(Timestamp::from_nanoseconds(41_500)), 42);
+        assert!(BeBytesColumn::new(&bytes[1..]).is_none());
+        assert!(TimeIndex::new(Vec::new()).is_empty());
+    }
+}
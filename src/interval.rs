@@ -0,0 +1,157 @@
+//! Half-open intervals between two timestamps.
+
+use core::fmt;
+
+#[cfg(feature = "serde-support")]
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+use crate::{TimeDelta, Timestamp};
+
+// ============================================================================================== //
+// [Interval]                                                                                     //
+// ============================================================================================== //
+
+/// A right open time interval `[start, end)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
+pub struct Interval {
+    start: Timestamp,
+    end: Timestamp,
+}
+
+/// Rejects an `end` before `start`, unlike a derived impl.
+#[cfg(feature = "serde-support")]
+impl<'de> Deserialize<'de> for Interval {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// Same wire format as the derived impl.
+        #[derive(Deserialize)]
+        #[serde(rename = "Interval")]
+        struct Raw {
+            start: Timestamp,
+            end: Timestamp,
+        }
+
+        let Raw { start, end } = Raw::deserialize(deserializer)?;
+        if end < start {
+            return Err(de::Error::custom("interval ends before it starts"));
+        }
+        Ok(Interval { start, end })
+    }
+}
+
+impl Interval {
+    /// Create the interval `[start, end)`. An `end` before `start` yields an empty interval.
+    #[inline]
+    pub const fn new(start: Timestamp, end: Timestamp) -> Self {
+        let end = if end.0 < start.0 { start } else { end };
+        Interval { start, end }
+    }
+
+    /// Create the interval `[start, start + length)`.
+    #[inline]
+    pub fn starting_at(start: Timestamp, length: TimeDelta) -> Self {
+        Self::new(start, start + length)
+    }
+
+    /// First timestamp within the interval.
+    #[inline]
+    pub const fn start(self) -> Timestamp {
+        self.start
+    }
+
+    /// First timestamp after the interval.
+    #[inline]
+    pub const fn end(self) -> Timestamp {
+        self.end
+    }
+
+    /// Length of the interval.
+    #[inline]
+    pub fn duration(self) -> TimeDelta {
+        self.end - self.start
+    }
+
+    /// Check whether the interval contains no timestamps at all.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.start.0 == self.end.0
+    }
+
+    /// Check whether `ts` lies within the interval.
+    #[inline]
+    pub const fn contains(self, ts: Timestamp) -> bool {
+        self.start.0 <= ts.0 && ts.0 < self.end.0
+    }
+
+    /// Check whether the two intervals share at least one timestamp.
+    #[inline]
+    pub const fn overlaps(self, other: Interval) -> bool {
+        // Empty intervals share nothing, even when lying within the other interval.
+        let start = if self.start.0 > other.start.0 { self.start.0 } else { other.start.0 };
+        let end = if self.end.0 < other.end.0 { self.end.0 } else { other.end.0 };
+        start < end
+    }
+}
+
+/// Display as `[start, end)`.
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn construction() {
+        let (start, end) = (Timestamp::from_seconds(10), Timestamp::from_seconds(20));
+        let interval = Interval::new(start, end);
+        assert_eq!((interval.start(), interval.end()), (start, end));
+        assert_eq!(interval.duration(), TimeDelta::from_seconds(10));
+        assert_eq!(Interval::starting_at(start, TimeDelta::from_seconds(10)), interval);
+        assert_eq!(interval.to_string(), format!("[{}, {})", start, end));
+
+        let empty = Interval::new(end, start);
+        assert!(empty.is_empty());
+        assert_eq!((empty.start(), empty.end()), (end, end));
+        assert!(Interval::new(start, start).is_empty());
+    }
+
+    #[test]
+    fn contains_and_overlaps() {
+        let interval = Interval::new(Timestamp::from_seconds(10), Timestamp::from_seconds(20));
+        assert!(interval.contains(Timestamp::from_seconds(10)));
+        assert!(interval.contains(Timestamp::from_seconds(20) - TimeDelta::NANOSECOND));
+        assert!(!interval.contains(Timestamp::from_seconds(20)));
+        assert!(!interval.contains(Timestamp::from_seconds(9)));
+
+        let at = |start, end| Interval::new(Timestamp::from_seconds(start), Timestamp::from_seconds(end));
+        assert!(interval.overlaps(at(15, 25)));
+        assert!(interval.overlaps(at(0, 11)));
+        assert!(interval.overlaps(at(12, 13)));
+        assert!(!interval.overlaps(at(20, 30)));
+        assert!(!interval.overlaps(at(0, 10)));
+        assert!(!interval.overlaps(at(15, 15)));
+    }
+
+    #[cfg(feature = "serde-support")]
+    #[test]
+    fn deserialize_validates() {
+        let interval = Interval::new(Timestamp::from_nanoseconds(1), Timestamp::from_nanoseconds(2));
+        let json = serde_json::to_string(&interval).unwrap();
+        assert_eq!(serde_json::from_str::<Interval>(&json).unwrap(), interval);
+
+        let empty = Interval::new(Timestamp::from_nanoseconds(1), Timestamp::from_nanoseconds(1));
+        assert_eq!(serde_json::from_str::<Interval>(&serde_json::to_string(&empty).unwrap()).unwrap(), empty);
+
+        let reversed = json.replace("start", "tmp").replace("end", "start").replace("tmp", "end");
+        let err = serde_json::from_str::<Interval>(&reversed).unwrap_err();
+        assert!(err.to_string().contains("ends before it starts"), "{}", err);
+    }
+}
@@ -0,0 +1,326 @@
+//! Dumping and reloading timestamped series as CSV or JSON lines.
+//!
+//! Timestamps are written as RFC 3339 with nanosecond precision through the allocation free
+//! formatter, values through their `Display` impl. Reading accepts RFC 3339 in any offset, see
+//! [`Timestamp::parse_rfc3339`], or integer nanoseconds and parses values with their `FromStr`
+//! impl, so a series written by [`write_series`] reads back unchanged.
+//!
+//! ```
+//! use fast_utc::io::{read_series, write_series, SeriesFormat};
+//! use fast_utc::Timestamp;
+//!
+//! let series = vec![(Timestamp::from_seconds(1), 0.5), (Timestamp::from_seconds(2), 1.25)];
+//! let mut out = Vec::new();
+//! write_series(&mut out, series.iter().copied(), SeriesFormat::Csv).unwrap();
+//! assert_eq!(
+//!     String::from_utf8_lossy(&out),
+//!     "timestamp,value\n\
+//!      1970-01-01T00:00:01.000000000Z,0.5\n\
+//!      1970-01-01T00:00:02.000000000Z,1.25\n",
+//! );
+//!
+//! let read: Result<Vec<(Timestamp, f64)>, _> = read_series(&out[..], SeriesFormat::Csv).collect();
+//! assert_eq!(read.unwrap(), series);
+//! ```
+
+use core::fmt::{Display, Write as _};
+use core::marker::PhantomData;
+use core::str::FromStr;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+
+use crate::Timestamp;
+
+/// Header line of the CSV format.
+const CSV_HEADER: &str = "timestamp,value";
+
+/// Line layout of a serialized series.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SeriesFormat {
+    /// A `timestamp,value` header, then one `timestamp,value` record per line. Values containing
+    /// commas, quotes or line breaks are quoted as per RFC 4180, so a record may span lines.
+    Csv,
+    /// One `{"ts":"...","value":"..."}` object per line. Values are written as JSON strings,
+    /// the reader also accepts bare JSON numbers and literals.
+    JsonLines,
+}
+
+// ============================================================================================== //
+// [Writing]                                                                                      //
+// ============================================================================================== //
+
+/// Write a series of `(timestamp, value)` pairs to `writer`.
+///
+/// Output is buffered internally and flushed before returning, so `writer` doesn't need to be
+/// buffered itself.
+pub fn write_series<W, T, I>(writer: W, series: I, format: SeriesFormat) -> io::Result<()>
+where
+    W: Write,
+    T: Display,
+    I: IntoIterator<Item = (Timestamp, T)>,
+{
+    let mut out = BufWriter::new(writer);
+    let mut ts_buf = [0; Timestamp::RFC3339_LEN];
+    // Reused between records, so values are rendered without allocating once it has grown.
+    let mut value = String::new();
+
+    if format == SeriesFormat::Csv {
+        writeln!(out, "{}", CSV_HEADER)?;
+    }
+    for (ts, v) in series {
+        let ts = ts.format_rfc3339(&mut ts_buf);
+        value.clear();
+        write!(value, "{}", v).expect("writing to a String can't fail");
+
+        match format {
+            SeriesFormat::Csv => {
+                out.write_all(ts.as_bytes())?;
+                out.write_all(b",")?;
+                write_csv_field(&mut out, &value)?;
+            }
+            SeriesFormat::JsonLines => {
+                out.write_all(b"{\"ts\":\"")?;
+                out.write_all(ts.as_bytes())?;
+                out.write_all(b"\",\"value\":")?;
+                write_json_string(&mut out, &value)?;
+                out.write_all(b"}")?;
+            }
+        }
+        out.write_all(b"\n")?;
+    }
+    out.flush()
+}
+
+fn write_csv_field(out: &mut impl Write, field: &str) -> io::Result<()> {
+    if !field.contains([',', '"', '\n', '\r']) {
+        return out.write_all(field.as_bytes());
+    }
+    out.write_all(b"\"")?;
+    for (i, part) in field.split('"').enumerate() {
+        if i > 0 {
+            out.write_all(b"\"\"")?;
+        }
+        out.write_all(part.as_bytes())?;
+    }
+    out.write_all(b"\"")
+}
+
+fn write_json_string(out: &mut impl Write, s: &str) -> io::Result<()> {
+    out.write_all(b"\"")?;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        let escape: &[u8] = match c {
+            '"' => b"\\\"",
+            '\\' => b"\\\\",
+            '\n' => b"\\n",
+            '\r' => b"\\r",
+            '\t' => b"\\t",
+            c if c < ' ' => b"",
+            _ => continue,
+        };
+        out.write_all(&s.as_bytes()[start..i])?;
+        if escape.is_empty() {
+            write!(out, "\\u{:04x}", c as u32)?;
+        } else {
+            out.write_all(escape)?;
+        }
+        start = i + c.len_utf8();
+    }
+    out.write_all(&s.as_bytes()[start..])?;
+    out.write_all(b"\"")
+}
+
+// ============================================================================================== //
+// [Reading]                                                                                      //
+// ============================================================================================== //
+
+/// Read a series written by [`write_series`] from `reader`.
+///
+/// Input is buffered internally. Malformed lines are reported as
+/// [`InvalidData`](io::ErrorKind::InvalidData) errors naming the line number, reading can
+/// continue with the next line afterwards. Empty lines are skipped.
+pub fn read_series<R: Read, T: FromStr>(reader: R, format: SeriesFormat) -> SeriesReader<R, T> {
+    let reader = BufReader::new(reader);
+    SeriesReader { reader, format, line: String::new(), line_no: 0, _value: PhantomData }
+}
+
+/// Iterator over the records of a serialized series, see [`read_series`].
+#[derive(Debug)]
+pub struct SeriesReader<R, T> {
+    reader: BufReader<R>,
+    format: SeriesFormat,
+    line: String,
+    line_no: usize,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<R: Read, T: FromStr> SeriesReader<R, T> {
+    fn parse_line(&self) -> Option<(Timestamp, T)> {
+        let line = self.line.trim_end_matches(['\n', '\r']);
+        let (ts, value) = match self.format {
+            SeriesFormat::Csv => {
+                let (ts, value) = line.split_once(',')?;
+                (ts, parse_csv_field(value)?)
+            }
+            SeriesFormat::JsonLines => parse_json_record(line)?,
+        };
+        Some((parse_timestamp(ts)?, value.parse().ok()?))
+    }
+}
+
+/// Parse RFC 3339 or integer nanoseconds, covering the full range unlike chrono.
+fn parse_timestamp(s: &str) -> Option<Timestamp> {
+    if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+        return s.parse().ok().map(Timestamp::from_nanoseconds);
+    }
+    Timestamp::parse_rfc3339(s).ok()
+}
+
+impl<R: Read, T: FromStr> Iterator for SeriesReader<R, T> {
+    type Item = io::Result<(Timestamp, T)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => self.line_no += 1,
+                Err(err) => return Some(Err(err)),
+            }
+            // A quoted CSV field may span lines, its quotes are balanced once the record is complete.
+            let record_line = self.line_no;
+            while self.format == SeriesFormat::Csv && self.line.matches('"').count() % 2 == 1 {
+                match self.reader.read_line(&mut self.line) {
+                    Ok(0) => break,
+                    Ok(_) => self.line_no += 1,
+                    Err(err) => return Some(Err(err)),
+                }
+            }
+
+            let line = self.line.trim_end_matches(['\n', '\r']);
+            let is_header = record_line == 1 && self.format == SeriesFormat::Csv && line == CSV_HEADER;
+            if line.is_empty() || is_header {
+                continue;
+            }
+            return Some(self.parse_line().ok_or_else(|| {
+                let msg = format!("malformed series record on line {}", record_line);
+                io::Error::new(io::ErrorKind::InvalidData, msg)
+            }));
+        }
+    }
+}
+
+fn parse_csv_field(field: &str) -> Option<std::borrow::Cow<'_, str>> {
+    match field.strip_prefix('"') {
+        Some(quoted) => {
+            let inner = quoted.strip_suffix('"')?;
+            Some(inner.replace("\"\"", "\"").into())
+        }
+        None => Some(field.into()),
+    }
+}
+
+/// Split a `{"ts":"...","value":...}` line into the timestamp and the decoded value.
+fn parse_json_record(line: &str) -> Option<(&str, std::borrow::Cow<'_, str>)> {
+    let rest = line.trim().strip_prefix("{\"ts\":\"")?;
+    let (ts, rest) = rest.split_once('"')?;
+    let rest = rest.strip_prefix(",\"value\":")?.strip_suffix('}')?;
+    let value = match rest.strip_prefix('"') {
+        Some(quoted) => unescape_json(quoted.strip_suffix('"')?)?.into(),
+        None => rest.into(),
+    };
+    Some((ts, value))
+}
+
+fn unescape_json(s: &str) -> Option<String> {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        out.push(match chars.next()? {
+            '"' => '"',
+            '\\' => '\\',
+            '/' => '/',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'u' => {
+                let hex = chars.as_str().get(..4)?;
+                chars = chars.as_str()[4..].chars();
+                char::from_u32(u32::from_str_radix(hex, 16).ok()?)?
+            }
+            _ => return None,
+        });
+    }
+    Some(out)
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::io::*;
+    use crate::*;
+
+    fn round_trip(series: &[(Timestamp, String)], format: SeriesFormat) -> Vec<(Timestamp, String)> {
+        let mut out = Vec::new();
+        write_series(&mut out, series.iter().map(|(ts, v)| (*ts, v)), format).unwrap();
+        read_series(&out[..], format).collect::<Result<_, _>>().unwrap()
+    }
+
+    #[test]
+    fn series_round_trip() {
+        let start = Timestamp::from_seconds(1_714_570_200);
+        let series: Vec<_> = ["plain", "a,b", "say \"hi\"", "tab\tand\nnewline", "\u{1}", ""]
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (start + TimeDelta::from_nanoseconds(i as i64 * 7), v.to_string()))
+            .collect();
+
+        assert_eq!(round_trip(&series, SeriesFormat::Csv), series);
+        assert_eq!(round_trip(&series, SeriesFormat::JsonLines), series);
+
+        let past_chrono = vec![
+            (Timestamp::from_seconds(10_000_000_000), "2286".to_string()),
+            (Timestamp::MAX, "2554".to_string()),
+        ];
+        assert_eq!(round_trip(&past_chrono, SeriesFormat::Csv), past_chrono);
+        assert_eq!(round_trip(&past_chrono, SeriesFormat::JsonLines), past_chrono);
+    }
+
+    #[test]
+    fn json_lines_layout() {
+        let mut out = Vec::new();
+        write_series(&mut out, [(Timestamp::from_seconds(1), "a\"b")], SeriesFormat::JsonLines).unwrap();
+        assert_eq!(out, b"{\"ts\":\"1970-01-01T00:00:01.000000000Z\",\"value\":\"a\\\"b\"}\n");
+
+        let input = "{\"ts\":\"1970-01-01T00:00:01Z\",\"value\":42}\n\n\
+                     {\"ts\":\"5\",\"value\":\"\\u0041\"}\n";
+        let read: Result<Vec<(Timestamp, String)>, _> =
+            read_series(input.as_bytes(), SeriesFormat::JsonLines).collect();
+        assert_eq!(
+            read.unwrap(),
+            [(Timestamp::from_seconds(1), "42".into()), (Timestamp::from_nanoseconds(5), "A".into())],
+        );
+    }
+
+    #[test]
+    fn malformed_records() {
+        let input = "timestamp,value\n1970-01-01T00:00:01Z,1\nnonsense\n1970-01-01T00:00:02Z,x\n3,3\n";
+        let results: Vec<_> = read_series::<_, u32>(input.as_bytes(), SeriesFormat::Csv).collect();
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap(), &(Timestamp::from_seconds(1), 1));
+        let err = results[1].as_ref().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("line 3"), "{}", err);
+        assert!(results[2].is_err());
+        assert_eq!(results[3].as_ref().unwrap(), &(Timestamp::from_nanoseconds(3), 3));
+    }
+}
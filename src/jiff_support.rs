@@ -0,0 +1,120 @@
+//! Conversions between the crate's types and jiff's `Timestamp`, `SignedDuration` and `Span`.
+
+use jiff::{SignedDuration, Span, SpanRelativeTo};
+
+use crate::{TimeDelta, TimeError, Timestamp};
+
+// ============================================================================================== //
+// [Timestamp]                                                                                    //
+// ============================================================================================== //
+
+/// Every timestamp lies within jiff's range of years -9999 to 9999.
+impl From<Timestamp> for jiff::Timestamp {
+    fn from(ts: Timestamp) -> Self {
+        jiff::Timestamp::from_nanosecond(ts.0 as i128).expect("timestamps are within jiff's range")
+    }
+}
+
+/// Fails with [`TimeError::BeforeEpoch`] or [`TimeError::Overflow`] for times outside the
+/// range of [`Timestamp`].
+impl TryFrom<jiff::Timestamp> for Timestamp {
+    type Error = TimeError;
+
+    fn try_from(ts: jiff::Timestamp) -> Result<Self, Self::Error> {
+        let nanos = ts.as_nanosecond();
+        if nanos < 0 {
+            return Err(TimeError::BeforeEpoch);
+        }
+        u64::try_from(nanos).map(Timestamp).map_err(|_| TimeError::Overflow)
+    }
+}
+
+// ============================================================================================== //
+// [TimeDelta]                                                                                    //
+// ============================================================================================== //
+
+impl From<TimeDelta> for SignedDuration {
+    fn from(delta: TimeDelta) -> Self {
+        SignedDuration::from_nanos(delta.0)
+    }
+}
+
+/// Fails with [`TimeError::Overflow`] for durations longer than about 292 years.
+impl TryFrom<SignedDuration> for TimeDelta {
+    type Error = TimeError;
+
+    fn try_from(duration: SignedDuration) -> Result<Self, Self::Error> {
+        i64::try_from(duration.as_nanos()).map(TimeDelta).map_err(|_| TimeError::Overflow)
+    }
+}
+
+/// A span balanced up to hours, e.g. 90 minutes become 1 hour and 30 minutes.
+impl From<TimeDelta> for Span {
+    fn from(delta: TimeDelta) -> Self {
+        let nanos = delta.0.unsigned_abs();
+        let span = Span::new()
+            .hours((nanos / 3_600_000_000_000) as i64)
+            .minutes((nanos / 60_000_000_000 % 60) as i64)
+            .seconds((nanos / 1_000_000_000 % 60) as i64)
+            .milliseconds((nanos / 1_000_000 % 1_000) as i64)
+            .microseconds((nanos / 1_000 % 1_000) as i64)
+            .nanoseconds((nanos % 1_000) as i64);
+        if delta.0 < 0 { span.negate() } else { span }
+    }
+}
+
+/// Days count as 24 hours and weeks as 7 days, like in [`TimeDelta::parse_iso8601`].
+///
+/// Fails with [`TimeError::InvalidFormat`] if the span has years or months, which have no fixed
+/// length, and with [`TimeError::Overflow`] for spans longer than about 292 years.
+impl TryFrom<Span> for TimeDelta {
+    type Error = TimeError;
+
+    fn try_from(span: Span) -> Result<Self, Self::Error> {
+        if span.get_years() != 0 || span.get_months() != 0 {
+            return Err(TimeError::InvalidFormat);
+        }
+        let duration = span.to_duration(SpanRelativeTo::days_are_24_hours());
+        duration.map_err(|_| TimeError::Overflow).and_then(TimeDelta::try_from)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use jiff::{SignedDuration, Span, ToSpan};
+
+    #[test]
+    fn jiff_timestamp_conversions() {
+        let ts = Timestamp::from_nanoseconds(1_714_570_200_123_456_789);
+        let jiff_ts: jiff::Timestamp = ts.into();
+        assert_eq!(jiff_ts.to_string(), "2024-05-01T13:30:00.123456789Z");
+        assert_eq!(Timestamp::try_from(jiff_ts), Ok(ts));
+        assert_eq!(Timestamp::try_from(jiff::Timestamp::from(Timestamp::MAX)), Ok(Timestamp::MAX));
+
+        let before = jiff::Timestamp::from_second(-1).unwrap();
+        assert_eq!(Timestamp::try_from(before), Err(TimeError::BeforeEpoch));
+        assert_eq!(Timestamp::try_from(jiff::Timestamp::MAX), Err(TimeError::Overflow));
+    }
+
+    #[test]
+    fn jiff_span_conversions() {
+        let delta = TimeDelta::from_minutes(-90) - TimeDelta::from_milliseconds(5);
+        assert_eq!(SignedDuration::from(delta), SignedDuration::from_millis(-5_400_005));
+        assert_eq!(TimeDelta::try_from(SignedDuration::from(delta)), Ok(delta));
+        assert_eq!(TimeDelta::try_from(SignedDuration::MAX), Err(TimeError::Overflow));
+
+        let span = Span::from(delta);
+        assert_eq!(span.fieldwise(), (-1).hours().minutes(30).milliseconds(5).fieldwise());
+        assert_eq!(TimeDelta::try_from(span), Ok(delta));
+        assert_eq!(TimeDelta::try_from(1.week().days(1)), Ok(TimeDelta::from_days(8)));
+        assert_eq!(TimeDelta::try_from(1.month()), Err(TimeError::InvalidFormat));
+        assert_eq!(TimeDelta::try_from(200_000.days()), Err(TimeError::Overflow));
+        let min = TimeDelta::from_nanoseconds(i64::MIN);
+        assert_eq!(TimeDelta::try_from(Span::from(min)), Ok(min));
+    }
+}
@@ -1,17 +1,163 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
 use core::{fmt, ops};
 
 #[cfg(feature = "serde-support")]
-use serde::{Deserialize, Serialize};
+use ::serde::{Deserialize, Serialize};
 
 #[cfg(feature = "coarsetime-support")]
 use coarsetime::Clock;
 
+pub mod anonymize;
+#[cfg(feature = "std")]
+pub mod clock;
+#[cfg(feature = "std")]
+pub mod decay;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "chrono-support")]
+pub mod io;
+#[cfg(feature = "serde-support")]
+pub mod serde;
+
+#[cfg(feature = "std")]
+mod age;
+#[cfg(feature = "arbitrary-support")]
+mod arbitrary_support;
+#[cfg(feature = "arrow-support")]
+mod arrow_support;
+#[cfg(target_has_atomic = "64")]
+mod atomic;
+mod calendar;
+#[cfg(feature = "chrono-support")]
+mod chrono_traits;
+mod civil;
+#[cfg(feature = "defmt-support")]
+mod defmt_support;
+#[cfg(feature = "std")]
+mod clock_pair;
+mod error;
+mod format;
+#[cfg(feature = "chrono-support")]
+mod formats;
+#[cfg(feature = "hifitime-support")]
+mod hifitime_support;
+mod humanize;
+#[cfg(feature = "std")]
+mod index;
+mod interval;
+#[cfg(feature = "jiff-support")]
+mod jiff_support;
+#[cfg(feature = "std")]
+mod liveness;
+#[cfg(feature = "std")]
+mod partition;
+#[cfg(feature = "polars-support")]
+mod polars_support;
+#[cfg(feature = "postgres-support")]
+mod postgres_support;
+#[cfg(feature = "std")]
+mod pool;
+#[cfg(feature = "proptest-support")]
+mod proptest_support;
+#[cfg(feature = "pyo3-support")]
+mod pyo3_support;
+#[cfg(feature = "quickcheck-support")]
+mod quickcheck_support;
+#[cfg(feature = "rand-support")]
+mod rand_support;
+#[cfg(feature = "std")]
+mod range_map;
+#[cfg(feature = "std")]
+mod rate;
+#[cfg(feature = "std")]
+mod retention;
+#[cfg(feature = "std")]
+mod schedule;
+#[cfg(feature = "schemars-support")]
+mod schemars_support;
+#[cfg(feature = "std")]
+mod slicer;
+#[cfg(feature = "std")]
+mod stamped;
+#[cfg(feature = "rusqlite-support")]
+mod rusqlite_support;
+#[cfg(feature = "sqlx-support")]
+mod sqlx_support;
+mod std_time;
+#[cfg(feature = "std")]
+mod stopwatch;
+#[cfg(feature = "std")]
+mod strftime;
+#[cfg(feature = "utoipa-support")]
+mod utoipa_support;
+#[cfg(feature = "std")]
+mod uncertain;
+#[cfg(feature = "std")]
+mod validate;
+#[cfg(feature = "wasm-support")]
+mod wasm_support;
+#[cfg(feature = "std")]
+mod watermark;
+#[cfg(feature = "chrono-support")]
+mod zoned;
+
+#[cfg(feature = "std")]
+pub use age::AgeBuckets;
+#[cfg(target_has_atomic = "64")]
+pub use atomic::AtomicTimestamp;
+pub use calendar::CalendarDelta;
+pub use civil::TimeOfDay;
+#[cfg(feature = "defmt-support")]
+pub use defmt_support::SplitSeconds;
+#[cfg(feature = "std")]
+pub use clock_pair::ClockPairTracker;
+pub use error::TimeError;
+pub use humanize::HumanizedDelta;
+#[cfg(feature = "std")]
+pub use index::{BeBytesColumn, TimeColumn, TimeIndex};
+pub use interval::Interval;
+#[cfg(feature = "std")]
+pub use liveness::{Heartbeat, LivenessTable};
+#[cfg(feature = "std")]
+pub use partition::PartitionScheme;
+#[cfg(feature = "std")]
+pub use pool::TimestampFormatterPool;
+#[cfg(feature = "rand-support")]
+pub use rand_support::{UniformTimeDelta, UniformTimestamp};
+#[cfg(feature = "std")]
+pub use range_map::{OverlapPolicy, TimestampRangeMap};
+#[cfg(feature = "std")]
+pub use rate::{rate_between, RateTracker};
+#[cfg(feature = "std")]
+pub use retention::RetentionPolicy;
+#[cfg(feature = "std")]
+pub use schedule::{MissedFires, NextFire, RotationSchedule};
+#[cfg(feature = "std")]
+pub use slicer::TimeSlicer;
+#[cfg(feature = "std")]
+pub use stamped::Stamped;
+#[cfg(feature = "std")]
+pub use stopwatch::{BudgetScope, DurationBudget, Stopwatch};
+#[cfg(feature = "std")]
+pub use strftime::FormatSpec;
+#[cfg(feature = "std")]
+pub use uncertain::UncertainTimestamp;
+#[cfg(feature = "std")]
+pub use validate::{validate_series, ValidationReport, ValidationRules};
+#[cfg(feature = "std")]
+pub use watermark::{LowWatermark, WatermarkSlot};
+#[cfg(feature = "chrono-support")]
+pub use zoned::ZonedFormatter;
+
 #[cfg(feature = "coarsetime-support")]
 pub fn coarsetime_update() {
 	coarsetime::Clock::update();
 }
 
-#[cfg(feature = "coarsetime-support")]
+/// Start a thread refreshing the coarse clock every millisecond for the rest of the process, see
+/// [`clock::CoarseUpdater`] to choose the interval or stop it again.
+#[cfg(all(feature = "coarsetime-support", not(target_arch = "wasm32")))]
 pub fn coarsetime_init_updater() {
 	coarsetime::Updater::new(1).start().expect("Failed to start coarsetime updater");
 }
@@ -21,37 +167,55 @@ pub fn coarsetime_init_updater() {
 // ============================================================================================== //
 
 /// Represents a dumb but fast UTC timestamp.
+///
+/// In memory, a timestamp is a native endian `u64` of nanoseconds since the epoch. With
+/// `bytemuck-support`, slices of timestamps can be cast from and to memory mapped bytes.
 #[repr(transparent)]
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck-support", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(feature = "wasm-support", wasm_bindgen::prelude::wasm_bindgen)]
 pub struct Timestamp(u64);
 
-/// Display timestamp using chrono.
-impl fmt::Display for Timestamp {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        chrono::DateTime::<chrono::Utc>::from(*self).fmt(f)
-    }
-}
-
 impl fmt::Debug for Timestamp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Timestamp({})", self.0)
     }
 }
 
-/// Create a dumb timestamp from a chrono date time object.
-impl From<chrono::DateTime<chrono::Utc>> for Timestamp {
-    fn from(other: chrono::DateTime<chrono::Utc>) -> Self {
-        let nanos = other.timestamp_nanos_opt().unwrap_or(0);
-        if nanos < 0 {
-            Self(0) // Clamp negative timestamps to 0
-        } else {
-            Self(nanos as u64)
+/// Parse an RFC 3339 date time, e.g. `2024-05-01T09:30:00.5-04:00`, or integer nanoseconds.
+#[cfg(feature = "chrono-support")]
+impl core::str::FromStr for Timestamp {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+            return s.parse().map(Timestamp).map_err(|_| TimeError::Overflow);
         }
+
+        let dt = chrono::DateTime::parse_from_rfc3339(s).map_err(|_| TimeError::InvalidFormat)?;
+        let nanos = dt.timestamp_nanos_opt().ok_or(TimeError::Overflow)?;
+        u64::try_from(nanos).map(Timestamp).map_err(|_| TimeError::BeforeEpoch)
+    }
+}
+
+/// Create a dumb timestamp from a chrono date time object in any time zone.
+///
+/// Times before the epoch or past `2262-04-11` are clamped to 0, see
+/// [`Timestamp::try_from_chrono`] for a checked conversion. With the `strict-conversions`
+/// feature, such lossy conversions panic in debug builds.
+#[cfg(feature = "chrono-support")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Timestamp {
+    #[track_caller]
+    fn from(other: chrono::DateTime<Tz>) -> Self {
+        Self::try_from_chrono(other).unwrap_or_else(|err| {
+            lossy_conversion(err);
+            Self(0)
+        })
     }
 }
 
 /// Create a chrono date time object from a dumb timestamp.
+#[cfg(feature = "chrono-support")]
 impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
     fn from(other: Timestamp) -> Self {
         let nanoseconds_u64 = other.0;
@@ -75,8 +239,53 @@ impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
     }
 }
 
+/// Report a lossy `From` conversion, which panics in debug builds with `strict-conversions`.
+#[cfg(feature = "chrono-support")]
+#[inline]
+#[track_caller]
+fn lossy_conversion(_err: TimeError) {
+    #[cfg(all(feature = "strict-conversions", debug_assertions))]
+    panic!("lossy time conversion: {}", _err);
+}
+
+#[cfg(feature = "chrono-support")]
+impl Timestamp {
+    /// Convert a chrono date time, failing with [`TimeError::BeforeEpoch`] or
+    /// [`TimeError::Overflow`] instead of clamping.
+    pub fn try_from_chrono<Tz: chrono::TimeZone>(dt: chrono::DateTime<Tz>) -> Result<Self, TimeError> {
+        match dt.timestamp_nanos_opt() {
+            Some(nanos) => u64::try_from(nanos).map(Timestamp).map_err(|_| TimeError::BeforeEpoch),
+            None if dt.timestamp() < 0 => Err(TimeError::BeforeEpoch),
+            None => Err(TimeError::Overflow),
+        }
+    }
+
+    /// Convert to a chrono date time, failing with [`TimeError::Overflow`] for timestamps that
+    /// wouldn't convert back, see [`is_representable_as_chrono`](Self::is_representable_as_chrono).
+    pub fn try_into_chrono(self) -> Result<chrono::DateTime<chrono::Utc>, TimeError> {
+        if self.is_representable_as_chrono() { Ok(self.into()) } else { Err(TimeError::Overflow) }
+    }
+}
 
 impl Timestamp {
+    /// The earliest representable timestamp, `1970-01-01 00:00:00 UTC`.
+    pub const MIN: Timestamp = Timestamp(0);
+
+    /// The latest representable timestamp, `2554-07-21 23:34:33.709551615 UTC`.
+    pub const MAX: Timestamp = Timestamp(u64::MAX);
+
+    /// The latest timestamp surviving a round trip through `chrono::DateTime`,
+    /// `2262-04-11 23:47:16.854775807 UTC`.
+    ///
+    /// chrono stores nanoseconds since the epoch as `i64`, later timestamps come back clamped.
+    const MAX_CHRONO: Timestamp = Timestamp(i64::MAX as u64);
+
+    /// Check whether the timestamp converts to `chrono::DateTime` and back without loss.
+    #[inline]
+    pub const fn is_representable_as_chrono(self) -> bool {
+        self.0 <= Self::MAX_CHRONO.0
+    }
+
     /// Initialize a timestamp with 0, `1970-01-01 00:00:00 UTC`.
     #[inline]
     pub const fn zero() -> Self {
@@ -84,51 +293,167 @@ impl Timestamp {
     }
 
     /// Initialize a timestamp using the current local time converted to UTC.
-    #[cfg(not(feature = "coarsetime-support"))]
+    ///
+    /// Which clock is read can be chosen at runtime with [`clock::set_source`]. With the default
+    /// coarse source and `coarsetime-support`, `coarsetime::Clock::update()` should be called
+    /// periodically, unless `coarse-clock` reads the kernel's coarse clock instead. With
+    /// `tsc-clock`, the precise source is the default and reads the CPU's time stamp counter.
+    /// With `clock-replay`, reads can be recorded and replayed, and with `clock-override`, reads
+    /// can be redirected to another clock in tests, see [`clock`]. On `wasm32-unknown-unknown`,
+    /// the time is read from JavaScript's `Date.now()` refined by `performance.now()`.
+    #[cfg(feature = "std")]
+    #[inline]
     pub fn now() -> Self {
-        let nanos = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
-        if nanos < 0 {
-            Self(0)
-        } else {
-            Self(nanos as u64)
+        #[cfg(feature = "clock-override")]
+        if let Some(ts) = clock::global::read() {
+            return ts;
         }
+        #[cfg(feature = "clock-replay")]
+        return clock::replay::intercept(Self::read_clock);
+        #[cfg(not(feature = "clock-replay"))]
+        return Self::read_clock();
     }
 
-    /// Initialize a timestamp using the current local time converted to UTC, using `coarsetime`.
-    /// For optimal performance, `coarsetime::Clock::update()` should be called periodically.
-    #[cfg(feature = "coarsetime-support")]
-    pub fn now() -> Self {
-        Self(Clock::recent_since_epoch().as_nanos())
+    /// Read the current time truncated to whole milliseconds, from the same clock as
+    /// [`now`](Self::now).
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn now_millis() -> Self {
+        Self::now().align_to(TimeDelta::MILLISECOND)
+    }
+
+    /// Read the current time truncated to whole seconds, from the coarse clock like
+    /// [`recent`](Self::recent), whose lag of a few milliseconds doesn't matter at this resolution.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn now_seconds() -> Self {
+        Self::recent().align_to(TimeDelta::SECOND)
+    }
+
+    /// Read the coarse clock regardless of the selected [`clock::ClockSource`], the cheapest
+    /// read available: the value cached by `coarsetime`'s updater, or the kernel's coarse clock
+    /// with `coarse-clock`. Without either, this reads the precise clock.
+    ///
+    /// Redirected by `clock-override` like [`now`](Self::now), but not recorded by
+    /// `clock-replay`.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn recent() -> Self {
+        #[cfg(feature = "clock-override")]
+        if let Some(ts) = clock::global::read() {
+            return ts;
+        }
+        clock::backend::read_coarse()
+    }
+
+    /// Read the clock selected with [`clock::set_source`].
+    #[cfg(feature = "std")]
+    #[inline]
+    fn read_clock() -> Self {
+        clock::backend::read()
     }
 
     /// Fetches the current UTC time using `chrono::Utc::now()`.
-    #[cfg(not(feature = "coarsetime-support"))]
+    #[cfg(all(feature = "chrono-support", not(feature = "coarsetime-support")))]
     pub fn fetch_chrono_utc_now() -> chrono::DateTime<chrono::Utc> {
         chrono::Utc::now()
     }
 
     /// Fetches the current UTC time using `coarsetime` and converts it to `chrono::DateTime<chrono::Utc>`.
     /// For optimal performance, `coarsetime::Clock::update()` should be called periodically.
-    #[cfg(feature = "coarsetime-support")]
+    #[cfg(all(feature = "chrono-support", feature = "coarsetime-support"))]
     pub fn fetch_chrono_utc_now() -> chrono::DateTime<chrono::Utc> {
         let nanos = coarsetime::Clock::recent_since_epoch().as_nanos();
         Timestamp(nanos).into()
     }
 
+    /// Time of the last refresh of the cached coarse clock, i.e. the last `coarsetime_update()`
+    /// or updater thread tick.
+    #[cfg(feature = "coarsetime-support")]
+    pub fn coarse_last_update() -> Self {
+        Self(Clock::recent_since_epoch().as_nanos())
+    }
+
+    /// How far the cached coarse clock lags behind the precise one.
+    ///
+    /// With a running updater this stays below its update interval. Alarming on larger values
+    /// catches a stalled updater thread, which otherwise silently skews every [`now`](Self::now).
+    #[cfg(feature = "coarsetime-support")]
+    pub fn coarse_staleness() -> TimeDelta {
+        let precise = Clock::now_since_epoch().as_nanos();
+        let recent = Clock::recent_since_epoch().as_nanos();
+        TimeDelta(precise.saturating_sub(recent).min(i64::MAX as u64) as i64)
+    }
+
+    /// Explicit conversion from `u64` milliseconds.
+    ///
+    /// # Panics
+    ///
+    /// If the value exceeds the representable range, see [`try_from_milliseconds`](Self::try_from_milliseconds).
     #[inline]
     pub const fn from_milliseconds(int: u64) -> Self {
-        Timestamp(int * 1_000_000)
+        match Self::try_from_milliseconds(int) {
+            Ok(ts) => ts,
+            Err(_) => panic!("milliseconds out of range for Timestamp"),
+        }
+    }
+
+    /// Explicit conversion from `u64` microseconds.
+    ///
+    /// # Panics
+    ///
+    /// If the value exceeds the representable range, see [`try_from_microseconds`](Self::try_from_microseconds).
+    #[inline]
+    pub const fn from_microseconds(int: u64) -> Self {
+        match Self::try_from_microseconds(int) {
+            Ok(ts) => ts,
+            Err(_) => panic!("microseconds out of range for Timestamp"),
+        }
     }
 
     /// Explicit conversion from `u64` seconds.
+    ///
+    /// # Panics
+    ///
+    /// If the value exceeds the representable range, see [`try_from_seconds`](Self::try_from_seconds).
     #[inline]
     pub const fn from_seconds(int: u64) -> Self {
-        Timestamp(int * 1_000_000_000)
+        match Self::try_from_seconds(int) {
+            Ok(ts) => ts,
+            Err(_) => panic!("seconds out of range for Timestamp"),
+        }
+    }
+
+    /// Fallible conversion from `u64` milliseconds.
+    #[inline]
+    pub const fn try_from_milliseconds(int: u64) -> Result<Self, TimeError> {
+        match int.checked_mul(1_000_000) {
+            Some(nanos) => Ok(Timestamp(nanos)),
+            None => Err(TimeError::Overflow),
+        }
+    }
+
+    /// Fallible conversion from `u64` microseconds.
+    #[inline]
+    pub const fn try_from_microseconds(int: u64) -> Result<Self, TimeError> {
+        match int.checked_mul(1_000) {
+            Some(nanos) => Ok(Timestamp(nanos)),
+            None => Err(TimeError::Overflow),
+        }
+    }
+
+    /// Fallible conversion from `u64` seconds.
+    #[inline]
+    pub const fn try_from_seconds(int: u64) -> Result<Self, TimeError> {
+        match int.checked_mul(1_000_000_000) {
+            Some(nanos) => Ok(Timestamp(nanos)),
+            None => Err(TimeError::Overflow),
+        }
     }
 
     /// Explicit conversion from `u64` nanoseconds.
     #[inline]
-    pub fn from_nanoseconds(int: u64) -> Self {
+    pub const fn from_nanoseconds(int: u64) -> Self {
         Timestamp(int)
     }
 
@@ -138,12 +463,74 @@ impl Timestamp {
         self.0 / 1_000_000
     }
 
+    /// Explicit conversion to `u64` microseconds.
+    #[inline]
+    pub const fn as_microseconds(self) -> u64 {
+        self.0 / 1_000
+    }
+
+    /// Explicit conversion to `u64` seconds.
+    #[inline]
+    pub const fn as_seconds(self) -> u64 {
+        self.0 / 1_000_000_000
+    }
+
     /// Explicit conversion to `u64` nanoseconds.
     #[inline]
     pub const fn as_nanoseconds(self) -> u64 {
         self.0
     }
 
+    /// Encode as 8 bytes of big endian `u64` nanoseconds since the epoch.
+    ///
+    /// This layout is stable. Byte-wise order equals time order, so the encoding can be used
+    /// directly as a sorted key in key-value stores.
+    #[inline]
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
+
+    /// Decode from [`to_be_bytes`](Self::to_be_bytes).
+    #[inline]
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        Timestamp(u64::from_be_bytes(bytes))
+    }
+
+    /// Encode as 8 bytes of little endian `u64` nanoseconds since the epoch. This layout is
+    /// stable, but unlike [`to_be_bytes`](Self::to_be_bytes) doesn't sort byte-wise.
+    #[inline]
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
+
+    /// Decode from [`to_le_bytes`](Self::to_le_bytes).
+    #[inline]
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        Timestamp(u64::from_le_bytes(bytes))
+    }
+
+    /// Create a timestamp from floating point seconds since the epoch, e.g. from a metrics system.
+    ///
+    /// Rounds to the nearest nanosecond and clamps to the representable range. NaN maps to the
+    /// epoch.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn from_secs_f64(secs: f64) -> Self {
+        // Scaling the whole value would round away the nanoseconds of present day timestamps.
+        let whole = secs.trunc();
+        let frac_nanos = ((secs - whole) * 1e9).round() as i64;
+        Timestamp((whole as u64).saturating_mul(1_000_000_000).saturating_add_signed(frac_nanos))
+    }
+
+    /// Floating point seconds since the epoch.
+    ///
+    /// An `f64` has 53 bits of precision, so present day timestamps lose everything below
+    /// roughly a quarter microsecond.
+    #[inline]
+    pub fn as_secs_f64(self) -> f64 {
+        (self.0 / 1_000_000_000) as f64 + (self.0 % 1_000_000_000) as f64 / 1e9
+    }
+
     /// Align a timestamp to a given frequency.
     pub const fn align_to(self, freq: TimeDelta) -> Timestamp {
         self.align_to_anchored(Timestamp::zero(), freq)
@@ -166,6 +553,84 @@ impl Timestamp {
     pub const fn is_zero(self) -> bool {
         self.0 == 0
     }
+
+    /// Advance the timestamp by a delta, `None` if the result would precede the epoch or
+    /// exceed the `u64` nanosecond range.
+    #[inline]
+    pub const fn checked_add(self, rhs: TimeDelta) -> Option<Timestamp> {
+        match self.0.checked_add_signed(rhs.0) {
+            Some(nanos) => Some(Timestamp(nanos)),
+            None => None,
+        }
+    }
+
+    /// Move the timestamp back by a delta, `None` if the result would precede the epoch or
+    /// exceed the `u64` nanosecond range.
+    #[inline]
+    pub const fn checked_sub(self, rhs: TimeDelta) -> Option<Timestamp> {
+        match self.0.checked_sub_signed(rhs.0) {
+            Some(nanos) => Some(Timestamp(nanos)),
+            None => None,
+        }
+    }
+
+    /// Advance the timestamp by a delta, clamping to the epoch and to `u64::MAX` nanoseconds.
+    #[inline]
+    pub const fn saturating_add(self, rhs: TimeDelta) -> Timestamp {
+        Timestamp(self.0.saturating_add_signed(rhs.0))
+    }
+
+    /// Move the timestamp back by a delta, clamping to the epoch and to `u64::MAX` nanoseconds.
+    #[inline]
+    pub const fn saturating_sub(self, rhs: TimeDelta) -> Timestamp {
+        Timestamp(self.0.saturating_sub_signed(rhs.0))
+    }
+
+    /// Advance the timestamp by a delta, wrapping around at the ends of the `u64` range.
+    #[inline]
+    pub const fn wrapping_add(self, rhs: TimeDelta) -> Timestamp {
+        Timestamp(self.0.wrapping_add_signed(rhs.0))
+    }
+
+    /// Move the timestamp back by a delta, wrapping around at the ends of the `u64` range.
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: TimeDelta) -> Timestamp {
+        Timestamp(self.0.wrapping_sub_signed(rhs.0))
+    }
+
+    /// Time elapsed from `earlier` to `self`, `None` if `earlier` is later than `self` or the
+    /// difference doesn't fit a `TimeDelta`.
+    #[inline]
+    pub const fn checked_duration_since(self, earlier: Timestamp) -> Option<TimeDelta> {
+        match self.0.checked_sub(earlier.0) {
+            Some(nanos) if nanos <= i64::MAX as u64 => Some(TimeDelta(nanos as i64)),
+            _ => None,
+        }
+    }
+
+    /// Same as `self + rhs`, usable in const contexts.
+    #[inline]
+    pub const fn const_add(self, rhs: TimeDelta) -> Timestamp {
+        // Convert to i64 for arithmetic, then clamp to 0 and convert back to u64
+        let result_i64 = (self.0 as i64) + rhs.0;
+        Self(if result_i64 < 0 { 0 } else { result_i64 as u64 })
+    }
+
+    /// Same as `self - rhs`, usable in const contexts.
+    #[inline]
+    pub const fn const_sub(self, rhs: TimeDelta) -> Timestamp {
+        // Convert to i64 for arithmetic, then clamp to 0 and convert back to u64
+        let result_i64 = (self.0 as i64) - rhs.0;
+        Self(if result_i64 < 0 { 0 } else { result_i64 as u64 })
+    }
+
+    /// Check whether two timestamps are at most `tolerance` apart.
+    ///
+    /// Useful when comparing readings of different clocks. A negative tolerance never matches.
+    #[inline]
+    pub const fn approx_eq(self, other: Timestamp, tolerance: TimeDelta) -> bool {
+        tolerance.0 >= 0 && self.0.abs_diff(other.0) <= tolerance.0 as u64
+    }
 }
 
 /// Calculate the timestamp advanced by a timedelta.
@@ -173,9 +638,7 @@ impl ops::Add<TimeDelta> for Timestamp {
     type Output = Timestamp;
 
     fn add(self, rhs: TimeDelta) -> Self::Output {
-        // Convert to i64 for arithmetic, then clamp to 0 and convert back to u64
-        let result_i64 = (self.0 as i64) + rhs.0;
-        Self(result_i64.max(0) as u64)
+        self.const_add(rhs)
     }
 }
 
@@ -190,9 +653,7 @@ impl ops::Sub<TimeDelta> for Timestamp {
     type Output = Timestamp;
 
     fn sub(self, rhs: TimeDelta) -> Self::Output {
-        // Convert to i64 for arithmetic, then clamp to 0 and convert back to u64
-        let result_i64 = (self.0 as i64) - rhs.0;
-        Self(result_i64.max(0) as u64)
+        self.const_sub(rhs)
     }
 }
 
@@ -225,29 +686,97 @@ impl ops::Sub<Timestamp> for Timestamp {
 // ============================================================================================== //
 
 /// Millisecond precision time delta.
+///
+/// In memory, a delta is a native endian `i64` of nanoseconds, see [`Timestamp`] for casting.
 #[repr(transparent)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck-support", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct TimeDelta(i64);
 
-/// Display timedelta using chrono.
-impl fmt::Display for TimeDelta {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        chrono::Duration::from(*self).fmt(f)
+/// Parse a Go style duration such as `1h30m`, `250ms`, `-1.5s` or `1d12h`.
+///
+/// A possibly signed sequence of decimal numbers, each with an optional fraction and one of the
+/// units `ns`, `us` (or `µs`), `ms`, `s`, `m`, `h` and `d`. Unlike Go, `d` for 24 hours is
+/// accepted. A bare `0` needs no unit.
+impl core::str::FromStr for TimeDelta {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, mut rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if rest == "0" {
+            return Ok(TimeDelta(0));
+        }
+        if rest.is_empty() {
+            return Err(TimeError::InvalidFormat);
+        }
+
+        let mut total: u128 = 0;
+        while !rest.is_empty() {
+            let digits = |s: &str| s.bytes().take_while(u8::is_ascii_digit).count();
+            let (int, after_int) = rest.split_at(digits(rest));
+            let (frac, after_number) = match after_int.strip_prefix('.') {
+                Some(after_dot) => after_dot.split_at(digits(after_dot)),
+                None => ("", after_int),
+            };
+            if int.is_empty() && frac.is_empty() {
+                return Err(TimeError::InvalidFormat);
+            }
+
+            let unit_end = after_number.find(|c: char| c.is_ascii_digit() || c == '.');
+            let (unit, after_unit) = after_number.split_at(unit_end.unwrap_or(after_number.len()));
+            let unit_nanos: u128 = match unit {
+                "ns" => 1,
+                "us" | "µs" | "μs" => 1_000,
+                "ms" => 1_000_000,
+                "s" => 1_000_000_000,
+                "m" => 60_000_000_000,
+                "h" => 3_600_000_000_000,
+                "d" => 86_400_000_000_000,
+                _ => return Err(TimeError::InvalidFormat),
+            };
+
+            let int: u128 = if int.is_empty() { 0 } else { int.parse().map_err(|_| TimeError::Overflow)? };
+            // Digits past nanosecond precision of the largest unit don't matter.
+            let frac = &frac[..frac.len().min(18)];
+            let frac_nanos = match frac.is_empty() {
+                true => 0,
+                false => frac.parse::<u128>().expect("digits") * unit_nanos / 10u128.pow(frac.len() as u32),
+            };
+            total = int
+                .checked_mul(unit_nanos)
+                .and_then(|nanos| nanos.checked_add(frac_nanos))
+                .and_then(|nanos| nanos.checked_add(total))
+                .ok_or(TimeError::Overflow)?;
+            rest = after_unit;
+        }
+
+        let total = i128::try_from(total).map_err(|_| TimeError::Overflow)?;
+        let nanos = if negative { -total } else { total };
+        i64::try_from(nanos).map(TimeDelta).map_err(|_| TimeError::Overflow)
     }
 }
 
 /// Create a simple timedelta from a chrono duration.
+#[cfg(feature = "chrono-support")]
 impl From<chrono::Duration> for TimeDelta {
+    #[track_caller]
     fn from(other: chrono::Duration) -> Self {
         // chrono::Duration::num_nanoseconds() returns Option<i64>
         // If the duration is too large to fit in i64 nanoseconds, it returns None.
         // We handle this by clamping to 0, consistent with Timestamp's i64 nanosecond limits.
-        Self(other.num_nanoseconds().unwrap_or(0))
+        Self(other.num_nanoseconds().unwrap_or_else(|| {
+            lossy_conversion(TimeError::Overflow);
+            0
+        }))
     }
 }
 
 /// Create a chrono duration from a simple timedelta.
+#[cfg(feature = "chrono-support")]
 impl From<TimeDelta> for chrono::Duration {
     fn from(other: TimeDelta) -> Self {
         chrono::Duration::nanoseconds(other.0)
@@ -258,7 +787,7 @@ impl ops::Add<TimeDelta> for TimeDelta {
     type Output = TimeDelta;
 
     fn add(self, rhs: TimeDelta) -> Self::Output {
-        TimeDelta(self.0 + rhs.0)
+        self.const_add(rhs)
     }
 }
 
@@ -266,7 +795,52 @@ impl ops::Sub<TimeDelta> for TimeDelta {
     type Output = TimeDelta;
 
     fn sub(self, rhs: TimeDelta) -> Self::Output {
-        TimeDelta(self.0 - rhs.0)
+        self.const_sub(rhs)
+    }
+}
+
+impl ops::AddAssign<TimeDelta> for TimeDelta {
+    fn add_assign(&mut self, rhs: TimeDelta) {
+        *self = *self + rhs;
+    }
+}
+
+impl ops::SubAssign<TimeDelta> for TimeDelta {
+    fn sub_assign(&mut self, rhs: TimeDelta) {
+        *self = *self - rhs;
+    }
+}
+
+impl ops::MulAssign<i64> for TimeDelta {
+    fn mul_assign(&mut self, rhs: i64) {
+        *self = *self * rhs;
+    }
+}
+
+impl ops::DivAssign<i64> for TimeDelta {
+    fn div_assign(&mut self, rhs: i64) {
+        *self = *self / rhs;
+    }
+}
+
+/// Reverse the direction of the delta.
+impl ops::Neg for TimeDelta {
+    type Output = TimeDelta;
+
+    fn neg(self) -> Self::Output {
+        TimeDelta(-self.0)
+    }
+}
+
+impl core::iter::Sum for TimeDelta {
+    fn sum<I: Iterator<Item = TimeDelta>>(iter: I) -> Self {
+        iter.fold(TimeDelta(0), |total, delta| total + delta)
+    }
+}
+
+impl<'a> core::iter::Sum<&'a TimeDelta> for TimeDelta {
+    fn sum<I: Iterator<Item = &'a TimeDelta>>(iter: I) -> Self {
+        iter.copied().sum()
     }
 }
 
@@ -279,6 +853,26 @@ impl ops::Mul<i64> for TimeDelta {
     }
 }
 
+/// Scale the delta by a factor, see [`TimeDelta::mul_f64`].
+#[cfg(feature = "std")]
+impl ops::Mul<f64> for TimeDelta {
+    type Output = TimeDelta;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.mul_f64(rhs)
+    }
+}
+
+/// Shorten the delta by a factor, see [`TimeDelta::div_f64`].
+#[cfg(feature = "std")]
+impl ops::Div<f64> for TimeDelta {
+    type Output = TimeDelta;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        self.div_f64(rhs)
+    }
+}
+
 /// Shorten the delta by a given factor. Integer div.
 impl ops::Div<i64> for TimeDelta {
     type Output = TimeDelta;
@@ -288,7 +882,8 @@ impl ops::Div<i64> for TimeDelta {
     }
 }
 
-/// How many times does the timestamp fit into another?
+/// How many times does the delta fit into another? Panics on a zero delta, see
+/// [`TimeDelta::checked_div_delta`].
 impl ops::Div<TimeDelta> for TimeDelta {
     type Output = i64;
 
@@ -297,7 +892,8 @@ impl ops::Div<TimeDelta> for TimeDelta {
     }
 }
 
-/// How far away is the delta from being aligned to another delta?
+/// How far away is the delta from being aligned to another delta? Panics on a zero delta, see
+/// [`TimeDelta::checked_rem`].
 impl ops::Rem<TimeDelta> for TimeDelta {
     type Output = TimeDelta;
 
@@ -306,6 +902,17 @@ impl ops::Rem<TimeDelta> for TimeDelta {
     }
 }
 
+impl TimeDelta {
+    pub const NANOSECOND: TimeDelta = TimeDelta(1);
+    pub const MICROSECOND: TimeDelta = TimeDelta(1_000);
+    pub const MILLISECOND: TimeDelta = TimeDelta(1_000_000);
+    pub const SECOND: TimeDelta = TimeDelta(1_000_000_000);
+    pub const MINUTE: TimeDelta = TimeDelta(60 * 1_000_000_000);
+    pub const HOUR: TimeDelta = TimeDelta(60 * 60 * 1_000_000_000);
+    pub const DAY: TimeDelta = TimeDelta(24 * 60 * 60 * 1_000_000_000);
+    pub const WEEK: TimeDelta = TimeDelta(7 * 24 * 60 * 60 * 1_000_000_000);
+}
+
 /// Explicit conversion from and to `i64`.
 impl TimeDelta {
     #[inline]
@@ -313,6 +920,16 @@ impl TimeDelta {
         TimeDelta(0)
     }
 
+    #[inline]
+    pub const fn from_weeks(int: i64) -> Self {
+        TimeDelta(int * 7 * 24 * 60 * 60 * 1_000_000_000)
+    }
+
+    #[inline]
+    pub const fn from_days(int: i64) -> Self {
+        TimeDelta(int * 24 * 60 * 60 * 1_000_000_000)
+    }
+
     #[inline]
     pub const fn from_hours(int: i64) -> Self {
         TimeDelta(int * 60 * 60 * 1_000_000_000)
@@ -333,22 +950,325 @@ impl TimeDelta {
         TimeDelta(int * 1_000_000)
     }
 
+    #[inline]
+    pub const fn from_microseconds(int: i64) -> Self {
+        TimeDelta(int * 1_000)
+    }
+
     #[inline]
     pub const fn from_nanoseconds(int: i64) -> Self {
         TimeDelta(int)
     }
 
+    #[inline]
+    pub const fn as_days(self) -> i64 {
+        self.0 / (24 * 60 * 60 * 1_000_000_000)
+    }
+
+    #[inline]
+    pub const fn as_hours(self) -> i64 {
+        self.0 / (60 * 60 * 1_000_000_000)
+    }
+
+    #[inline]
+    pub const fn as_minutes(self) -> i64 {
+        self.0 / (60 * 1_000_000_000)
+    }
+
+    #[inline]
+    pub const fn as_seconds(self) -> i64 {
+        self.0 / 1_000_000_000
+    }
+
     #[inline]
     pub const fn as_milliseconds(self) -> i64 {
         self.0 / 1_000_000
     }
 
+    #[inline]
+    pub const fn as_microseconds(self) -> i64 {
+        self.0 / 1_000
+    }
+
     #[inline]
     pub const fn as_nanoseconds(self) -> i64 {
         self.0
     }
+
+    /// Encode as 8 bytes of big endian two's complement `i64` nanoseconds.
+    ///
+    /// This layout is stable. Non-negative deltas sort byte-wise in order, negative ones sort
+    /// after all non-negative ones.
+    #[inline]
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
+
+    /// Decode from [`to_be_bytes`](Self::to_be_bytes).
+    #[inline]
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        TimeDelta(i64::from_be_bytes(bytes))
+    }
+
+    /// Encode as 8 bytes of little endian two's complement `i64` nanoseconds. This layout is
+    /// stable.
+    #[inline]
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
+
+    /// Decode from [`to_le_bytes`](Self::to_le_bytes).
+    #[inline]
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        TimeDelta(i64::from_le_bytes(bytes))
+    }
+
+    /// Create a delta from floating point seconds, rounding to the nearest nanosecond.
+    ///
+    /// Clamps to the `i64` nanosecond range. NaN maps to zero.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn from_secs_f64(secs: f64) -> Self {
+        let whole = secs.trunc();
+        let frac_nanos = ((secs - whole) * 1e9).round() as i64;
+        TimeDelta((whole as i64).saturating_mul(1_000_000_000).saturating_add(frac_nanos))
+    }
+
+    /// How many whole times `rhs` fits into the delta, rounding towards negative infinity for
+    /// positive `rhs`, so that `self == rhs * q + self.rem_euclid(rhs)`.
+    ///
+    /// Unlike the truncating `/`, a delta slightly before an anchor lands in bucket `-1`
+    /// rather than `0`.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is zero, or on overflow of `i64::MIN` divided by `-1` nanosecond.
+    #[inline]
+    pub const fn div_euclid(self, rhs: TimeDelta) -> i64 {
+        self.0.div_euclid(rhs.0)
+    }
+
+    /// The non-negative remainder of dividing by `rhs`, in `[0, |rhs|)`.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is zero, or on overflow of `i64::MIN` divided by `-1` nanosecond.
+    #[inline]
+    pub const fn rem_euclid(self, rhs: TimeDelta) -> TimeDelta {
+        TimeDelta(self.0.rem_euclid(rhs.0))
+    }
+
+    /// Scale the delta by a factor, rounding to the nearest nanosecond, halfway cases away from
+    /// zero.
+    ///
+    /// Clamps to the `i64` nanosecond range. A NaN factor yields zero. Deltas beyond about 104
+    /// days exceed the 53 bit precision of `f64` and are scaled with reduced precision.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn mul_f64(self, rhs: f64) -> TimeDelta {
+        TimeDelta((self.0 as f64 * rhs).round() as i64)
+    }
+
+    /// Divide the delta by a factor, rounding like [`mul_f64`](Self::mul_f64).
+    ///
+    /// Dividing by zero clamps to the longest delta of the same sign, or zero for a zero delta.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn div_f64(self, rhs: f64) -> TimeDelta {
+        TimeDelta((self.0 as f64 / rhs).round() as i64)
+    }
+
+    /// Floating point seconds, e.g. for metrics systems.
+    #[inline]
+    pub fn as_secs_f64(self) -> f64 {
+        (self.0 / 1_000_000_000) as f64 + (self.0 % 1_000_000_000) as f64 / 1e9
+    }
+
+    /// Sum up deltas, failing with [`TimeError::Overflow`] instead of overflowing like `sum()`.
+    pub fn try_sum<I: IntoIterator<Item = TimeDelta>>(deltas: I) -> Result<TimeDelta, TimeError> {
+        deltas.into_iter().try_fold(TimeDelta(0), |total, delta| {
+            total.0.checked_add(delta.0).map(TimeDelta).ok_or(TimeError::Overflow)
+        })
+    }
+
+    /// Absolute value of the delta, saturating at the longest positive delta.
+    #[inline]
+    pub const fn abs(self) -> TimeDelta {
+        TimeDelta(self.0.saturating_abs())
+    }
+
+    /// `-1`, `0` or `1` depending on the sign of the delta.
+    #[inline]
+    pub const fn signum(self) -> i64 {
+        self.0.signum()
+    }
+
+    /// Check whether the delta points backwards in time.
+    #[inline]
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Check whether the delta points forwards in time.
+    #[inline]
+    pub const fn is_positive(self) -> bool {
+        self.0 > 0
+    }
+
+    /// Check whether the delta is empty.
+    #[inline]
+    pub const fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Same as `self + rhs`, usable in const contexts.
+    #[inline]
+    pub const fn const_add(self, rhs: TimeDelta) -> TimeDelta {
+        TimeDelta(self.0 + rhs.0)
+    }
+
+    /// Same as `self - rhs`, usable in const contexts.
+    #[inline]
+    pub const fn const_sub(self, rhs: TimeDelta) -> TimeDelta {
+        TimeDelta(self.0 - rhs.0)
+    }
+
+    /// Add two deltas, `None` if the result exceeds the `i64` nanosecond range.
+    #[inline]
+    pub const fn checked_add(self, rhs: TimeDelta) -> Option<TimeDelta> {
+        match self.0.checked_add(rhs.0) {
+            Some(nanos) => Some(TimeDelta(nanos)),
+            None => None,
+        }
+    }
+
+    /// Subtract two deltas, `None` if the result exceeds the `i64` nanosecond range.
+    #[inline]
+    pub const fn checked_sub(self, rhs: TimeDelta) -> Option<TimeDelta> {
+        match self.0.checked_sub(rhs.0) {
+            Some(nanos) => Some(TimeDelta(nanos)),
+            None => None,
+        }
+    }
+
+    /// Multiply the delta, `None` if the result exceeds the `i64` nanosecond range.
+    #[inline]
+    pub const fn checked_mul(self, rhs: i64) -> Option<TimeDelta> {
+        match self.0.checked_mul(rhs) {
+            Some(nanos) => Some(TimeDelta(nanos)),
+            None => None,
+        }
+    }
+
+    /// Divide the delta, `None` if `rhs` is zero or the result overflows.
+    #[inline]
+    pub const fn checked_div(self, rhs: i64) -> Option<TimeDelta> {
+        match self.0.checked_div(rhs) {
+            Some(nanos) => Some(TimeDelta(nanos)),
+            None => None,
+        }
+    }
+
+    /// How many times `rhs` fits into the delta, `None` if `rhs` is zero or the result overflows.
+    #[inline]
+    pub const fn checked_div_delta(self, rhs: TimeDelta) -> Option<i64> {
+        self.0.checked_div(rhs.0)
+    }
+
+    /// Remainder of dividing by `rhs`, `None` if `rhs` is zero or the division overflows.
+    #[inline]
+    pub const fn checked_rem(self, rhs: TimeDelta) -> Option<TimeDelta> {
+        match self.0.checked_rem(rhs.0) {
+            Some(nanos) => Some(TimeDelta(nanos)),
+            None => None,
+        }
+    }
+
+    /// Add two deltas, clamping to the `i64` nanosecond range.
+    #[inline]
+    pub const fn saturating_add(self, rhs: TimeDelta) -> TimeDelta {
+        TimeDelta(self.0.saturating_add(rhs.0))
+    }
+
+    /// Subtract two deltas, clamping to the `i64` nanosecond range.
+    #[inline]
+    pub const fn saturating_sub(self, rhs: TimeDelta) -> TimeDelta {
+        TimeDelta(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Multiply the delta, clamping to the `i64` nanosecond range.
+    #[inline]
+    pub const fn saturating_mul(self, rhs: i64) -> TimeDelta {
+        TimeDelta(self.0.saturating_mul(rhs))
+    }
+
+    /// Divide the delta, clamping to the `i64` nanosecond range.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is zero.
+    #[inline]
+    pub const fn saturating_div(self, rhs: i64) -> TimeDelta {
+        TimeDelta(self.0.saturating_div(rhs))
+    }
+
+    /// Add two deltas, wrapping around at the ends of the `i64` range.
+    #[inline]
+    pub const fn wrapping_add(self, rhs: TimeDelta) -> TimeDelta {
+        TimeDelta(self.0.wrapping_add(rhs.0))
+    }
+
+    /// Subtract two deltas, wrapping around at the ends of the `i64` range.
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: TimeDelta) -> TimeDelta {
+        TimeDelta(self.0.wrapping_sub(rhs.0))
+    }
+
+    /// Check whether two deltas differ by at most `tolerance`. A negative tolerance never matches.
+    #[inline]
+    pub const fn approx_eq(self, other: TimeDelta, tolerance: TimeDelta) -> bool {
+        tolerance.0 >= 0 && self.0.abs_diff(other.0) <= tolerance.0 as u64
+    }
 } // This brace was missing
 
+/// Assert that two [`Timestamp`]s or two [`TimeDelta`]s are at most a given [`TimeDelta`] apart.
+///
+/// ```
+/// use fast_utc::{assert_ts_approx_eq, TimeDelta, Timestamp};
+///
+/// let a = Timestamp::from_milliseconds(1_000);
+/// let b = Timestamp::from_milliseconds(1_004);
+/// assert_ts_approx_eq!(a, b, TimeDelta::from_milliseconds(5));
+/// assert_ts_approx_eq!(b - a, TimeDelta::zero(), TimeDelta::from_milliseconds(5), "clock skew");
+/// ```
+#[macro_export]
+macro_rules! assert_ts_approx_eq {
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, &$tolerance) {
+            (left, right, tolerance) => {
+                if !left.approx_eq(*right, *tolerance) {
+                    panic!(
+                        "assertion `left ≈ right` failed\n     left: {:?}\n    right: {:?}\ntolerance: {:?}",
+                        left, right, tolerance,
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $tolerance:expr, $($arg:tt)+) => {
+        match (&$left, &$right, &$tolerance) {
+            (left, right, tolerance) => {
+                if !left.approx_eq(*right, *tolerance) {
+                    panic!(
+                        "assertion `left ≈ right` failed: {}\n     left: {:?}\n    right: {:?}\ntolerance: {:?}",
+                        format_args!($($arg)+), left, right, tolerance,
+                    );
+                }
+            }
+        }
+    };
+}
+
 // ============================================================================================== //
 // [TimeRange]                                                                                    //
 // ============================================================================================== //
@@ -361,6 +1281,7 @@ impl TimeDelta {
 /// Examples:
 ///
 /// ```
+/// # #[cfg(feature = "chrono-support")] {
 /// use fast_utc::TimeRange;
 /// use chrono::{offset::TimeZone, Duration, Utc};
 ///
@@ -376,6 +1297,7 @@ impl TimeDelta {
 ///     Utc.with_ymd_and_hms(2019, 4, 15, 12, 0, 0).unwrap().into(),
 ///     Utc.with_ymd_and_hms(2019, 4, 16, 0, 0, 0).unwrap().into(),
 /// ]);
+/// # }
 /// ```
 #[derive(Debug)]
 pub struct TimeRange {
@@ -442,8 +1364,10 @@ impl Iterator for TimeRange {
 #[cfg(test)]
 mod tests {
     use crate::*;
+    #[cfg(feature = "chrono-support")]
     use chrono::{offset::TimeZone, Duration, Utc};
 
+    #[cfg(feature = "chrono-support")]
     #[test]
     fn open_time_range() {
         let start = Utc.with_ymd_and_hms(2019, 4, 14, 0, 0, 0).unwrap();
@@ -459,7 +1383,9 @@ mod tests {
         ]);
     }
 
+    #[cfg(feature = "chrono-support")]
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn timestamp_and_delta_vs_chrono() {
         let c_dt = Utc.with_ymd_and_hms(2019, 3, 13, 16, 14, 9).unwrap();
         let c_td = Duration::nanoseconds(123456000000); // 123456 milliseconds as nanoseconds
@@ -489,6 +1415,7 @@ mod tests {
         assert_ne!(ts1, ts3);
     }
 
+            #[cfg(feature = "chrono-support")]
             #[test]
 
             fn align_to_anchored() {
@@ -561,6 +1488,7 @@ mod tests {
 
             }
 
+    #[cfg(feature = "chrono-support")]
     #[test]
     fn align_to_anchored_eq() {
         let day_naive = chrono::NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
@@ -591,13 +1519,446 @@ mod tests {
         coarsetime::Clock::update();
         let coarsetime_now = Timestamp::now();
         std::thread::sleep(Duration::from_millis(10));
-        let chrono_now = chrono::Utc::now();
-        let diff = (chrono_now.timestamp_nanos_opt().unwrap_or(0) - (coarsetime_now.as_nanoseconds() as i64)).abs();
+        let system_now = Timestamp::from(std::time::SystemTime::now());
         // Allow for a small difference due to the nature of coarsetime and thread sleep.
-        // 50ms = 50_000_000ns
-        assert!(diff < 50_000_000, "Difference was: {}", diff);
+        assert_ts_approx_eq!(system_now, coarsetime_now, TimeDelta::from_milliseconds(50));
+    }
+
+    #[cfg(feature = "coarsetime-support")]
+    #[test]
+    fn coarse_staleness() {
+        coarsetime_update();
+        let before = Timestamp::from(std::time::SystemTime::now());
+        assert!(Timestamp::coarse_staleness() < TimeDelta::from_seconds(1));
+        assert!(Timestamp::coarse_staleness() >= TimeDelta::zero());
+        assert!(Timestamp::coarse_last_update() <= before + TimeDelta::from_milliseconds(1));
+    }
+
+    #[test]
+    fn approx_eq() {
+        let ts = Timestamp::from_milliseconds(1_000);
+        let tolerance = TimeDelta::from_milliseconds(10);
+
+        assert!(ts.approx_eq(ts + tolerance, tolerance));
+        assert!(ts.approx_eq(ts - tolerance, tolerance));
+        assert!(!ts.approx_eq(ts + tolerance + TimeDelta::from_nanoseconds(1), tolerance));
+        assert!(!ts.approx_eq(ts, TimeDelta::from_nanoseconds(-1)));
+        assert!(TimeDelta::from_seconds(-1).approx_eq(TimeDelta::from_milliseconds(-995), tolerance));
+        assert!(!TimeDelta::from_seconds(-1).approx_eq(TimeDelta::from_seconds(1), tolerance));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion `left ≈ right` failed")]
+    fn assert_ts_approx_eq_fails() {
+        assert_ts_approx_eq!(Timestamp::zero(), Timestamp::from_seconds(1), TimeDelta::from_milliseconds(999));
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let ts = Timestamp::from_seconds(10);
+
+        assert_eq!(ts.checked_add(TimeDelta::from_seconds(-10)), Some(Timestamp::zero()));
+        assert_eq!(ts.checked_add(TimeDelta::from_seconds(-11)), None);
+        assert_eq!(ts.checked_sub(TimeDelta::from_seconds(11)), None);
+        assert_eq!(ts.checked_sub(TimeDelta::from_seconds(-5)), Some(Timestamp::from_seconds(15)));
+        assert_eq!(Timestamp::from_nanoseconds(u64::MAX).checked_add(TimeDelta::from_nanoseconds(1)), None);
+
+        assert_eq!(ts.checked_duration_since(Timestamp::from_seconds(4)), Some(TimeDelta::from_seconds(6)));
+        assert_eq!(ts.checked_duration_since(Timestamp::from_seconds(11)), None);
+        assert_eq!(Timestamp::from_nanoseconds(u64::MAX).checked_duration_since(Timestamp::zero()), None);
+    }
+
+    #[test]
+    fn saturating_and_wrapping_arithmetic() {
+        let max = Timestamp::from_nanoseconds(u64::MAX);
+        let one = TimeDelta::from_nanoseconds(1);
+
+        assert_eq!(Timestamp::zero().saturating_sub(one), Timestamp::zero());
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(max.saturating_sub(TimeDelta::from_nanoseconds(-1)), max);
+        assert_eq!(Timestamp::zero().wrapping_sub(one), max);
+        assert_eq!(max.wrapping_add(one), Timestamp::zero());
+        assert_eq!(max.wrapping_sub(TimeDelta::from_nanoseconds(-2)), Timestamp::from_nanoseconds(1));
+
+        let delta_max = TimeDelta::from_nanoseconds(i64::MAX);
+        let delta_min = TimeDelta::from_nanoseconds(i64::MIN);
+        assert_eq!(delta_max.saturating_add(one), delta_max);
+        assert_eq!(delta_min.saturating_sub(one), delta_min);
+        assert_eq!(delta_max.wrapping_add(one), delta_min);
+        assert_eq!(delta_min.wrapping_sub(one), delta_max);
+    }
+
+    #[cfg(feature = "chrono-support")]
+    #[test]
+    fn representable_range() {
+        assert_eq!(Timestamp::MIN, Timestamp::zero());
+        assert_eq!(Timestamp::MAX.to_string(), "2554-07-21 23:34:33.709551615 UTC");
+
+        let last = Timestamp::from_nanoseconds(i64::MAX as u64);
+        assert_eq!(last.to_string(), "2262-04-11 23:47:16.854775807 UTC");
+        let next = Timestamp::from_nanoseconds(i64::MAX as u64 + 1);
+        for ts in [Timestamp::MIN, last, next, Timestamp::MAX] {
+            let round_trip = Timestamp::try_from_chrono(chrono::DateTime::<chrono::Utc>::from(ts));
+            assert_eq!(ts.is_representable_as_chrono(), round_trip == Ok(ts), "ts = {}", ts);
+        }
+        assert!(!Timestamp::MAX.is_representable_as_chrono());
+    }
+
+    #[cfg(feature = "chrono-support")]
+    #[test]
+    fn from_str() {
+        let expected = Timestamp::from_milliseconds(1_714_570_200_500);
+        assert_eq!("2024-05-01T09:30:00.5-04:00".parse(), Ok(expected));
+        assert_eq!("2024-05-01T13:30:00.500Z".parse(), Ok(expected));
+        assert_eq!("1714570200500000000".parse(), Ok(expected));
+
+        assert_eq!("18446744073709551616".parse::<Timestamp>(), Err(TimeError::Overflow));
+        assert_eq!("2300-01-01T00:00:00Z".parse::<Timestamp>(), Err(TimeError::Overflow));
+        assert_eq!("1969-12-31T23:59:59Z".parse::<Timestamp>(), Err(TimeError::BeforeEpoch));
+        for invalid in ["", "-1", "2024-05-01", "2024-05-01 13:30:00", "1.5"] {
+            assert_eq!(invalid.parse::<Timestamp>(), Err(TimeError::InvalidFormat), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn time_delta_unit_constants() {
+        assert_eq!(TimeDelta::MICROSECOND, TimeDelta::NANOSECOND * 1_000);
+        assert_eq!(TimeDelta::MILLISECOND, TimeDelta::from_milliseconds(1));
+        assert_eq!(TimeDelta::SECOND, TimeDelta::from_seconds(1));
+        assert_eq!(TimeDelta::MINUTE * 90, TimeDelta::HOUR + TimeDelta::MINUTE * 30);
+        assert_eq!(TimeDelta::DAY, TimeDelta::from_hours(24));
+        assert_eq!(TimeDelta::WEEK, TimeDelta::DAY * 7);
+
+        let ts = Timestamp::from_seconds(1_700_000_123);
+        assert_eq!(ts.align_to(TimeDelta::MINUTE * 5), Timestamp::from_seconds(1_700_000_100));
+    }
+
+    #[test]
+    fn time_delta_sign() {
+        let early = Timestamp::from_seconds(10);
+        let late = Timestamp::from_seconds(25);
+
+        assert_eq!((early - late).abs(), TimeDelta::from_seconds(15));
+        assert_eq!((early - late).signum(), -1);
+        assert_eq!((late - early).signum(), 1);
+        assert!((early - late).is_negative() && !(early - late).is_positive());
+        assert!((late - early).is_positive() && !(late - early).is_negative());
+        assert!((late - late).is_zero() && (late - late).signum() == 0);
+        assert!(!(late - late).is_positive() && !(late - late).is_negative());
+        assert_eq!(TimeDelta::from_nanoseconds(i64::MIN).abs(), TimeDelta::from_nanoseconds(i64::MAX));
+    }
+
+    #[test]
+    fn time_delta_neg() {
+        let ts = Timestamp::from_seconds(100);
+        let delta = TimeDelta::from_seconds(30);
+
+        assert_eq!(-delta, TimeDelta::from_seconds(-30));
+        assert_eq!(-(-delta), delta);
+        assert_eq!(ts + (-delta), ts - delta);
+        assert_eq!(ts - (-delta), ts + delta);
+        assert_eq!(ts + (-TimeDelta::from_seconds(200)), Timestamp::zero());
+        assert_eq!(-TimeDelta::zero(), TimeDelta::zero());
+    }
+
+    #[test]
+    fn time_delta_sum() {
+        let latencies = [TimeDelta::from_microseconds(250), TimeDelta::from_microseconds(750), -TimeDelta::SECOND];
+
+        assert_eq!(latencies.iter().sum::<TimeDelta>(), TimeDelta::from_microseconds(-999_000));
+        assert_eq!(latencies.into_iter().sum::<TimeDelta>(), TimeDelta::from_microseconds(-999_000));
+        assert_eq!([].iter().sum::<TimeDelta>(), TimeDelta::zero());
+        assert_eq!(TimeDelta::try_sum(latencies), Ok(TimeDelta::from_microseconds(-999_000)));
+
+        let huge = TimeDelta::from_nanoseconds(i64::MAX / 2 + 1);
+        assert_eq!(TimeDelta::try_sum([huge, huge]), Err(TimeError::Overflow));
+        assert_eq!(TimeDelta::try_sum([huge, huge, -huge]), Err(TimeError::Overflow));
+        assert_eq!(TimeDelta::try_sum([huge, -huge, huge]), Ok(huge));
+    }
+
+    #[test]
+    fn time_delta_checked_and_saturating() {
+        let max = TimeDelta::from_nanoseconds(i64::MAX);
+        let min = TimeDelta::from_nanoseconds(i64::MIN);
+        let second = TimeDelta::SECOND;
+
+        assert_eq!(second.checked_add(second), Some(second * 2));
+        assert_eq!(max.checked_add(TimeDelta::NANOSECOND), None);
+        assert_eq!(min.checked_sub(TimeDelta::NANOSECOND), None);
+        assert_eq!(second.checked_mul(-3), Some(-second * 3));
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(second.checked_div(4), Some(TimeDelta::from_milliseconds(250)));
+        assert_eq!(second.checked_div(0), None);
+        assert_eq!(min.checked_div(-1), None);
+        assert_eq!(TimeDelta::MINUTE.checked_div_delta(second), Some(60));
+        assert_eq!(TimeDelta::MINUTE.checked_div_delta(TimeDelta::zero()), None);
+        assert_eq!(TimeDelta::from_seconds(61).checked_rem(TimeDelta::MINUTE), Some(second));
+        assert_eq!(second.checked_rem(TimeDelta::zero()), None);
+
+        assert_eq!(max.saturating_mul(2), max);
+        assert_eq!(max.saturating_mul(-2), min);
+        assert_eq!(min.saturating_div(-1), max);
+        assert_eq!(max.saturating_add(second), max);
+        assert_eq!(min.saturating_sub(second), min);
+    }
+
+    #[test]
+    fn time_delta_large_units() {
+        assert_eq!(TimeDelta::from_days(1), TimeDelta::from_hours(24));
+        assert_eq!(TimeDelta::from_weeks(2), TimeDelta::from_days(14));
+
+        let delta = TimeDelta::from_days(3) + TimeDelta::from_hours(5) + TimeDelta::from_nanoseconds(1_500);
+        assert_eq!(delta.as_days(), 3);
+        assert_eq!(delta.as_hours(), 77);
+        assert_eq!(delta.as_minutes(), 77 * 60);
+        assert_eq!(delta.as_seconds(), 77 * 3_600);
+        assert_eq!(delta.as_microseconds(), 77 * 3_600_000_000 + 1);
+        // Truncated towards zero, like integer division.
+        assert_eq!((-delta).as_days(), -3);
+        assert_eq!(TimeDelta::from_seconds(-59).as_minutes(), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn float_seconds() {
+        assert_eq!(TimeDelta::from_secs_f64(1.5), TimeDelta::from_milliseconds(1_500));
+        assert_eq!(TimeDelta::from_secs_f64(-0.000_000_001_4), TimeDelta::from_nanoseconds(-1));
+        assert_eq!(TimeDelta::from_secs_f64(f64::INFINITY), TimeDelta::from_nanoseconds(i64::MAX));
+        assert_eq!(TimeDelta::from_secs_f64(f64::NAN), TimeDelta::zero());
+        assert_eq!(TimeDelta::from_milliseconds(-250).as_secs_f64(), -0.25);
+
+        let ts = Timestamp::from_secs_f64(1_700_000_000.25);
+        assert_eq!(ts, Timestamp::from_milliseconds(1_700_000_000_250));
+        assert_eq!(ts.as_secs_f64(), 1_700_000_000.25);
+        assert_eq!(Timestamp::from_secs_f64(-1.0), Timestamp::zero());
+        assert_eq!(Timestamp::from_secs_f64(1e20), Timestamp::MAX);
+        let precise = Timestamp::from_nanoseconds(1_700_000_000_123_456_789);
+        assert_ts_approx_eq!(Timestamp::from_secs_f64(precise.as_secs_f64()), precise, TimeDelta::MICROSECOND);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn time_delta_float_scaling() {
+        let mut backoff = TimeDelta::from_milliseconds(100);
+        for _ in 0..3 {
+            backoff = backoff * 1.5;
+        }
+        assert_eq!(backoff, TimeDelta::from_microseconds(337_500));
+        assert_eq!(backoff / 2.5, TimeDelta::from_milliseconds(135));
+
+        assert_eq!(TimeDelta::from_nanoseconds(5) * 0.5, TimeDelta::from_nanoseconds(3));
+        assert_eq!(TimeDelta::from_nanoseconds(-5) * 0.5, TimeDelta::from_nanoseconds(-3));
+        assert_eq!(TimeDelta::from_nanoseconds(10).div_f64(3.0), TimeDelta::from_nanoseconds(3));
+        assert_eq!(TimeDelta::SECOND.mul_f64(f64::NAN), TimeDelta::zero());
+        assert_eq!(TimeDelta::SECOND.div_f64(0.0), TimeDelta::from_nanoseconds(i64::MAX));
+        assert_eq!((-TimeDelta::SECOND).div_f64(0.0), TimeDelta::from_nanoseconds(i64::MIN));
+    }
+
+    #[test]
+    fn time_delta_euclid() {
+        let anchor = Timestamp::from_seconds(1_000);
+        let bucket = TimeDelta::MINUTE;
+        let before = Timestamp::from_seconds(990) - anchor;
+
+        assert_eq!(before / bucket, 0);
+        assert_eq!(before.div_euclid(bucket), -1);
+        assert_eq!(before.rem_euclid(bucket), TimeDelta::from_seconds(50));
+        assert_eq!(bucket * before.div_euclid(bucket) + before.rem_euclid(bucket), before);
+
+        let after = TimeDelta::from_seconds(130);
+        assert_eq!(after.div_euclid(bucket), 2);
+        assert_eq!(after.rem_euclid(bucket), TimeDelta::from_seconds(10));
+        assert_eq!(after.rem_euclid(-bucket), TimeDelta::from_seconds(10));
+        assert_eq!((-bucket).div_euclid(bucket), -1);
+        assert_eq!((-bucket).rem_euclid(bucket), TimeDelta::zero());
+    }
+
+    #[test]
+    fn time_delta_assign_ops() {
+        let mut total = TimeDelta::zero();
+        for ms in [10, 20, 30] {
+            total += TimeDelta::from_milliseconds(ms);
+        }
+        assert_eq!(total, TimeDelta::from_milliseconds(60));
+
+        total -= TimeDelta::from_milliseconds(15);
+        assert_eq!(total, TimeDelta::from_milliseconds(45));
+        total *= 4;
+        assert_eq!(total, TimeDelta::from_milliseconds(180));
+        total /= -3;
+        assert_eq!(total, TimeDelta::from_milliseconds(-60));
+    }
+
+    #[test]
+    fn byte_encoding_layout() {
+        let ts = Timestamp::from_nanoseconds(0x0102_0304_0506_0708);
+        assert_eq!(ts.to_be_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(ts.to_le_bytes(), [8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(Timestamp::from_be_bytes(ts.to_be_bytes()), ts);
+        assert_eq!(Timestamp::from_le_bytes(ts.to_le_bytes()), ts);
+
+        let scrambled = |i: u64| Timestamp::from_nanoseconds(i.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+        let mut timestamps: Vec<_> = (0..1_000).map(scrambled).collect();
+        let mut keys: Vec<_> = timestamps.iter().map(|ts| ts.to_be_bytes()).collect();
+        timestamps.sort();
+        keys.sort();
+        assert!(keys.iter().zip(&timestamps).all(|(key, &ts)| Timestamp::from_be_bytes(*key) == ts));
+
+        let delta = TimeDelta::from_nanoseconds(-2);
+        assert_eq!(delta.to_be_bytes(), [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe]);
+        assert_eq!(delta.to_le_bytes(), [0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]);
+        assert_eq!(TimeDelta::from_be_bytes(delta.to_be_bytes()), delta);
+        assert_eq!(TimeDelta::from_le_bytes(delta.to_le_bytes()), delta);
+    }
+
+    #[cfg(feature = "chrono-support")]
+    #[test]
+    fn checked_chrono_conversions() {
+        use chrono::{TimeZone, Utc};
+
+        let dt = Utc.with_ymd_and_hms(2024, 5, 1, 13, 30, 0).unwrap();
+        let ts = Timestamp::from_seconds(1_714_570_200);
+        assert_eq!(Timestamp::try_from_chrono(dt), Ok(ts));
+        assert_eq!(ts.try_into_chrono(), Ok(dt));
+
+        let before = Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap();
+        let long_before = Utc.with_ymd_and_hms(1000, 1, 1, 0, 0, 0).unwrap();
+        let after = Utc.with_ymd_and_hms(2300, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(Timestamp::try_from_chrono(before), Err(TimeError::BeforeEpoch));
+        assert_eq!(Timestamp::try_from_chrono(long_before), Err(TimeError::BeforeEpoch));
+        assert_eq!(Timestamp::try_from_chrono(after), Err(TimeError::Overflow));
+        assert_eq!(Timestamp::MAX.try_into_chrono(), Err(TimeError::Overflow));
+    }
+
+    #[cfg(feature = "chrono-support")]
+    #[test]
+    fn from_any_time_zone() {
+        use chrono::{FixedOffset, TimeZone};
+
+        let offset = FixedOffset::west_opt(4 * 3_600).unwrap();
+        let dt = offset.with_ymd_and_hms(2024, 5, 1, 9, 30, 0).unwrap();
+        assert_eq!(Timestamp::from(dt), Timestamp::from_seconds(1_714_570_200));
+        assert_eq!(Timestamp::try_from_chrono(dt), Ok(Timestamp::from_seconds(1_714_570_200)));
+
+        let local: Timestamp = chrono::Local.timestamp_opt(1_714_570_200, 5).unwrap().into();
+        assert_eq!(local, Timestamp::from_nanoseconds(1_714_570_200_000_000_005));
+    }
+
+    #[cfg(all(feature = "strict-conversions", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "lossy time conversion: time lies before the Unix epoch")]
+    fn strict_conversions_panic() {
+        use chrono::TimeZone;
+        let _ = Timestamp::from(chrono::Utc.with_ymd_and_hms(1969, 12, 31, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn time_delta_from_str() {
+        let cases = [
+            ("1h30m", TimeDelta::from_minutes(90)),
+            ("250ms", TimeDelta::from_milliseconds(250)),
+            ("90s", TimeDelta::from_seconds(90)),
+            ("1d12h", TimeDelta::from_hours(36)),
+            ("-1.5s", TimeDelta::from_milliseconds(-1_500)),
+            ("+.5us", TimeDelta::from_nanoseconds(500)),
+            ("1µs1ns", TimeDelta::from_nanoseconds(1_001)),
+            ("2m0.000000001s", TimeDelta::from_seconds(120) + TimeDelta::from_nanoseconds(1)),
+            ("0", TimeDelta::zero()),
+            ("-0", TimeDelta::zero()),
+            ("-9223372036.854775808s", TimeDelta::from_nanoseconds(i64::MIN)),
+        ];
+        for (s, delta) in cases {
+            assert_eq!(s.parse(), Ok(delta), "{}", s);
+        }
+
+        for invalid in ["", "-", "1", "1x", "h", ".s", "1h 30m", "1hh", "0s0"] {
+            assert_eq!(invalid.parse::<TimeDelta>(), Err(TimeError::InvalidFormat), "{}", invalid);
+        }
+        assert_eq!("9223372036.854775808s".parse::<TimeDelta>(), Err(TimeError::Overflow));
+        let huge = "99999999999999999999999999999999999999999h";
+        assert_eq!(huge.parse::<TimeDelta>(), Err(TimeError::Overflow));
+        assert_eq!("-170141183460469231731687303715884105728ns".parse::<TimeDelta>(), Err(TimeError::Overflow));
+    }
+
+    #[test]
+    fn const_arithmetic() {
+        const OPEN: Timestamp = Timestamp::from_nanoseconds(1_700_000_000_000_000_000);
+        const SESSION: TimeDelta = TimeDelta::from_hours(6).const_sub(TimeDelta::from_minutes(30));
+        const CLOSE: Timestamp = OPEN.const_add(SESSION.const_add(TimeDelta::from_minutes(30)));
+        const SCHEDULE: [Timestamp; 2] = [OPEN.const_sub(TimeDelta::from_hours(1)), CLOSE];
+
+        assert_eq!(CLOSE, OPEN + TimeDelta::from_hours(6));
+        assert_eq!(SCHEDULE[0], OPEN - TimeDelta::from_hours(1));
+        assert_eq!(Timestamp::zero().const_sub(SESSION), Timestamp::zero());
+    }
+
+    #[test]
+    fn microsecond_and_second_accessors() {
+        let ts = Timestamp::from_microseconds(1_556_064_000_123_456);
+        assert_eq!(ts, Timestamp::from_nanoseconds(1_556_064_000_123_456_000));
+        assert_eq!(ts.as_microseconds(), 1_556_064_000_123_456);
+        assert_eq!(ts.as_seconds(), 1_556_064_000);
+        assert_eq!(Timestamp::try_from_microseconds(u64::MAX), Err(TimeError::Overflow));
+
+        let delta = TimeDelta::from_microseconds(-1_500);
+        assert_eq!(delta, TimeDelta::from_nanoseconds(-1_500_000));
+        assert_eq!(delta.as_microseconds(), -1_500);
+    }
+
+    #[test]
+    fn overflow_safe_constructors() {
+        assert_eq!(Timestamp::try_from_seconds(18_446_744_073), Ok(Timestamp::from_nanoseconds(18_446_744_073_000_000_000)));
+        assert_eq!(Timestamp::try_from_seconds(18_446_744_074), Err(TimeError::Overflow));
+        assert_eq!(Timestamp::try_from_milliseconds(u64::MAX), Err(TimeError::Overflow));
+        assert_eq!(Timestamp::try_from_milliseconds(5), Ok(Timestamp::from_nanoseconds(5_000_000)));
+    }
+
+    #[test]
+    #[should_panic(expected = "seconds out of range")]
+    fn from_seconds_overflow_panics() {
+        let _ = Timestamp::from_seconds(u64::MAX);
+    }
+
+    #[cfg(feature = "bytemuck-support")]
+    #[test]
+    fn bytemuck_casts() {
+        let raw: Vec<u64> = vec![0, 1_000, 1_714_570_200_000_000_000];
+        let timestamps: &[Timestamp] = bytemuck::cast_slice(&raw);
+        assert_eq!(timestamps[2], Timestamp::from_seconds(1_714_570_200));
+
+        let bytes: &[u8] = bytemuck::cast_slice(timestamps);
+        assert_eq!(bytes.len(), 24);
+        let second: Timestamp = bytemuck::pod_read_unaligned(&bytes[8..16]);
+        assert_eq!(second, Timestamp::from_nanoseconds(1_000));
+
+        let deltas: &[TimeDelta] = bytemuck::cast_slice(&[-1i64, 5]);
+        assert_eq!(deltas, [TimeDelta::from_nanoseconds(-1), TimeDelta::from_nanoseconds(5)]);
+        assert_eq!(<Timestamp as bytemuck::Zeroable>::zeroed(), Timestamp::zero());
+    }
+
+    #[cfg(feature = "serde-support")]
+    #[test]
+    fn serde_json_map_keys() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let mut by_time = BTreeMap::new();
+        by_time.insert(Timestamp::from_seconds(5), 1);
+        by_time.insert(Timestamp::from_nanoseconds(7), 2);
+        let json = serde_json::to_string(&by_time).unwrap();
+        #[cfg(not(feature = "serde-nanos"))]
+        assert_eq!(json, r#"{"1970-01-01T00:00:00.000000007Z":2,"1970-01-01T00:00:05.000000000Z":1}"#);
+        #[cfg(feature = "serde-nanos")]
+        assert_eq!(json, r#"{"7":2,"5000000000":1}"#);
+        assert_eq!(serde_json::from_str::<BTreeMap<Timestamp, i32>>(&json).unwrap(), by_time);
+
+        let mut by_delta = HashMap::new();
+        by_delta.insert(TimeDelta::from_seconds(-5), "late");
+        let json = serde_json::to_string(&by_delta).unwrap();
+        assert_eq!(json, r#"{"-5000000000":"late"}"#);
+        assert_eq!(serde_json::from_str::<HashMap<TimeDelta, &str>>(&json).unwrap(), by_delta);
     }
 
+    #[cfg(feature = "chrono-support")]
     #[test]
     fn test_fetch_chrono_utc_now() {
         use chrono::Utc;
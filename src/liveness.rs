@@ -0,0 +1,232 @@
+//! Tracking whether peers are still alive from the time of their last heartbeat.
+
+use core::borrow::Borrow;
+use core::hash::Hash;
+use core::sync::atomic::Ordering;
+use std::collections::HashMap;
+
+use crate::{AtomicTimestamp, TimeDelta, Timestamp};
+
+// ============================================================================================== //
+// [Heartbeat]                                                                                    //
+// ============================================================================================== //
+
+/// Time of the last heartbeat received from a single peer.
+///
+/// ```
+/// use fast_utc::{Heartbeat, TimeDelta, Timestamp};
+///
+/// let mut heartbeat = Heartbeat::new(Timestamp::from_seconds(100));
+/// heartbeat.beat(Timestamp::from_seconds(105));
+///
+/// let now = Timestamp::from_seconds(112);
+/// assert_eq!(heartbeat.staleness(now), TimeDelta::from_seconds(7));
+/// assert!(heartbeat.is_stale(now, TimeDelta::from_seconds(5)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Heartbeat {
+    last: Timestamp,
+}
+
+impl Heartbeat {
+    /// Start tracking a peer, counting `now` as its first heartbeat.
+    #[inline]
+    pub const fn new(now: Timestamp) -> Self {
+        Heartbeat { last: now }
+    }
+
+    /// Record a heartbeat. Heartbeats older than the last one are ignored.
+    #[inline]
+    pub fn beat(&mut self, now: Timestamp) {
+        self.last = self.last.max(now);
+    }
+
+    /// Time of the last heartbeat.
+    #[inline]
+    pub const fn last_beat(&self) -> Timestamp {
+        self.last
+    }
+
+    /// Time since the last heartbeat, zero if it lies after `now`.
+    #[inline]
+    pub fn staleness(&self, now: Timestamp) -> TimeDelta {
+        staleness(self.last, now)
+    }
+
+    /// Check whether more than `threshold` passed since the last heartbeat.
+    #[inline]
+    pub fn is_stale(&self, now: Timestamp, threshold: TimeDelta) -> bool {
+        self.staleness(now) > threshold
+    }
+}
+
+fn staleness(last: Timestamp, now: Timestamp) -> TimeDelta {
+    now.checked_duration_since(last).unwrap_or(TimeDelta::zero())
+}
+
+// ============================================================================================== //
+// [LivenessTable]                                                                                //
+// ============================================================================================== //
+
+/// Heartbeats of many peers, e.g. all connections of a supervisor.
+///
+/// Registering and removing peers needs exclusive access, while heartbeats of registered peers
+/// are recorded through a shared reference without locking, so the table can be shared between
+/// the threads serving the connections.
+///
+/// ```
+/// use fast_utc::{LivenessTable, TimeDelta, Timestamp};
+///
+/// let mut table = LivenessTable::new();
+/// table.register("feed-a", Timestamp::from_seconds(0));
+/// table.register("feed-b", Timestamp::from_seconds(0));
+/// assert!(table.beat("feed-a", Timestamp::from_seconds(9)));
+///
+/// let stale: Vec<_> = table.stale(Timestamp::from_seconds(10), TimeDelta::from_seconds(5)).collect();
+/// assert_eq!(stale, [&"feed-b"]);
+/// ```
+#[derive(Debug)]
+pub struct LivenessTable<K> {
+    peers: HashMap<K, AtomicTimestamp>,
+}
+
+impl<K: Eq + Hash> LivenessTable<K> {
+    /// Create an empty table.
+    pub fn new() -> Self {
+        LivenessTable { peers: HashMap::new() }
+    }
+
+    /// Start tracking `key`, counting `now` as its first heartbeat. Resets an existing entry.
+    pub fn register(&mut self, key: K, now: Timestamp) {
+        self.peers.insert(key, AtomicTimestamp::new(now));
+    }
+
+    /// Stop tracking `key`, returning the time of its last heartbeat.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<Timestamp>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.peers.remove(key).map(AtomicTimestamp::into_inner)
+    }
+
+    /// Record a heartbeat of `key`. Returns `false` if the key isn't registered.
+    ///
+    /// Heartbeats older than the last one are ignored.
+    pub fn beat<Q>(&self, key: &Q, now: Timestamp) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        match self.peers.get(key) {
+            Some(last) => {
+                last.fetch_max(now, Ordering::Relaxed);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Time of the last heartbeat of `key`.
+    pub fn last_beat<Q>(&self, key: &Q) -> Option<Timestamp>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.peers.get(key).map(|last| last.load(Ordering::Relaxed))
+    }
+
+    /// Time since the last heartbeat of `key`, zero if it lies after `now`.
+    pub fn staleness<Q>(&self, key: &Q, now: Timestamp) -> Option<TimeDelta>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.last_beat(key).map(|last| staleness(last, now))
+    }
+
+    /// Check whether more than `threshold` passed since the last heartbeat of `key`.
+    ///
+    /// Unregistered keys count as stale.
+    pub fn is_stale<Q>(&self, key: &Q, now: Timestamp, threshold: TimeDelta) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.staleness(key, now).is_none_or(|staleness| staleness > threshold)
+    }
+
+    /// Keys whose last heartbeat is more than `threshold` before `now`, in no particular order.
+    pub fn stale(&self, now: Timestamp, threshold: TimeDelta) -> impl Iterator<Item = &K> {
+        self.peers
+            .iter()
+            .filter(move |(_, last)| staleness(last.load(Ordering::Relaxed), now) > threshold)
+            .map(|(key, _)| key)
+    }
+
+    /// Number of tracked peers.
+    pub fn len(&self) -> usize {
+        self.peers.len()
+    }
+
+    /// Check whether no peers are tracked.
+    pub fn is_empty(&self) -> bool {
+        self.peers.is_empty()
+    }
+}
+
+impl<K: Eq + Hash> Default for LivenessTable<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn heartbeat_staleness() {
+        let mut heartbeat = Heartbeat::new(Timestamp::from_seconds(10));
+        heartbeat.beat(Timestamp::from_seconds(20));
+        heartbeat.beat(Timestamp::from_seconds(15));
+
+        assert_eq!(heartbeat.last_beat(), Timestamp::from_seconds(20));
+        assert_eq!(heartbeat.staleness(Timestamp::from_seconds(19)), TimeDelta::zero());
+        assert!(!heartbeat.is_stale(Timestamp::from_seconds(25), TimeDelta::from_seconds(5)));
+        assert!(heartbeat.is_stale(Timestamp::from_seconds(26), TimeDelta::from_seconds(5)));
+    }
+
+    #[test]
+    fn liveness_table_shared_beats() {
+        let mut table = LivenessTable::new();
+        for id in 0..4u32 {
+            table.register(id, Timestamp::zero());
+        }
+
+        std::thread::scope(|s| {
+            for id in 0..3u32 {
+                let table = &table;
+                s.spawn(move || {
+                    for secs in 1..=100 {
+                        table.beat(&id, Timestamp::from_seconds(secs));
+                    }
+                });
+            }
+        });
+
+        let now = Timestamp::from_seconds(101);
+        let threshold = TimeDelta::from_seconds(10);
+        assert_eq!(table.stale(now, threshold).collect::<Vec<_>>(), [&3]);
+        assert_eq!(table.staleness(&0, now), Some(TimeDelta::from_seconds(1)));
+        assert!(table.is_stale(&7, now, threshold));
+        assert!(!table.beat(&7, now));
+
+        assert_eq!(table.remove(&0), Some(Timestamp::from_seconds(100)));
+        assert_eq!(table.len(), 3);
+    }
+}
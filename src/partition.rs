@@ -0,0 +1,257 @@
+//! Mapping timestamps to the time partitions of data lakes and back.
+
+use crate::civil::{days_from_civil, days_in_month};
+use crate::{Interval, TimeDelta, TimeOfDay, Timestamp};
+
+// ============================================================================================== //
+// [PartitionScheme]                                                                              //
+// ============================================================================================== //
+
+/// How a time series is split into partitions, all in UTC.
+///
+/// Partitions are labelled by the start of the partition, either plainly (`2024-05-01`) or as
+/// Hive style key/value directories (`dt=2024-05-01/hour=13`). Both forms parse back into the
+/// covered [`Interval`].
+///
+/// | Scheme       | `label`               | `hive_label`                |
+/// |--------------|-----------------------|-----------------------------|
+/// | `Hourly`     | `2024-05-01T13`       | `dt=2024-05-01/hour=13`     |
+/// | `Daily`      | `2024-05-01`          | `dt=2024-05-01`             |
+/// | `Monthly`    | `2024-05`             | `dt=2024-05`                |
+/// | `Custom(..)` | `2024-05-01T13:15:00` | `ts=2024-05-01T13:15:00`    |
+///
+/// Custom partitions are aligned to the epoch and include the nanoseconds in the label if
+/// they don't start on a whole second.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PartitionScheme {
+    Hourly,
+    Daily,
+    Monthly,
+    /// Fixed length partitions. The length must be positive.
+    Custom(TimeDelta),
+}
+
+impl PartitionScheme {
+    /// The partition containing `ts`.
+    ///
+    /// The last partition is cut short at [`Timestamp::MAX`], which it therefore doesn't contain.
+    ///
+    /// # Panics
+    ///
+    /// If the length of a `Custom` scheme isn't positive.
+    pub fn interval(self, ts: Timestamp) -> Interval {
+        match self {
+            PartitionScheme::Hourly => Self::fixed(ts, TimeDelta::from_hours(1)),
+            PartitionScheme::Daily => Self::fixed(ts, TimeDelta::from_days(1)),
+            PartitionScheme::Custom(freq) => Self::fixed(ts, freq),
+            PartitionScheme::Monthly => {
+                let (year, month, _) = ts.civil_date();
+                month_interval(year, month).expect("month of a timestamp starts after the epoch")
+            }
+        }
+    }
+
+    /// Plain label of the partition containing `ts`, e.g. `2024-05-01`.
+    pub fn label(self, ts: Timestamp) -> String {
+        let start = self.interval(ts).start();
+        let (year, month, day) = start.civil_date();
+        match self {
+            PartitionScheme::Hourly => {
+                format!("{:04}-{:02}-{:02}T{:02}", year, month, day, start.time_of_day().hour())
+            }
+            PartitionScheme::Daily => format!("{:04}-{:02}-{:02}", year, month, day),
+            PartitionScheme::Monthly => format!("{:04}-{:02}", year, month),
+            PartitionScheme::Custom(_) => {
+                let time = start.time_of_day();
+                let mut label = format!(
+                    "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+                    year,
+                    month,
+                    day,
+                    time.hour(),
+                    time.minute(),
+                    time.second(),
+                );
+                if time.nanosecond() != 0 {
+                    label.push_str(&format!(".{:09}", time.nanosecond()));
+                }
+                label
+            }
+        }
+    }
+
+    /// Hive style label of the partition containing `ts`, e.g. `dt=2024-05-01/hour=13`.
+    pub fn hive_label(self, ts: Timestamp) -> String {
+        match self {
+            PartitionScheme::Hourly => {
+                let label = self.label(ts);
+                let (date, hour) = label.split_at(10);
+                format!("dt={}/hour={}", date, &hour[1..])
+            }
+            PartitionScheme::Daily | PartitionScheme::Monthly => format!("dt={}", self.label(ts)),
+            PartitionScheme::Custom(_) => format!("ts={}", self.label(ts)),
+        }
+    }
+
+    /// Parse a plain or Hive style label back into the partition's interval.
+    ///
+    /// Returns `None` if the label is malformed or doesn't start a partition of this scheme.
+    pub fn parse_label(self, label: &str) -> Option<Interval> {
+        let start = match self {
+            PartitionScheme::Hourly => {
+                let (date, hour) = match label.strip_prefix("dt=") {
+                    Some(hive) => hive.split_once("/hour=")?,
+                    None => label.split_once('T')?,
+                };
+                let hour = parse_fixed(hour, 2)?;
+                if hour >= 24 {
+                    return None;
+                }
+                parse_date(date)?.checked_add(TimeDelta::from_hours(hour as i64))?
+            }
+            PartitionScheme::Daily => parse_date(label.strip_prefix("dt=").unwrap_or(label))?,
+            PartitionScheme::Monthly => {
+                let label = label.strip_prefix("dt=").unwrap_or(label);
+                let (year, month) = label.split_once('-')?;
+                return month_interval(parse_fixed(year, 4)? as i64, parse_fixed(month, 2)?);
+            }
+            PartitionScheme::Custom(_) => {
+                let label = label.strip_prefix("ts=").unwrap_or(label);
+                let (date, time) = label.split_once('T')?;
+                let (hms, nanos) = match time.split_once('.') {
+                    Some((hms, nanos)) => (hms, parse_fixed(nanos, 9)?),
+                    None => (time, 0),
+                };
+                let mut parts = hms.splitn(3, ':').map(|part| parse_fixed(part, 2));
+                let (h, m, s) = (parts.next()??, parts.next()??, parts.next()??);
+                let time = TimeOfDay::from_hms_nano(h, m, s, nanos)?;
+                parse_date(date)?.with_time_of_day(time)
+            }
+        };
+
+        let interval = self.interval(start);
+        (interval.start() == start).then_some(interval)
+    }
+
+    fn fixed(ts: Timestamp, freq: TimeDelta) -> Interval {
+        assert!(freq.0 > 0, "partition length must be positive");
+        let start = Timestamp(ts.0 - ts.0 % freq.0 as u64);
+        Interval::new(start, start.saturating_add(freq))
+    }
+}
+
+/// The interval covering a calendar month, `None` if the month doesn't start within the
+/// representable range. The last month ends at [`Timestamp::MAX`].
+fn month_interval(year: i64, month: u32) -> Option<Interval> {
+    if !(1..=12).contains(&month) {
+        return None;
+    }
+    let first_day = days_from_civil(year, month, 1);
+    let start = Timestamp::from_day_and_nanos(first_day, 0)?;
+    let end = Timestamp::from_day_and_nanos(first_day + days_in_month(year, month) as i64, 0)
+        .unwrap_or(Timestamp::MAX);
+    Some(Interval::new(start, end))
+}
+
+/// Parse `YYYY-MM-DD` to midnight of that day.
+fn parse_date(s: &str) -> Option<Timestamp> {
+    let mut parts = s.splitn(3, '-');
+    let year = parse_fixed(parts.next()?, 4)? as i64;
+    let month = parse_fixed(parts.next()?, 2)?;
+    let day = parse_fixed(parts.next()?, 2)?;
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+    Timestamp::from_day_and_nanos(days_from_civil(year, month, day), 0)
+}
+
+/// Parse exactly `digits` ASCII digits.
+fn parse_fixed(s: &str, digits: usize) -> Option<u32> {
+    if s.len() != digits || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn ts(y: i64, mo: u32, d: u32, h: u64, mi: u64, s: u64) -> Timestamp {
+        let days = super::days_from_civil(y, mo, d) as u64;
+        Timestamp::from_seconds(days * 86_400 + h * 3_600 + mi * 60 + s)
+    }
+
+    #[test]
+    fn partition_labels() {
+        let at = ts(2024, 5, 1, 13, 47, 12);
+        let custom = PartitionScheme::Custom(TimeDelta::from_minutes(15));
+
+        assert_eq!(PartitionScheme::Hourly.label(at), "2024-05-01T13");
+        assert_eq!(PartitionScheme::Hourly.hive_label(at), "dt=2024-05-01/hour=13");
+        assert_eq!(PartitionScheme::Daily.label(at), "2024-05-01");
+        assert_eq!(PartitionScheme::Daily.hive_label(at), "dt=2024-05-01");
+        assert_eq!(PartitionScheme::Monthly.label(at), "2024-05");
+        assert_eq!(PartitionScheme::Monthly.hive_label(at), "dt=2024-05");
+        assert_eq!(custom.label(at), "2024-05-01T13:45:00");
+        assert_eq!(custom.hive_label(at), "ts=2024-05-01T13:45:00");
+        assert_eq!(
+            PartitionScheme::Custom(TimeDelta::from_milliseconds(250)).label(at + TimeDelta::from_milliseconds(600)),
+            "2024-05-01T13:47:12.500000000",
+        );
+    }
+
+    #[test]
+    fn partition_labels_parse_back() {
+        let schemes = [
+            PartitionScheme::Hourly,
+            PartitionScheme::Daily,
+            PartitionScheme::Monthly,
+            PartitionScheme::Custom(TimeDelta::from_minutes(15)),
+            PartitionScheme::Custom(TimeDelta::from_milliseconds(250)),
+        ];
+        for scheme in schemes {
+            for at in [ts(2024, 2, 29, 23, 59, 59), ts(1970, 1, 1, 0, 0, 0), ts(2023, 12, 31, 0, 7, 0)] {
+                let interval = scheme.interval(at);
+                assert!(interval.contains(at));
+                assert_eq!(scheme.parse_label(&scheme.label(at)), Some(interval), "{:?}", scheme);
+                assert_eq!(scheme.parse_label(&scheme.hive_label(at)), Some(interval), "{:?}", scheme);
+            }
+        }
+
+        assert_eq!(
+            PartitionScheme::Monthly.parse_label("2024-02"),
+            Some(Interval::new(ts(2024, 2, 1, 0, 0, 0), ts(2024, 3, 1, 0, 0, 0))),
+        );
+        assert_eq!(PartitionScheme::Daily.parse_label("2023-02-29"), None);
+        assert_eq!(PartitionScheme::Hourly.parse_label("dt=2024-05-01/hour=24"), None);
+        assert_eq!(PartitionScheme::Custom(TimeDelta::from_minutes(15)).parse_label("2024-05-01T13:47:00"), None);
+    }
+
+    #[test]
+    fn last_partitions_end_at_max() {
+        let schemes = [
+            PartitionScheme::Hourly,
+            PartitionScheme::Daily,
+            PartitionScheme::Monthly,
+            PartitionScheme::Custom(TimeDelta::from_days(7)),
+        ];
+        for scheme in schemes {
+            let interval = scheme.interval(Timestamp::MAX);
+            assert_eq!(interval.end(), Timestamp::MAX, "{:?}", scheme);
+            assert!(interval.contains(Timestamp::from_nanoseconds(u64::MAX - 1)), "{:?}", scheme);
+            assert_eq!(scheme.parse_label(&scheme.label(Timestamp::MAX)), Some(interval), "{:?}", scheme);
+        }
+        assert_eq!(PartitionScheme::Monthly.label(Timestamp::MAX), "2554-07");
+    }
+
+    #[test]
+    #[should_panic(expected = "partition length must be positive")]
+    fn custom_partitions_must_have_a_length() {
+        PartitionScheme::Custom(TimeDelta::zero()).interval(Timestamp::zero());
+    }
+}
@@ -0,0 +1,118 @@
+//! Conversions between timestamps or deltas and Polars `Datetime` and `Duration` series.
+//!
+//! The conversions are associated functions rather than `From`/`TryFrom` impls, which the orphan
+//! rule forbids between `Vec<Timestamp>` and `Series` as both `Vec` and `Series` are foreign.
+
+use polars_core::prelude::{Int64Chunked, IntoSeries, PlSmallStr, Series, TimeUnit, TimeZone};
+
+use crate::{TimeDelta, TimeError, Timestamp};
+
+/// The factor from a Polars time unit to nanoseconds.
+#[inline]
+fn nanos_per_unit(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
+    }
+}
+
+/// The values of a physical column in nanoseconds, rejecting nulls and values that overflow when
+/// scaled from coarser units.
+fn nanos(physical: &Int64Chunked, unit: TimeUnit) -> Result<Vec<i64>, TimeError> {
+    let factor = nanos_per_unit(unit);
+    physical
+        .iter()
+        .map(|value| value.ok_or(TimeError::InvalidFormat)?.checked_mul(factor).ok_or(TimeError::Overflow))
+        .collect()
+}
+
+impl Timestamp {
+    /// Build a `Datetime[ns, UTC]` series without nulls.
+    ///
+    /// Fails with [`TimeError::Overflow`] for timestamps past `2262-04-11`, the end of Polars'
+    /// `i64` range.
+    pub fn to_series(name: impl Into<PlSmallStr>, timestamps: &[Timestamp]) -> Result<Series, TimeError> {
+        let nanos: Vec<i64> = timestamps
+            .iter()
+            .map(|ts| i64::try_from(ts.0).map_err(|_| TimeError::Overflow))
+            .collect::<Result<_, _>>()?;
+        let physical = Int64Chunked::from_vec(name.into(), nanos);
+        Ok(physical.into_datetime(TimeUnit::Nanoseconds, Some(TimeZone::UTC)).into_series())
+    }
+
+    /// Extract the values of a `Datetime` series of any time unit, reading series in any time zone
+    /// or none at all as UTC.
+    ///
+    /// Fails with [`TimeError::InvalidFormat`] for other data types or if the series contains
+    /// nulls, and with [`TimeError::BeforeEpoch`] for negative values.
+    pub fn from_series(series: &Series) -> Result<Vec<Timestamp>, TimeError> {
+        let datetime = series.datetime().map_err(|_| TimeError::InvalidFormat)?;
+        let nanos = nanos(datetime.physical(), datetime.time_unit())?;
+        let timestamps = nanos.into_iter().map(|n| u64::try_from(n).map(Timestamp));
+        timestamps.map(|ts| ts.map_err(|_| TimeError::BeforeEpoch)).collect()
+    }
+}
+
+impl TimeDelta {
+    /// Build a `Duration[ns]` series without nulls.
+    pub fn to_series(name: impl Into<PlSmallStr>, deltas: &[TimeDelta]) -> Series {
+        let nanos = deltas.iter().map(|delta| delta.0).collect();
+        Int64Chunked::from_vec(name.into(), nanos).into_duration(TimeUnit::Nanoseconds).into_series()
+    }
+
+    /// Extract the values of a `Duration` series of any time unit.
+    ///
+    /// Fails with [`TimeError::InvalidFormat`] for other data types or if the series contains
+    /// nulls, and with [`TimeError::Overflow`] if a coarser unit does not fit in nanoseconds.
+    pub fn from_series(series: &Series) -> Result<Vec<TimeDelta>, TimeError> {
+        let duration = series.duration().map_err(|_| TimeError::InvalidFormat)?;
+        let nanos = nanos(duration.physical(), duration.time_unit())?;
+        Ok(nanos.into_iter().map(TimeDelta).collect())
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use polars_core::prelude::{DataType, Int64Chunked, IntoSeries, NewChunkedArray, TimeUnit, TimeZone};
+
+    #[test]
+    fn polars_round_trip() {
+        let timestamps: Vec<_> = (0..100).map(|i| Timestamp::from_seconds(1_714_570_200 + i)).collect();
+        let series = Timestamp::to_series("at", &timestamps).unwrap();
+        assert_eq!(series.len(), 100);
+        assert_eq!(series.dtype(), &DataType::Datetime(TimeUnit::Nanoseconds, Some(TimeZone::UTC)));
+        assert_eq!(Timestamp::from_series(&series), Ok(timestamps));
+
+        let deltas = [TimeDelta::from_milliseconds(-1_500), TimeDelta::from_seconds(90)];
+        let series = TimeDelta::to_series("took", &deltas);
+        assert_eq!(series.dtype(), &DataType::Duration(TimeUnit::Nanoseconds));
+        assert_eq!(TimeDelta::from_series(&series), Ok(deltas.to_vec()));
+
+        let millis = Int64Chunked::from_vec("at".into(), vec![1_714_570_200_000]);
+        let series = millis.into_datetime(TimeUnit::Milliseconds, None).into_series();
+        assert_eq!(Timestamp::from_series(&series), Ok(vec![Timestamp::from_seconds(1_714_570_200)]));
+    }
+
+    #[test]
+    fn polars_out_of_range() {
+        assert_eq!(Timestamp::to_series("at", &[Timestamp::MAX]).err(), Some(TimeError::Overflow));
+
+        let series = Int64Chunked::from_vec("at".into(), vec![1, -1]).into_series();
+        assert_eq!(Timestamp::from_series(&series), Err(TimeError::InvalidFormat));
+        let series = series.into_datetime(TimeUnit::Nanoseconds, None);
+        assert_eq!(Timestamp::from_series(&series), Err(TimeError::BeforeEpoch));
+
+        let nulls = Int64Chunked::from_slice_options("took".into(), &[Some(1), None]);
+        let series = nulls.into_duration(TimeUnit::Nanoseconds).into_series();
+        assert_eq!(TimeDelta::from_series(&series), Err(TimeError::InvalidFormat));
+        let micros = Int64Chunked::from_vec("took".into(), vec![i64::MAX]);
+        let series = micros.into_duration(TimeUnit::Microseconds).into_series();
+        assert_eq!(TimeDelta::from_series(&series), Err(TimeError::Overflow));
+    }
+}
@@ -0,0 +1,158 @@
+//! Formatting many timestamps into one shared buffer.
+
+use core::ops::Range;
+
+use crate::{FormatSpec, Timestamp};
+
+// ============================================================================================== //
+// [TimestampFormatterPool]                                                                       //
+// ============================================================================================== //
+
+/// Formats timestamps back to back into a single string arena, remembering where each one ends.
+///
+/// Meant for report generators rendering millions of timestamps: instead of allocating a
+/// `String` per value, all output shares one growing buffer that can be cleared and reused,
+/// or handed over to the caller together with the offsets. Timestamps are rendered as RFC 3339
+/// with nanosecond precision unless a [`FormatSpec`] is given.
+///
+/// ```
+/// use fast_utc::{FormatSpec, Timestamp, TimestampFormatterPool};
+///
+/// let spec = FormatSpec::new("%H:%M:%S").unwrap();
+/// let mut pool = TimestampFormatterPool::with_spec(spec);
+/// pool.extend((0..3).map(|i| Timestamp::from_seconds(i * 3_661)));
+///
+/// assert_eq!(pool.get(1), Some("01:01:01"));
+/// assert_eq!(pool.as_str(), "00:00:0001:01:0102:02:02");
+/// assert_eq!(pool.range(2), Some(16..24));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TimestampFormatterPool {
+    spec: Option<FormatSpec>,
+    arena: String,
+    /// End offset of each formatted timestamp within `arena`.
+    ends: Vec<usize>,
+}
+
+impl TimestampFormatterPool {
+    /// Create an empty pool formatting as RFC 3339.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty pool formatting with `spec`.
+    pub fn with_spec(spec: FormatSpec) -> Self {
+        TimestampFormatterPool { spec: Some(spec), ..Self::default() }
+    }
+
+    /// Create a pool formatting as RFC 3339 into caller provided buffers, which are cleared
+    /// first. Their capacity is kept, so buffers from [`into_buffers`](Self::into_buffers) can
+    /// be recycled.
+    pub fn from_buffers(mut arena: String, mut ends: Vec<usize>) -> Self {
+        arena.clear();
+        ends.clear();
+        TimestampFormatterPool { spec: None, arena, ends }
+    }
+
+    /// Format `ts` at the end of the arena, returning its index.
+    pub fn push(&mut self, ts: Timestamp) -> usize {
+        match &self.spec {
+            Some(spec) => spec.write(ts, &mut self.arena).expect("writing to a String doesn't fail"),
+            None => {
+                let mut buf = [0; Timestamp::RFC3339_LEN];
+                self.arena.push_str(ts.format_rfc3339(&mut buf));
+            }
+        }
+        self.ends.push(self.arena.len());
+        self.ends.len() - 1
+    }
+
+    /// Formatted timestamp at `index`.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.range(index).map(|range| &self.arena[range])
+    }
+
+    /// Byte range of the timestamp at `index` within [`as_str`](Self::as_str).
+    pub fn range(&self, index: usize) -> Option<Range<usize>> {
+        let end = *self.ends.get(index)?;
+        let start = if index == 0 { 0 } else { self.ends[index - 1] };
+        Some(start..end)
+    }
+
+    /// Formatted timestamps in insertion order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &str> + '_ {
+        (0..self.ends.len()).map(|index| self.get(index).expect("index is in bounds"))
+    }
+
+    /// All formatted timestamps, back to back.
+    pub fn as_str(&self) -> &str {
+        &self.arena
+    }
+
+    /// End offsets of the formatted timestamps within [`as_str`](Self::as_str).
+    pub fn ends(&self) -> &[usize] {
+        &self.ends
+    }
+
+    /// Number of formatted timestamps.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Check whether nothing was formatted yet.
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Drop all formatted timestamps, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        self.arena.clear();
+        self.ends.clear();
+    }
+
+    /// Take the arena and the end offsets.
+    pub fn into_buffers(self) -> (String, Vec<usize>) {
+        (self.arena, self.ends)
+    }
+}
+
+impl Extend<Timestamp> for TimestampFormatterPool {
+    fn extend<I: IntoIterator<Item = Timestamp>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.ends.reserve(iter.size_hint().0);
+        for ts in iter {
+            self.push(ts);
+        }
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn pool_reuses_buffers() {
+        let start = Timestamp::from_seconds(1_714_570_200);
+        let timestamps: Vec<_> = (0..100).map(|i| start + TimeDelta::from_milliseconds(i)).collect();
+        let mut pool = TimestampFormatterPool::new();
+        pool.extend(timestamps.iter().copied());
+
+        assert_eq!(pool.len(), 100);
+        assert_eq!(pool.get(42), Some("2024-05-01T13:30:00.042000000Z"));
+        assert_eq!(pool.get(100), None);
+        assert!(pool.iter().zip(&timestamps).all(|(s, ts)| s == ts.to_rfc3339()));
+        assert_eq!(pool.ends().last(), Some(&(100 * Timestamp::RFC3339_LEN)));
+
+        let (arena, ends) = pool.into_buffers();
+        let capacity = arena.capacity();
+        let mut pool = TimestampFormatterPool::from_buffers(arena, ends);
+        assert!(pool.is_empty());
+        assert_eq!(pool.push(Timestamp::zero()), 0);
+        assert_eq!(pool.as_str(), "1970-01-01T00:00:00.000000000Z");
+        assert_eq!(pool.into_buffers().0.capacity(), capacity);
+    }
+}
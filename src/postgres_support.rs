@@ -0,0 +1,140 @@
+//! tokio-postgres column conversions through `postgres-types`, using the binary wire format.
+//!
+//! Postgres counts microseconds from `2000-01-01`, so nanoseconds are truncated when writing.
+
+use std::error::Error;
+
+use bytes::{BufMut, BytesMut};
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::{TimeDelta, TimeError, Timestamp};
+
+/// Microseconds from the Unix epoch to the Postgres epoch, `2000-01-01`.
+const PG_EPOCH_MICROS: i64 = 946_684_800_000_000;
+
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+type BoxError = Box<dyn Error + Sync + Send>;
+
+/// Read a big endian `i64` from the start of `raw`.
+fn read_i64(raw: &[u8]) -> Result<i64, BoxError> {
+    let bytes = raw.get(..8).ok_or(TimeError::InvalidFormat)?;
+    Ok(i64::from_be_bytes(bytes.try_into().expect("slice is 8 bytes long")))
+}
+
+// ============================================================================================== //
+// [Timestamp]                                                                                    //
+// ============================================================================================== //
+
+/// A `timestamptz` value. Fails with [`TimeError::BeforeEpoch`] or [`TimeError::Overflow`] for
+/// times outside the range of [`Timestamp`], including `-infinity` and `infinity`.
+impl<'a> FromSql<'a> for Timestamp {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        let micros = read_i64(raw)?.checked_add(PG_EPOCH_MICROS).ok_or(TimeError::Overflow)?;
+        let micros = u64::try_from(micros).map_err(|_| TimeError::BeforeEpoch)?;
+        Ok(Timestamp::try_from_microseconds(micros)?)
+    }
+
+    accepts!(TIMESTAMPTZ);
+}
+
+/// A `timestamptz` value, truncated to microseconds.
+impl ToSql for Timestamp {
+    fn to_sql(&self, _ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        out.put_i64((self.0 / 1_000) as i64 - PG_EPOCH_MICROS);
+        Ok(IsNull::No)
+    }
+
+    accepts!(TIMESTAMPTZ);
+    to_sql_checked!();
+}
+
+// ============================================================================================== //
+// [TimeDelta]                                                                                    //
+// ============================================================================================== //
+
+/// An `interval` value, with days counting as 24 hours. Fails with
+/// [`TimeError::InvalidFormat`] for intervals with months, which have no fixed length, and
+/// with [`TimeError::Overflow`] for intervals longer than about 292 years.
+impl<'a> FromSql<'a> for TimeDelta {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        if raw.len() != 16 {
+            return Err(TimeError::InvalidFormat.into());
+        }
+        let micros = read_i64(raw)?;
+        let days = i32::from_be_bytes(raw[8..12].try_into().expect("slice is 4 bytes long"));
+        let months = i32::from_be_bytes(raw[12..16].try_into().expect("slice is 4 bytes long"));
+        if months != 0 {
+            return Err(TimeError::InvalidFormat.into());
+        }
+
+        let nanos = (days as i64)
+            .checked_mul(MICROS_PER_DAY)
+            .and_then(|days| days.checked_add(micros))
+            .and_then(|micros| micros.checked_mul(1_000))
+            .ok_or(TimeError::Overflow)?;
+        Ok(TimeDelta(nanos))
+    }
+
+    accepts!(INTERVAL);
+}
+
+/// An `interval` of microseconds only, truncated towards zero. Postgres shows it in hours,
+/// e.g. `36:00:00` rather than `1 day 12:00:00`.
+impl ToSql for TimeDelta {
+    fn to_sql(&self, _ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        out.put_i64(self.0 / 1_000);
+        out.put_i32(0);
+        out.put_i32(0);
+        Ok(IsNull::No)
+    }
+
+    accepts!(INTERVAL);
+    to_sql_checked!();
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, ToSql, Type};
+
+    fn interval(micros: i64, days: i32, months: i32) -> Vec<u8> {
+        [&micros.to_be_bytes()[..], &days.to_be_bytes(), &months.to_be_bytes()].concat()
+    }
+
+    #[test]
+    fn postgres_timestamptz() {
+        let ts = Timestamp::from_nanoseconds(1_714_570_200_123_456_789);
+        let mut out = BytesMut::new();
+        ts.to_sql(&Type::TIMESTAMPTZ, &mut out).unwrap();
+        assert_eq!(out[..], 767_885_400_123_456i64.to_be_bytes());
+        let read = Timestamp::from_sql(&Type::TIMESTAMPTZ, &out).unwrap();
+        assert_eq!(read, Timestamp::from_microseconds(1_714_570_200_123_456));
+
+        assert!(ts.to_sql_checked(&Type::TIMESTAMP, &mut out).is_err());
+        let from_sql = |raw: &[u8]| Timestamp::from_sql(&Type::TIMESTAMPTZ, raw).ok();
+        assert_eq!(from_sql(&(-super::PG_EPOCH_MICROS).to_be_bytes()), Some(Timestamp::zero()));
+        assert_eq!(from_sql(&(-super::PG_EPOCH_MICROS - 1).to_be_bytes()), None);
+        assert_eq!(from_sql(&i64::MAX.to_be_bytes()), None);
+        assert_eq!(from_sql(&[0; 4]), None);
+    }
+
+    #[test]
+    fn postgres_interval() {
+        let delta = TimeDelta::from_hours(-36) - TimeDelta::from_nanoseconds(1_999);
+        let mut out = BytesMut::new();
+        delta.to_sql(&Type::INTERVAL, &mut out).unwrap();
+        assert_eq!(out[..], interval(-129_600_000_001, 0, 0));
+
+        let from_sql = |raw: &[u8]| TimeDelta::from_sql(&Type::INTERVAL, raw).ok();
+        assert_eq!(from_sql(&out), Some(TimeDelta::from_hours(-36) - TimeDelta::MICROSECOND));
+        assert_eq!(from_sql(&interval(1_000_000, 2, 0)), Some(TimeDelta::from_days(2) + TimeDelta::SECOND));
+        assert_eq!(from_sql(&interval(0, 0, 1)), None);
+        assert_eq!(from_sql(&interval(0, 200_000, 0)), None);
+    }
+}
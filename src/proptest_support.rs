@@ -0,0 +1,105 @@
+//! proptest strategies generating timestamps, deltas and time ranges.
+
+use core::ops::RangeInclusive;
+
+use proptest::arbitrary::Arbitrary;
+use proptest::strategy::{BoxedStrategy, Map, Strategy};
+
+use crate::{TimeDelta, TimeRange, Timestamp};
+
+/// The longest step of a generated [`TimeRange`], one day.
+const MAX_RANGE_STEP: u64 = 86_400_000_000_000;
+
+/// The most steps of a generated [`TimeRange`], keeping iteration over it cheap.
+const MAX_RANGE_STEPS: u64 = 64;
+
+/// Timestamps up to `2262-04-11`, the ones converting to `chrono::DateTime` and back, see
+/// [`Timestamp::is_representable_as_chrono`].
+///
+/// Use [`Timestamp::arbitrary_in`] to cover other ranges.
+impl Arbitrary for Timestamp {
+    type Parameters = ();
+    type Strategy = Map<RangeInclusive<u64>, fn(u64) -> Timestamp>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        Timestamp::arbitrary_in(Timestamp::MIN..=Timestamp::MAX_CHRONO)
+    }
+}
+
+/// Deltas of either sign up to `i64::MAX` nanoseconds, leaving out `i64::MIN` so that negating
+/// a generated delta or taking its absolute value never overflows.
+///
+/// Use [`TimeDelta::arbitrary_in`] to cover other ranges.
+impl Arbitrary for TimeDelta {
+    type Parameters = ();
+    type Strategy = Map<RangeInclusive<i64>, fn(i64) -> TimeDelta>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        TimeDelta::arbitrary_in(TimeDelta(-i64::MAX)..=TimeDelta(i64::MAX))
+    }
+}
+
+/// Right open or right closed ranges of up to 64 steps of at most a day, ending before
+/// `2262-04-11` like arbitrary timestamps do.
+impl Arbitrary for TimeRange {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<TimeRange>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        let latest_start = Timestamp::MAX_CHRONO.0 - MAX_RANGE_STEP * MAX_RANGE_STEPS;
+        (0..=latest_start, 1..=MAX_RANGE_STEP, 0..=MAX_RANGE_STEPS, proptest::bool::ANY)
+            .prop_map(|(start, step, steps, right_closed)| TimeRange {
+                cur: Timestamp(start),
+                end: Timestamp(start + step * steps),
+                step: TimeDelta(step as i64),
+                right_closed,
+            })
+            .boxed()
+    }
+}
+
+impl Timestamp {
+    /// A strategy generating timestamps within `range`, e.g. from the epoch to the end of 2099.
+    pub fn arbitrary_in(range: RangeInclusive<Timestamp>) -> Map<RangeInclusive<u64>, fn(u64) -> Timestamp> {
+        (range.start().0..=range.end().0).prop_map(Timestamp as fn(u64) -> Timestamp)
+    }
+}
+
+impl TimeDelta {
+    /// A strategy generating deltas within `range`, e.g. only non-negative ones.
+    pub fn arbitrary_in(range: RangeInclusive<TimeDelta>) -> Map<RangeInclusive<i64>, fn(i64) -> TimeDelta> {
+        (range.start().0..=range.end().0).prop_map(TimeDelta as fn(i64) -> TimeDelta)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn arbitrary_round_trips_chrono(ts: Timestamp, delta: TimeDelta) {
+            prop_assert_eq!(ts.try_into_chrono().map(Timestamp::from), Ok(ts));
+            prop_assert_eq!(-(-delta), delta);
+            prop_assert!(delta.abs() >= TimeDelta::zero());
+        }
+
+        #[test]
+        fn arbitrary_ranges_are_bounded(range: TimeRange) {
+            let timestamps: Vec<_> = range.collect();
+            prop_assert!(timestamps.len() <= 65);
+            prop_assert!(timestamps.windows(2).all(|w| w[0] < w[1]));
+            prop_assert!(timestamps.iter().all(|ts| ts.is_representable_as_chrono()));
+        }
+
+        #[test]
+        fn arbitrary_in_range(ts in Timestamp::arbitrary_in(Timestamp::MIN..=Timestamp::from_seconds(60))) {
+            prop_assert!(ts <= Timestamp::from_seconds(60));
+        }
+    }
+}
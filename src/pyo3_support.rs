@@ -0,0 +1,143 @@
+//! Conversions between timestamps or deltas and Python's `datetime` objects.
+
+use pyo3::exceptions::{PyOverflowError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDateTime, PyDelta, PyInt, PyTzInfo, PyTzInfoAccess};
+
+use crate::{TimeDelta, TimeError, Timestamp};
+
+/// Raise [`TimeError::Overflow`] as `OverflowError` and any other error as `ValueError`.
+impl From<TimeError> for PyErr {
+    fn from(err: TimeError) -> Self {
+        match err {
+            TimeError::Overflow => PyOverflowError::new_err(err.to_string()),
+            _ => PyValueError::new_err(err.to_string()),
+        }
+    }
+}
+
+/// Convert to an aware `datetime.datetime` in `datetime.timezone.utc`, truncating to the
+/// microseconds Python can represent.
+impl<'py> IntoPyObject<'py> for Timestamp {
+    type Target = PyDateTime;
+    type Output = Bound<'py, PyDateTime>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        self.try_into_chrono()?.into_pyobject(py)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &Timestamp {
+    type Target = PyDateTime;
+    type Output = Bound<'py, PyDateTime>;
+    type Error = PyErr;
+
+    #[inline]
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        (*self).into_pyobject(py)
+    }
+}
+
+/// Extract from an `int` of nanoseconds since the epoch or an aware `datetime.datetime` in any
+/// time zone.
+///
+/// Naive date times are rejected with a `TypeError` rather than guessing their time zone.
+impl FromPyObject<'_, '_> for Timestamp {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
+        if obj.is_instance_of::<PyInt>() {
+            return obj.extract::<u64>().map(Timestamp);
+        }
+        let dt = obj.cast::<PyDateTime>()?;
+        if dt.get_tzinfo().is_none() {
+            return Err(PyTypeError::new_err("expected a datetime with non-None tzinfo"));
+        }
+        let utc = dt.call_method1("astimezone", (PyTzInfo::utc(obj.py())?,))?;
+        Ok(Timestamp::try_from_chrono(utc.extract::<chrono::DateTime<chrono::Utc>>()?)?)
+    }
+}
+
+/// Convert to a `datetime.timedelta`, truncating to microseconds.
+impl<'py> IntoPyObject<'py> for TimeDelta {
+    type Target = PyDelta;
+    type Output = Bound<'py, PyDelta>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        chrono::Duration::from(self).into_pyobject(py)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &TimeDelta {
+    type Target = PyDelta;
+    type Output = Bound<'py, PyDelta>;
+    type Error = PyErr;
+
+    #[inline]
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        (*self).into_pyobject(py)
+    }
+}
+
+/// Extract from a `datetime.timedelta`, raising `OverflowError` past about 292 years.
+impl FromPyObject<'_, '_> for TimeDelta {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
+        let duration = obj.extract::<chrono::Duration>()?;
+        Ok(TimeDelta(duration.num_nanoseconds().ok_or(TimeError::Overflow)?))
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use pyo3::exceptions::{PyOverflowError, PyTypeError, PyValueError};
+    use pyo3::prelude::*;
+
+    #[test]
+    fn python_round_trip() {
+        Python::initialize();
+        Python::attach(|py| {
+            let ts = Timestamp::from_seconds(1_714_570_200) + TimeDelta::from_microseconds(123_456);
+            let dt = ts.into_pyobject(py).unwrap();
+            assert_eq!(dt.str().unwrap().to_str().unwrap(), "2024-05-01 13:30:00.123456+00:00");
+            assert_eq!(dt.extract::<Timestamp>().unwrap(), ts);
+
+            let datetime = py.import("datetime").unwrap();
+            let two_hours = datetime.getattr("timedelta").unwrap().call((0, 7_200), None).unwrap();
+            let plus_two = datetime.getattr("timezone").unwrap().call1((two_hours,)).unwrap();
+            let local = dt.call_method1("astimezone", (plus_two,)).unwrap();
+            assert_eq!(local.extract::<Timestamp>().unwrap(), ts);
+            let nanos = (ts.as_nanoseconds() + 789).into_pyobject(py).unwrap();
+            assert_eq!(nanos.extract::<Timestamp>().unwrap(), ts + TimeDelta::from_nanoseconds(789));
+
+            let delta = TimeDelta::from_milliseconds(-1_500);
+            let td = delta.into_pyobject(py).unwrap();
+            assert_eq!(td.str().unwrap().to_str().unwrap(), "-1 day, 23:59:58.500000");
+            assert_eq!(td.extract::<TimeDelta>().unwrap(), delta);
+        });
+    }
+
+    #[test]
+    fn python_rejections() {
+        Python::initialize();
+        Python::attach(|py| {
+            let datetime = py.import("datetime").unwrap();
+            let class = datetime.getattr("datetime").unwrap();
+            let naive = class.call1((2024, 5, 1)).unwrap();
+            assert!(naive.extract::<Timestamp>().unwrap_err().is_instance_of::<PyTypeError>(py));
+            let utc = datetime.getattr("timezone").unwrap().getattr("utc").unwrap();
+            let before = class.call1((1969, 12, 31, 0, 0, 0, 0, utc)).unwrap();
+            assert!(before.extract::<Timestamp>().unwrap_err().is_instance_of::<PyValueError>(py));
+            let huge = datetime.getattr("timedelta").unwrap().call1((200_000,)).unwrap();
+            assert!(huge.extract::<TimeDelta>().unwrap_err().is_instance_of::<PyOverflowError>(py));
+        });
+    }
+}
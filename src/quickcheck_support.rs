@@ -0,0 +1,52 @@
+//! quickcheck generators and shrinkers for timestamps and deltas.
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{TimeDelta, Timestamp};
+
+/// Timestamps up to `2262-04-11`, the ones converting to `chrono::DateTime` and back, shrinking
+/// towards the epoch.
+impl Arbitrary for Timestamp {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Timestamp(u64::arbitrary(g) % (Timestamp::MAX_CHRONO.0 + 1))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.0.shrink().map(Timestamp))
+    }
+}
+
+/// Deltas of either sign without `i64::MIN`, so that negating them never overflows, shrinking
+/// towards zero.
+impl Arbitrary for TimeDelta {
+    fn arbitrary(g: &mut Gen) -> Self {
+        TimeDelta(i64::arbitrary(g).max(-i64::MAX))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.0.shrink().map(TimeDelta))
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use quickcheck::{Arbitrary, Gen, quickcheck};
+
+    #[test]
+    fn quickcheck_round_trips_chrono() {
+        fn prop(ts: Timestamp, delta: TimeDelta) -> bool {
+            ts.try_into_chrono().map(Timestamp::from) == Ok(ts) && -(-delta) == delta
+        }
+        quickcheck(prop as fn(Timestamp, TimeDelta) -> bool);
+
+        let mut g = Gen::new(100);
+        assert!((0..1_000).all(|_| TimeDelta::arbitrary(&mut g) != TimeDelta(i64::MIN)));
+        let ts = Timestamp::from_seconds(1_714_570_200);
+        assert!(ts.shrink().all(|shrunk| shrunk < ts));
+    }
+}
@@ -0,0 +1,108 @@
+//! Uniform sampling of timestamps and deltas with rand.
+
+use rand::Rng;
+use rand::distr::uniform::{Error, SampleBorrow, SampleRange, SampleUniform, UniformInt, UniformSampler};
+
+use crate::{TimeDelta, Timestamp};
+
+/// Samples timestamps uniformly from a range, see [`Timestamp::random_in`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UniformTimestamp(UniformInt<u64>);
+
+impl SampleUniform for Timestamp {
+    type Sampler = UniformTimestamp;
+}
+
+impl UniformSampler for UniformTimestamp {
+    type X = Timestamp;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Timestamp> + Sized,
+        B2: SampleBorrow<Timestamp> + Sized,
+    {
+        UniformInt::new(low.borrow().0, high.borrow().0).map(UniformTimestamp)
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Timestamp> + Sized,
+        B2: SampleBorrow<Timestamp> + Sized,
+    {
+        UniformInt::new_inclusive(low.borrow().0, high.borrow().0).map(UniformTimestamp)
+    }
+
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Timestamp {
+        Timestamp(self.0.sample(rng))
+    }
+}
+
+/// Samples deltas uniformly from a range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UniformTimeDelta(UniformInt<i64>);
+
+impl SampleUniform for TimeDelta {
+    type Sampler = UniformTimeDelta;
+}
+
+impl UniformSampler for UniformTimeDelta {
+    type X = TimeDelta;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<TimeDelta> + Sized,
+        B2: SampleBorrow<TimeDelta> + Sized,
+    {
+        UniformInt::new(low.borrow().0, high.borrow().0).map(UniformTimeDelta)
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<TimeDelta> + Sized,
+        B2: SampleBorrow<TimeDelta> + Sized,
+    {
+        UniformInt::new_inclusive(low.borrow().0, high.borrow().0).map(UniformTimeDelta)
+    }
+
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TimeDelta {
+        TimeDelta(self.0.sample(rng))
+    }
+}
+
+impl Timestamp {
+    /// Pick a timestamp uniformly from `range`, e.g. to spread generated events over a day.
+    ///
+    /// Same as `rng.random_range(range)`, panics if the range is empty.
+    #[inline]
+    pub fn random_in<R: Rng + ?Sized>(range: impl SampleRange<Timestamp>, rng: &mut R) -> Timestamp {
+        rng.random_range(range)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn random_in_range() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let start = Timestamp::from_seconds(1_714_521_600);
+        let end = start + TimeDelta::from_days(1);
+        for _ in 0..1_000 {
+            let ts = Timestamp::random_in(start..end, &mut rng);
+            assert!(start <= ts && ts < end);
+            let delta = rng.random_range(TimeDelta::from_seconds(-5)..=TimeDelta::from_seconds(5));
+            assert!(delta.abs() <= TimeDelta::from_seconds(5));
+        }
+        assert_eq!(Timestamp::random_in(start..=start, &mut rng), start);
+        assert_eq!(rng.random_range(Timestamp::MAX..=Timestamp::MAX), Timestamp::MAX);
+    }
+}
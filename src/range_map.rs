@@ -0,0 +1,192 @@
+//! Values that are effective during non-overlapping intervals.
+
+use std::collections::BTreeMap;
+
+use crate::{Interval, Timestamp};
+
+// ============================================================================================== //
+// [TimestampRangeMap]                                                                            //
+// ============================================================================================== //
+
+/// How [`TimestampRangeMap::insert`] treats an interval overlapping existing ones.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OverlapPolicy {
+    /// Refuse the insertion, leaving the map unchanged.
+    #[default]
+    Reject,
+    /// Insert anyway, trimming or splitting the existing intervals around the new one.
+    Overwrite,
+}
+
+/// A map from non-overlapping [`Interval`]s to values, e.g. fee schedules or config versions
+/// that are effective from one point in time to another.
+///
+/// Intervals are right open like everywhere else: a value effective during `[a, b)` isn't
+/// returned for `b`, so back to back intervals never both match a timestamp.
+///
+/// ```
+/// use fast_utc::{Interval, OverlapPolicy, TimestampRangeMap, Timestamp};
+///
+/// let ts = Timestamp::from_seconds;
+/// let mut fees = TimestampRangeMap::with_policy(OverlapPolicy::Overwrite);
+/// fees.insert(Interval::new(ts(0), ts(100)), 0.25).unwrap();
+/// fees.insert(Interval::new(ts(50), ts(60)), 0.1).unwrap();
+///
+/// assert_eq!(fees.get(ts(55)), Some(&0.1));
+/// assert_eq!(fees.get(ts(60)), Some(&0.25));
+/// assert_eq!(fees.get(ts(100)), None);
+/// assert_eq!(fees.len(), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TimestampRangeMap<V> {
+    policy: OverlapPolicy,
+    /// `start -> (end, value)`, never empty or overlapping.
+    entries: BTreeMap<Timestamp, (Timestamp, V)>,
+}
+
+impl<V> TimestampRangeMap<V> {
+    /// Create an empty map rejecting overlapping insertions.
+    pub fn new() -> Self {
+        Self::with_policy(OverlapPolicy::Reject)
+    }
+
+    /// Create an empty map handling overlapping insertions as given.
+    pub fn with_policy(policy: OverlapPolicy) -> Self {
+        TimestampRangeMap { policy, entries: BTreeMap::new() }
+    }
+
+    /// How overlapping insertions are handled.
+    pub fn policy(&self) -> OverlapPolicy {
+        self.policy
+    }
+
+    /// Value effective at `ts`.
+    pub fn get(&self, ts: Timestamp) -> Option<&V> {
+        self.get_entry(ts).map(|(_, value)| value)
+    }
+
+    /// Value effective at `ts` and the interval it is effective during.
+    pub fn get_entry(&self, ts: Timestamp) -> Option<(Interval, &V)> {
+        let (&start, (end, value)) = self.entries.range(..=ts).next_back()?;
+        (ts < *end).then(|| (Interval::new(start, *end), value))
+    }
+
+    /// Remove the entry effective at `ts`, returning its interval and value.
+    pub fn remove(&mut self, ts: Timestamp) -> Option<(Interval, V)> {
+        let (entry, _) = self.get_entry(ts)?;
+        let (_, value) = self.entries.remove(&entry.start()).expect("entry exists");
+        Some((entry, value))
+    }
+
+    /// Entries in time order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Interval, &V)> + '_ {
+        self.entries.iter().map(|(&start, (end, value))| (Interval::new(start, *end), value))
+    }
+
+    /// Entries sharing at least one timestamp with `interval`, in time order.
+    pub fn overlapping(&self, interval: Interval) -> impl Iterator<Item = (Interval, &V)> + '_ {
+        // Only the last entry starting before the interval may reach into it.
+        let before = self.entries.range(..interval.start()).next_back();
+        let within = self.entries.range(interval.start()..interval.end());
+        before
+            .into_iter()
+            .chain(within)
+            .map(|(&start, (end, value))| (Interval::new(start, *end), value))
+            .filter(move |(entry, _)| entry.overlaps(interval))
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<V: Clone> TimestampRangeMap<V> {
+    /// Make `value` effective during `interval`, handling overlaps according to the policy.
+    ///
+    /// Fails and hands `value` back if the interval overlaps an existing one under
+    /// [`OverlapPolicy::Reject`]. Empty intervals are accepted but not stored.
+    pub fn insert(&mut self, interval: Interval, value: V) -> Result<(), V> {
+        if interval.is_empty() {
+            return Ok(());
+        }
+
+        let overlapping: Vec<_> = self.overlapping(interval).map(|(entry, _)| entry.start()).collect();
+        if !overlapping.is_empty() && self.policy == OverlapPolicy::Reject {
+            return Err(value);
+        }
+        for start in overlapping {
+            let (end, existing) = self.entries.remove(&start).expect("entry exists");
+            if start < interval.start() {
+                self.entries.insert(start, (interval.start(), existing.clone()));
+            }
+            if end > interval.end() {
+                self.entries.insert(interval.end(), (end, existing));
+            }
+        }
+        self.entries.insert(interval.start(), (interval.end(), value));
+        Ok(())
+    }
+}
+
+impl<V> Default for TimestampRangeMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn interval(start: u64, end: u64) -> Interval {
+        Interval::new(Timestamp::from_seconds(start), Timestamp::from_seconds(end))
+    }
+
+    #[test]
+    fn range_map_boundaries() {
+        let mut map = TimestampRangeMap::new();
+        assert_eq!(map.insert(interval(10, 20), "a"), Ok(()));
+        assert_eq!(map.insert(interval(20, 30), "b"), Ok(()));
+        assert_eq!(map.insert(interval(19, 21), "c"), Err("c"));
+        assert_eq!(map.insert(interval(0, 10), "d"), Ok(()));
+        assert_eq!(map.insert(interval(5, 5), "e"), Ok(()));
+
+        let ts = Timestamp::from_seconds;
+        assert_eq!(map.get(ts(9)), Some(&"d"));
+        assert_eq!(map.get(ts(10)), Some(&"a"));
+        assert_eq!(map.get_entry(ts(20)), Some((interval(20, 30), &"b")));
+        assert_eq!(map.get(ts(30)), None);
+        assert_eq!(map.iter().map(|(_, v)| *v).collect::<Vec<_>>(), ["d", "a", "b"]);
+        assert_eq!(map.overlapping(interval(15, 21)).map(|(_, v)| *v).collect::<Vec<_>>(), ["a", "b"]);
+
+        assert_eq!(map.remove(ts(15)), Some((interval(10, 20), "a")));
+        assert_eq!(map.get(ts(15)), None);
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn range_map_overwrite() {
+        let mut map = TimestampRangeMap::with_policy(OverlapPolicy::Overwrite);
+        map.insert(interval(0, 10), 1).unwrap();
+        map.insert(interval(10, 20), 2).unwrap();
+        map.insert(interval(20, 30), 3).unwrap();
+        // Trims the first and the third, replaces the second.
+        map.insert(interval(5, 25), 4).unwrap();
+        // Splits the new entry.
+        map.insert(interval(12, 13), 5).unwrap();
+
+        let entries: Vec<_> = map.iter().map(|(i, &v)| (i, v)).collect();
+        let expected = [(0, 5, 1), (5, 12, 4), (12, 13, 5), (13, 25, 4), (25, 30, 3)];
+        assert_eq!(entries, expected.map(|(start, end, v)| (interval(start, end), v)));
+    }
+}
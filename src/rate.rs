@@ -0,0 +1,140 @@
+//! Rate of change computations over timestamped samples.
+
+use std::collections::VecDeque;
+
+use crate::{TimeDelta, Timestamp};
+
+// ============================================================================================== //
+// [Rates]                                                                                        //
+// ============================================================================================== //
+
+/// Per-second rate of change between two samples.
+///
+/// Order doesn't matter. If both samples share the same timestamp, the rate is NaN for equal
+/// values and infinite otherwise, signed like the change from `a` to `b`.
+pub fn rate_between(a: (Timestamp, f64), b: (Timestamp, f64)) -> f64 {
+    let secs = (b.0 - a.0).as_nanoseconds() as f64 / 1e9;
+    (b.1 - a.1) / secs
+}
+
+/// Tracks the per-second rate of a series over a trailing time window.
+///
+/// Samples may be spaced irregularly: the rate is always taken between the oldest and the
+/// newest sample within the window. Trackers created with [`counter`](Self::counter) treat
+/// any decrease as a counter reset and continue from the new value, like Prometheus' `rate()`.
+///
+/// ```
+/// use fast_utc::{RateTracker, TimeDelta, Timestamp};
+///
+/// let mut tracker = RateTracker::counter(TimeDelta::from_minutes(1));
+/// tracker.push(Timestamp::from_seconds(0), 100.0);
+/// tracker.push(Timestamp::from_seconds(10), 150.0);
+/// tracker.push(Timestamp::from_seconds(20), 30.0); // Process restarted.
+/// assert_eq!(tracker.rate(), Some(4.0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RateTracker {
+    window: TimeDelta,
+    resets: bool,
+    /// Samples within the window, values adjusted for counter resets.
+    samples: VecDeque<(Timestamp, f64)>,
+    /// Sum of the values lost to counter resets so far.
+    reset_offset: f64,
+    last_raw: Option<f64>,
+}
+
+impl RateTracker {
+    /// Track a monotonic counter, compensating for resets.
+    pub fn counter(window: TimeDelta) -> Self {
+        Self::new(window, true)
+    }
+
+    /// Track a gauge, where decreases are regular changes.
+    pub fn gauge(window: TimeDelta) -> Self {
+        Self::new(window, false)
+    }
+
+    fn new(window: TimeDelta, resets: bool) -> Self {
+        RateTracker { window, resets, samples: VecDeque::new(), reset_offset: 0.0, last_raw: None }
+    }
+
+    /// Add a sample. Samples older than the newest one seen so far are ignored.
+    pub fn push(&mut self, at: Timestamp, value: f64) {
+        if self.samples.back().is_some_and(|&(newest, _)| at < newest) {
+            return;
+        }
+
+        if self.resets && self.last_raw.is_some_and(|last| value < last) {
+            self.reset_offset += self.last_raw.unwrap_or(0.0);
+        }
+        self.last_raw = Some(value);
+        self.samples.push_back((at, value + self.reset_offset));
+
+        let horizon = at - self.window;
+        while self.samples.front().is_some_and(|&(oldest, _)| oldest < horizon) {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Per-second rate over the window, `None` until two distinct points in time were seen.
+    pub fn rate(&self) -> Option<f64> {
+        let (&first, &last) = (self.samples.front()?, self.samples.back()?);
+        if first.0 == last.0 {
+            return None;
+        }
+        Some(rate_between(first, last))
+    }
+
+    /// Number of samples currently within the window.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Check whether no samples are within the window.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn rate_between_irregular_samples() {
+        let a = (Timestamp::from_seconds(10), 5.0);
+        let b = (Timestamp::from_milliseconds(12_500), 10.0);
+
+        assert_eq!(rate_between(a, b), 2.0);
+        assert_eq!(rate_between(b, a), 2.0);
+        assert!(rate_between(a, a).is_nan());
+        assert_eq!(rate_between(a, (a.0, 6.0)), f64::INFINITY);
+        assert_eq!(rate_between(a, (a.0, 4.0)), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn rate_tracker_window() {
+        let mut counter = RateTracker::counter(TimeDelta::from_seconds(10));
+        assert_eq!(counter.rate(), None);
+
+        for secs in 0..=30 {
+            counter.push(Timestamp::from_seconds(secs), (secs * 3) as f64);
+        }
+        assert_eq!(counter.len(), 11);
+        assert_eq!(counter.rate(), Some(3.0));
+
+        // Restart at 31s: the counter drops to 1 but the rate stays continuous.
+        counter.push(Timestamp::from_seconds(31), 1.0);
+        assert_eq!(counter.rate(), Some((90.0 + 1.0 - 63.0) / 10.0));
+
+        let mut gauge = RateTracker::gauge(TimeDelta::from_seconds(10));
+        gauge.push(Timestamp::from_seconds(0), 50.0);
+        gauge.push(Timestamp::from_seconds(5), 40.0);
+        gauge.push(Timestamp::from_seconds(4), 0.0);
+        assert_eq!(gauge.rate(), Some(-2.0));
+    }
+}
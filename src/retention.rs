@@ -0,0 +1,172 @@
+//! Deciding which timestamped items (backups, logs, snapshots) to keep.
+
+use crate::{TimeDelta, Timestamp};
+
+// ============================================================================================== //
+// [RetentionPolicy]                                                                              //
+// ============================================================================================== //
+
+/// A set of retention rules. An item is kept if any of the rules keeps it.
+///
+/// - [`keep_within`](Self::keep_within) keeps everything younger than a given age.
+/// - [`keep_per_period`](Self::keep_per_period) keeps the newest item of each of the `n` most
+///   recent periods that contain items, like `restic forget --keep-daily n`. Periods are
+///   aligned to the epoch.
+///
+/// ```
+/// use fast_utc::{RetentionPolicy, TimeDelta, Timestamp};
+///
+/// let policy = RetentionPolicy::new()
+///     .keep_within(TimeDelta::from_hours(2))
+///     .keep_per_period(TimeDelta::from_days(1), 7);
+///
+/// let now = Timestamp::from_seconds(30 * 86_400);
+/// assert!(policy.should_retain(now - TimeDelta::from_hours(1), now));
+/// assert_eq!(policy.expired_before(now), now - TimeDelta::from_hours(2));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RetentionPolicy {
+    keep_within: Option<TimeDelta>,
+    per_period: Vec<(TimeDelta, usize)>,
+}
+
+impl RetentionPolicy {
+    /// A policy without rules, retaining nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also keep every item younger than `age`.
+    pub fn keep_within(mut self, age: TimeDelta) -> Self {
+        self.keep_within = Some(self.keep_within.map_or(age, |current| current.max(age)));
+        self
+    }
+
+    /// Also keep the newest item of each of the `count` most recent non-empty periods.
+    ///
+    /// # Panics
+    ///
+    /// If `period` isn't positive.
+    pub fn keep_per_period(mut self, period: TimeDelta, count: usize) -> Self {
+        assert!(period.as_nanoseconds() > 0, "period must be positive");
+        self.per_period.push((period, count));
+        self
+    }
+
+    /// Combine two policies, keeping everything either of them keeps.
+    pub fn union(mut self, other: RetentionPolicy) -> Self {
+        if let Some(age) = other.keep_within {
+            self = self.keep_within(age);
+        }
+        self.per_period.extend(other.per_period);
+        self
+    }
+
+    /// Items at or after this point in time are kept by the age rule, older ones expired.
+    /// Without an age rule this is `now`, even though the policy doesn't keep such items.
+    ///
+    /// Per-period rules may still keep older items, see [`apply`](Self::apply).
+    pub fn expired_before(&self, now: Timestamp) -> Timestamp {
+        match self.keep_within {
+            Some(age) => now - age,
+            None => now,
+        }
+    }
+
+    /// Whether the age rule keeps an item stamped `ts`.
+    ///
+    /// Per-period rules depend on the other items and are only evaluated by
+    /// [`apply`](Self::apply).
+    pub fn should_retain(&self, ts: Timestamp, now: Timestamp) -> bool {
+        self.keep_within.is_some() && ts >= self.expired_before(now)
+    }
+
+    /// Evaluate all rules over a set of items, returning whether to keep each one.
+    pub fn apply(&self, items: &[Timestamp], now: Timestamp) -> Vec<bool> {
+        let mut keep: Vec<bool> = items.iter().map(|&ts| self.should_retain(ts, now)).collect();
+        if self.per_period.is_empty() {
+            return keep;
+        }
+
+        let mut newest_first: Vec<usize> = (0..items.len()).collect();
+        newest_first.sort_by(|&a, &b| items[b].cmp(&items[a]));
+
+        for &(period, count) in &self.per_period {
+            let mut periods_kept = 0;
+            let mut last_period = None;
+            for &i in &newest_first {
+                if periods_kept == count {
+                    break;
+                }
+                let bucket = items[i].align_to(period);
+                if last_period != Some(bucket) {
+                    last_period = Some(bucket);
+                    periods_kept += 1;
+                    keep[i] = true;
+                }
+            }
+        }
+        keep
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn retention_age_rule() {
+        let now = Timestamp::from_seconds(1_000_000);
+        let policy = RetentionPolicy::new().keep_within(TimeDelta::from_hours(1));
+
+        assert!(policy.should_retain(now - TimeDelta::from_minutes(60), now));
+        assert!(!policy.should_retain(now - TimeDelta::from_minutes(61), now));
+        assert!(!RetentionPolicy::new().should_retain(now - TimeDelta::from_seconds(1), now));
+        assert_eq!(
+            RetentionPolicy::new().union(policy.clone()).keep_within(TimeDelta::from_minutes(5)),
+            policy,
+        );
+    }
+
+    #[test]
+    fn retention_empty_policy() {
+        let now = Timestamp::from_seconds(1_000_000);
+        let policy = RetentionPolicy::new();
+        let items = [now - TimeDelta::from_seconds(1), now, now + TimeDelta::from_seconds(1)];
+
+        assert!(!policy.should_retain(now, now));
+        assert!(!policy.should_retain(now + TimeDelta::from_seconds(1), now));
+        assert_eq!(policy.apply(&items, now), [false; 3]);
+    }
+
+    #[test]
+    fn retention_per_period() {
+        let day = TimeDelta::from_hours(24);
+        let now = Timestamp::from_epoch_days(100);
+        // Two backups a day for ten days, plus one from just now.
+        let mut items: Vec<_> = (90..100)
+            .map(Timestamp::from_epoch_days)
+            .flat_map(|d| [d + TimeDelta::from_hours(3), d + TimeDelta::from_hours(15)])
+            .collect();
+        items.push(now);
+
+        let policy = RetentionPolicy::new()
+            .keep_within(TimeDelta::from_hours(12))
+            .union(RetentionPolicy::new().keep_per_period(day, 3));
+        let kept: Vec<_> = items
+            .iter()
+            .zip(policy.apply(&items, now))
+            .filter_map(|(&ts, keep)| keep.then_some(ts))
+            .collect();
+
+        assert_eq!(kept, [
+            Timestamp::from_epoch_days(98) + TimeDelta::from_hours(15),
+            Timestamp::from_epoch_days(99) + TimeDelta::from_hours(15),
+            now,
+        ]);
+    }
+}
@@ -0,0 +1,65 @@
+//! rusqlite column conversions for [`Timestamp`].
+
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+use crate::{TimeError, Timestamp};
+
+/// Stored as `INTEGER` nanoseconds since the epoch. Fails past `2262-04-11`, the end of
+/// SQLite's `i64` range.
+impl ToSql for Timestamp {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        let nanos = i64::try_from(self.0)
+            .map_err(|_| rusqlite::Error::ToSqlConversionFailure(Box::new(TimeError::Overflow)))?;
+        Ok(ToSqlOutput::from(nanos))
+    }
+}
+
+/// Reads `INTEGER` nanoseconds since the epoch, or `TEXT` as written by SQLite's date and time
+/// functions, e.g. `2024-05-01 13:30:00.500`, with or without a zone designator. Text without
+/// one is taken as UTC.
+impl FromSql for Timestamp {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Integer(nanos) => {
+                u64::try_from(nanos).map(Timestamp).map_err(|_| FromSqlError::OutOfRange(nanos))
+            }
+            ValueRef::Text(text) => {
+                let text = core::str::from_utf8(text).map_err(|err| FromSqlError::Other(Box::new(err)))?;
+                text.parse()
+                    .or_else(|_| Timestamp::parse_naive(&text.replacen('T', " ", 1)))
+                    .map_err(|err| FromSqlError::Other(Box::new(err)))
+            }
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use rusqlite::types::{FromSql, FromSqlError, ToSql, ToSqlOutput, Value, ValueRef};
+
+    #[test]
+    fn rusqlite_round_trip() {
+        let ts = Timestamp::from_nanoseconds(1_714_570_200_123_456_789);
+        let nanos = 1_714_570_200_123_456_789;
+        assert_eq!(ts.to_sql().unwrap(), ToSqlOutput::Owned(Value::Integer(nanos)));
+        assert_eq!(Timestamp::column_result(ValueRef::Integer(nanos)), Ok(ts));
+        assert!(Timestamp::MAX.to_sql().is_err());
+        assert!(matches!(Timestamp::column_result(ValueRef::Integer(-1)), Err(FromSqlError::OutOfRange(-1))));
+        assert!(matches!(Timestamp::column_result(ValueRef::Real(1.0)), Err(FromSqlError::InvalidType)));
+    }
+
+    #[test]
+    fn rusqlite_text() {
+        let ts = Timestamp::from_milliseconds(1_714_570_200_500);
+        for text in ["2024-05-01 13:30:00.500", "2024-05-01T13:30:00.500", "2024-05-01T15:30:00.5+02:00"] {
+            assert_eq!(Timestamp::column_result(ValueRef::Text(text.as_bytes())), Ok(ts), "{}", text);
+        }
+        assert!(Timestamp::column_result(ValueRef::Text(b"yesterday")).is_err());
+    }
+}
@@ -0,0 +1,228 @@
+//! Helpers for running work on a fixed time grid.
+
+use crate::{Interval, TimeDelta, TimeOfDay, Timestamp};
+
+// ============================================================================================== //
+// [NextFire]                                                                                     //
+// ============================================================================================== //
+
+/// What [`NextFire`] does about grid points that passed while the caller was busy.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MissedFires {
+    /// Drop missed grid points and continue with the first one after `now`.
+    Skip,
+    /// Return every missed grid point in order, even if it already lies in the past.
+    CatchUp,
+}
+
+/// Computes the fire times of a periodic task on the grid `anchor + k * freq`.
+///
+/// Unlike adding the period to the time the task ran, fire times never drift off the grid
+/// when the caller wakes up late, and no grid point is ever returned twice.
+///
+/// ```
+/// use fast_utc::{NextFire, TimeDelta, Timestamp};
+///
+/// let mut fire = NextFire::new(TimeDelta::from_seconds(10), Timestamp::from_seconds(3));
+/// assert_eq!(fire.next_after(Timestamp::from_seconds(0)), Timestamp::from_seconds(3));
+/// // Woke up late, past the 13s grid point: keep to the grid.
+/// assert_eq!(fire.next_after(Timestamp::from_seconds(17)), Timestamp::from_seconds(23));
+/// ```
+#[derive(Clone, Debug)]
+pub struct NextFire {
+    freq: TimeDelta,
+    anchor: Timestamp,
+    policy: MissedFires,
+    last: Option<Timestamp>,
+}
+
+impl NextFire {
+    /// Fire every `freq`, on the grid through `anchor`, skipping missed grid points.
+    ///
+    /// # Panics
+    ///
+    /// If `freq` isn't positive.
+    pub fn new(freq: TimeDelta, anchor: Timestamp) -> Self {
+        assert!(freq.as_nanoseconds() > 0, "frequency must be positive");
+        NextFire { freq, anchor, policy: MissedFires::Skip, last: None }
+    }
+
+    /// Choose how grid points missed by a delayed caller are handled.
+    pub fn with_policy(mut self, policy: MissedFires) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Fire time following the last one returned, given the current time.
+    ///
+    /// Strictly after `now` unless catching up on missed grid points.
+    pub fn next_after(&mut self, now: Timestamp) -> Timestamp {
+        let upcoming = {
+            let aligned = now.align_to_anchored(self.anchor, self.freq);
+            if aligned > now { aligned } else { aligned + self.freq }
+        };
+        let next = match (self.last, self.policy) {
+            (None, _) => upcoming,
+            (Some(last), MissedFires::Skip) => upcoming.max(last + self.freq),
+            (Some(last), MissedFires::CatchUp) => last + self.freq,
+        };
+        self.last = Some(next);
+        next
+    }
+
+    /// The fire time most recently returned by [`next_after`](Self::next_after).
+    pub fn last(&self) -> Option<Timestamp> {
+        self.last
+    }
+}
+
+// ============================================================================================== //
+// [RotationSchedule]                                                                             //
+// ============================================================================================== //
+
+/// When a log or archive file is rotated, independent of its size.
+///
+/// ```
+/// use fast_utc::{RotationSchedule, TimeOfDay, Timestamp};
+///
+/// // Rotate every day at 02:00 UTC.
+/// let schedule = RotationSchedule::daily_at(TimeOfDay::from_hms(2, 0, 0).unwrap());
+/// let ts = Timestamp::from_seconds(1_714_528_800); // 2024-05-01 02:00:00 UTC
+///
+/// assert_eq!(schedule.filename_suffix(ts), "2024-05-01");
+/// assert_eq!(schedule.filename_suffix(ts - fast_utc::TimeDelta::from_seconds(1)), "2024-04-30");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RotationSchedule {
+    kind: RotationKind,
+    freq: TimeDelta,
+    /// Offset of the period grid from the epoch.
+    anchor: Timestamp,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+enum RotationKind {
+    Hourly,
+    Daily,
+    Every,
+}
+
+impl RotationSchedule {
+    /// Rotate at the start of every UTC hour.
+    pub const fn hourly() -> Self {
+        RotationSchedule {
+            kind: RotationKind::Hourly,
+            freq: TimeDelta::from_hours(1),
+            anchor: Timestamp::zero(),
+        }
+    }
+
+    /// Rotate once a day at the given UTC time of day.
+    pub const fn daily_at(time: TimeOfDay) -> Self {
+        RotationSchedule {
+            kind: RotationKind::Daily,
+            freq: TimeDelta::from_days(1),
+            anchor: Timestamp::zero().with_time_of_day(time),
+        }
+    }
+
+    /// Rotate every `freq`, on a grid aligned to the epoch.
+    ///
+    /// # Panics
+    ///
+    /// If `freq` isn't positive.
+    pub fn every(freq: TimeDelta) -> Self {
+        assert!(freq.as_nanoseconds() > 0, "frequency must be positive");
+        RotationSchedule { kind: RotationKind::Every, freq, anchor: Timestamp::zero() }
+    }
+
+    /// The rotation period containing `ts`.
+    ///
+    /// Periods starting before the epoch are cut off at the epoch.
+    pub fn current_period(&self, ts: Timestamp) -> Interval {
+        let since_anchor = ts.0 as i64 - self.anchor.0 as i64;
+        let start = self.anchor.0 as i64 + since_anchor.div_euclid(self.freq.0) * self.freq.0;
+        let end = start + self.freq.0;
+        Interval::new(Timestamp(start.max(0) as u64), Timestamp(end as u64))
+    }
+
+    /// Suffix for the file covering the period containing `ts`, named after the period start.
+    ///
+    /// `2024-05-01-13` for hourly, `2024-05-01` for daily and `2024-05-01T13-15-00` for other
+    /// schedules, avoiding characters that are invalid in file names.
+    pub fn filename_suffix(&self, ts: Timestamp) -> String {
+        let start = self.current_period(ts).start();
+        let (year, month, day) = start.civil_date();
+        let time = start.time_of_day();
+        match self.kind {
+            RotationKind::Hourly => format!("{:04}-{:02}-{:02}-{:02}", year, month, day, time.hour()),
+            RotationKind::Daily => format!("{:04}-{:02}-{:02}", year, month, day),
+            RotationKind::Every => format!(
+                "{:04}-{:02}-{:02}T{:02}-{:02}-{:02}",
+                year,
+                month,
+                day,
+                time.hour(),
+                time.minute(),
+                time.second(),
+            ),
+        }
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn next_fire_skip() {
+        let secs = Timestamp::from_seconds;
+        let mut fire = NextFire::new(TimeDelta::from_seconds(10), secs(1_000_003));
+
+        assert_eq!(fire.next_after(secs(5)), secs(13));
+        // Early wake-up must not return the same grid point twice.
+        assert_eq!(fire.next_after(secs(12)), secs(23));
+        assert_eq!(fire.next_after(secs(23)), secs(33));
+        assert_eq!(fire.next_after(secs(71)), secs(73));
+        assert_eq!(fire.last(), Some(secs(73)));
+    }
+
+    #[test]
+    fn next_fire_catch_up() {
+        let secs = Timestamp::from_seconds;
+        let mut fire = NextFire::new(TimeDelta::from_seconds(10), secs(3)).with_policy(MissedFires::CatchUp);
+
+        assert_eq!(fire.next_after(secs(5)), secs(13));
+        assert_eq!(fire.next_after(secs(45)), secs(23));
+        assert_eq!(fire.next_after(secs(45)), secs(33));
+        assert_eq!(fire.next_after(secs(45)), secs(43));
+        assert_eq!(fire.next_after(secs(45)), secs(53));
+    }
+
+    #[test]
+    fn rotation_periods() {
+        let base = Timestamp::from_epoch_days(19_844); // 2024-05-01
+        let at = |h: i64, m: i64| base + TimeDelta::from_hours(h) + TimeDelta::from_minutes(m);
+
+        let hourly = RotationSchedule::hourly();
+        assert_eq!(hourly.current_period(at(13, 47)), Interval::new(at(13, 0), at(14, 0)));
+        assert_eq!(hourly.filename_suffix(at(13, 47)), "2024-05-01-13");
+
+        let daily = RotationSchedule::daily_at(TimeOfDay::from_hms(2, 30, 0).unwrap());
+        assert_eq!(daily.current_period(at(1, 0)), Interval::new(at(-22, 30), at(2, 30)));
+        assert_eq!(daily.current_period(at(2, 30)), Interval::new(at(2, 30), at(26, 30)));
+        assert_eq!(daily.filename_suffix(at(1, 0)), "2024-04-30");
+        assert_eq!(
+            daily.current_period(Timestamp::zero()),
+            Interval::new(Timestamp::zero(), Timestamp::zero() + TimeDelta::from_minutes(150)),
+        );
+
+        let every = RotationSchedule::every(TimeDelta::from_minutes(15));
+        assert_eq!(every.current_period(at(13, 47)), Interval::new(at(13, 45), at(14, 0)));
+        assert_eq!(every.filename_suffix(at(13, 47)), "2024-05-01T13-45-00");
+    }
+}
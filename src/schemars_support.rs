@@ -0,0 +1,94 @@
+//! JSON Schemas of the serde representations of timestamps and deltas in JSON.
+
+use std::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{TimeDelta, Timestamp};
+
+/// An RFC 3339 date time string, or with `serde-nanos` an unsigned integer of nanoseconds since the
+/// epoch, as serialized by serde to JSON.
+impl JsonSchema for Timestamp {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Timestamp".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "fast_utc::Timestamp".into()
+    }
+
+    #[cfg(not(feature = "serde-nanos"))]
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "format": "date-time",
+            "description": "RFC 3339 date time, written in UTC with nanoseconds",
+        })
+    }
+
+    #[cfg(feature = "serde-nanos")]
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "integer",
+            "format": "uint64",
+            "minimum": 0,
+            "description": "Nanoseconds since 1970-01-01T00:00:00Z",
+        })
+    }
+}
+
+/// A signed integer of nanoseconds, as serialized by serde.
+impl JsonSchema for TimeDelta {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "TimeDelta".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "fast_utc::TimeDelta".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "integer",
+            "format": "int64",
+            "description": "Signed duration in nanoseconds",
+        })
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use schemars::{JsonSchema, SchemaGenerator};
+
+    #[test]
+    fn schemas_match_serde() {
+        let mut generator = SchemaGenerator::default();
+        let schema = Timestamp::json_schema(&mut generator);
+        let value = serde_json::to_value(Timestamp::from_seconds(1)).unwrap();
+        if cfg!(feature = "serde-nanos") {
+            assert_eq!(schema.get("format"), Some(&"uint64".into()));
+            assert_eq!(value, serde_json::json!(1_000_000_000u64));
+        } else {
+            assert_eq!(schema.get("format"), Some(&"date-time".into()));
+            assert_eq!(value, serde_json::json!("1970-01-01T00:00:01.000000000Z"));
+        }
+
+        let schema = TimeDelta::json_schema(&mut generator);
+        assert_eq!(schema.get("format"), Some(&"int64".into()));
+        let value = serde_json::to_value(-TimeDelta::SECOND).unwrap();
+        assert_eq!(value, serde_json::json!(-1_000_000_000i64));
+    }
+}
@@ -0,0 +1,592 @@
+//! Serde support for timestamps in human readable and compact formats.
+//!
+//! [`Timestamp`] serializes as an RFC 3339 string with nanoseconds, e.g.
+//! `"2024-05-01T13:30:00.500000000Z"`, in human readable formats such as JSON or YAML and as `u64`
+//! nanoseconds since the epoch in binary formats such as bincode or postcard. The `serde-nanos`
+//! feature forces integer nanoseconds everywhere, as earlier releases wrote them.
+//!
+//! Human readable formats deserialize whatever feeds commonly send:
+//!
+//! - RFC 3339 strings in any offset, e.g. `"2024-05-01T09:30:00.5-04:00"`,
+//! - integer nanoseconds, e.g. `1714570200000000000`,
+//! - float seconds, e.g. `1714570200.5`,
+//! - strings of integer nanoseconds, e.g. map keys written with `serde-nanos`.
+//!
+//! Integer milliseconds can't be told apart from nanoseconds by type, so they are only accepted
+//! by the opt-in [`lenient`] mode. Binary formats keep reading plain `u64` nanoseconds.
+//!
+//! Like chrono's `serde` modules, the submodules here pin the format of a single field with
+//! `#[serde(with = "...")]`, both ways:
+//!
+//! ```
+//! use fast_utc::Timestamp;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Order {
+//!     #[serde(with = "fast_utc::serde::rfc3339")]
+//!     placed: Timestamp,
+//!     #[serde(with = "fast_utc::serde::ts_millis_option")]
+//!     filled: Option<Timestamp>,
+//! }
+//!
+//! let order = Order { placed: Timestamp::from_seconds(1_714_570_200), filled: None };
+//! let json = serde_json::to_string(&order).unwrap();
+//! assert_eq!(json, r#"{"placed":"2024-05-01T13:30:00.000000000Z","filled":null}"#);
+//! ```
+
+use core::fmt;
+
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::{Deserialize, Serialize, Serializer};
+
+use crate::{TimeError, Timestamp};
+
+/// Visits any representation of a timestamp, see the [module docs](self).
+struct TimestampVisitor {
+    lenient: bool,
+}
+
+impl TimestampVisitor {
+    fn nanos_or_millis<E: de::Error>(&self, int: u64) -> Result<Timestamp, E> {
+        if self.lenient && int < lenient::MILLIS_BELOW {
+            Timestamp::try_from_milliseconds(int).map_err(E::custom)
+        } else {
+            Ok(Timestamp(int))
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for TimestampVisitor {
+    type Value = Timestamp;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("integer nanoseconds, float seconds or an RFC 3339 string")
+    }
+
+    fn visit_u64<E: de::Error>(self, int: u64) -> Result<Timestamp, E> {
+        self.nanos_or_millis(int)
+    }
+
+    fn visit_i64<E: de::Error>(self, int: i64) -> Result<Timestamp, E> {
+        let int = u64::try_from(int).map_err(|_| E::custom(TimeError::BeforeEpoch))?;
+        self.nanos_or_millis(int)
+    }
+
+    #[cfg(feature = "std")]
+    fn visit_f64<E: de::Error>(self, secs: f64) -> Result<Timestamp, E> {
+        if secs.is_nan() {
+            Err(E::custom(TimeError::InvalidFormat))
+        } else if secs < 0.0 {
+            Err(E::custom(TimeError::BeforeEpoch))
+        } else if secs >= u64::MAX as f64 / 1e9 {
+            Err(E::custom(TimeError::Overflow))
+        } else {
+            Ok(Timestamp::from_secs_f64(secs))
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Timestamp, E> {
+        if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+            let int = s.parse().map_err(|_| E::custom(TimeError::Overflow))?;
+            return self.nanos_or_millis(int);
+        }
+        Timestamp::parse_rfc3339(s).map_err(E::custom)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<Timestamp, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(self)
+        } else {
+            u64::deserialize(deserializer).map(Timestamp)
+        }
+    }
+}
+
+/// Read any representation in human readable formats and `u64` nanoseconds otherwise.
+fn deserialize_timestamp<'de, D>(deserializer: D, lenient: bool) -> Result<Timestamp, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = TimestampVisitor { lenient };
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(visitor)
+    } else {
+        deserializer.deserialize_newtype_struct("Timestamp", visitor)
+    }
+}
+
+/// RFC 3339 strings in human readable formats and `u64` nanoseconds otherwise, see the
+/// [module docs](self).
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if cfg!(feature = "serde-nanos") || !serializer.is_human_readable() {
+            serializer.serialize_newtype_struct("Timestamp", &self.0)
+        } else {
+            rfc3339::serialize(self, serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_timestamp(deserializer, false)
+    }
+}
+
+/// Like the default representation, but reading small integers as milliseconds, for
+/// `#[serde(with = "fast_utc::serde::lenient")]`.
+///
+/// Integers below [`MILLIS_BELOW`](lenient::MILLIS_BELOW), `10^15`, are milliseconds, which
+/// misreads nanoseconds in the first 12 days of 1970 and milliseconds past the year 33658.
+/// Serializing writes nanoseconds as usual.
+///
+/// ```
+/// # #[cfg(feature = "serde-support")] {
+/// use fast_utc::Timestamp;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Tick {
+///     #[serde(with = "fast_utc::serde::lenient")]
+///     at: Timestamp,
+/// }
+///
+/// let millis: Tick = serde_json::from_str(r#"{"at": 1714570200000}"#).unwrap();
+/// let nanos: Tick = serde_json::from_str(r#"{"at": 1714570200000000000}"#).unwrap();
+/// assert_eq!(millis.at, nanos.at);
+/// # }
+/// ```
+pub mod lenient {
+    use super::*;
+
+    /// Integers below this are read as milliseconds.
+    pub const MILLIS_BELOW: u64 = 1_000_000_000_000_000;
+
+    /// Write integer nanoseconds.
+    pub fn serialize<S: Serializer>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(ts.0)
+    }
+
+    /// Read any representation, taking small integers as milliseconds.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        deserialize_timestamp(deserializer, true)
+    }
+}
+
+// ============================================================================================== //
+// [Field formats]                                                                                //
+// ============================================================================================== //
+
+/// Integer nanoseconds since the epoch, for `#[serde(with = "fast_utc::serde::ts_nanos")]`.
+pub mod ts_nanos {
+    use super::*;
+
+    /// Write integer nanoseconds.
+    pub fn serialize<S: Serializer>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(ts.as_nanoseconds())
+    }
+
+    /// Read integer nanoseconds.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        u64::deserialize(deserializer).map(Timestamp)
+    }
+}
+
+/// Integer milliseconds since the epoch, for `#[serde(with = "fast_utc::serde::ts_millis")]`.
+///
+/// Serializing truncates to whole milliseconds.
+pub mod ts_millis {
+    use super::*;
+
+    /// Write integer milliseconds, truncated.
+    pub fn serialize<S: Serializer>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(ts.as_milliseconds())
+    }
+
+    /// Read integer milliseconds, failing past [`Timestamp::MAX`].
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        Timestamp::try_from_milliseconds(u64::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// Integer seconds since the epoch, for `#[serde(with = "fast_utc::serde::ts_seconds")]`.
+///
+/// Serializing truncates to whole seconds.
+pub mod ts_seconds {
+    use super::*;
+
+    /// Write integer seconds, truncated.
+    pub fn serialize<S: Serializer>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(ts.as_seconds())
+    }
+
+    /// Read integer seconds, failing past [`Timestamp::MAX`].
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        Timestamp::try_from_seconds(u64::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// Integer milliseconds since the epoch that JavaScript reads exactly, for
+/// `#[serde(with = "fast_utc::serde::js_millis")]`.
+///
+/// JavaScript parses JSON numbers as `f64`, which silently rounds integers above
+/// [`MAX_SAFE_INTEGER`](js_millis::MAX_SAFE_INTEGER), e.g. the default nanoseconds of present day
+/// timestamps. Milliseconds of any [`Timestamp`] stay far below, until the year 2554, so
+/// serializing only truncates to whole milliseconds. Deserializing rejects larger integers, which
+/// can't have come from JavaScript intact.
+pub mod js_millis {
+    use super::*;
+
+    /// `2^53 - 1`, JavaScript's `Number.MAX_SAFE_INTEGER`.
+    pub const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+    /// Write integer milliseconds, truncated.
+    pub fn serialize<S: Serializer>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        let millis = ts.as_milliseconds();
+        debug_assert!(millis <= MAX_SAFE_INTEGER);
+        serializer.serialize_u64(millis)
+    }
+
+    /// Read integer milliseconds up to [`MAX_SAFE_INTEGER`].
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        let millis = u64::deserialize(deserializer)?;
+        if millis > MAX_SAFE_INTEGER {
+            return Err(de::Error::custom("integer beyond 2^53 can't come from JavaScript intact"));
+        }
+        Timestamp::try_from_milliseconds(millis).map_err(de::Error::custom)
+    }
+}
+
+/// RFC 3339 strings with nanoseconds, e.g. `"2024-05-01T13:30:00.500000000Z"`, for
+/// `#[serde(with = "fast_utc::serde::rfc3339")]`.
+///
+/// Any offset is read, see [`Timestamp::parse_rfc3339`].
+pub mod rfc3339 {
+    use super::*;
+
+    /// Write an RFC 3339 string in UTC.
+    pub fn serialize<S: Serializer>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut buf = [0; Timestamp::RFC3339_LEN];
+        serializer.serialize_str(ts.format_rfc3339(&mut buf))
+    }
+
+    /// Read an RFC 3339 string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        struct Rfc3339Visitor;
+
+        impl Visitor<'_> for Rfc3339Visitor {
+            type Value = Timestamp;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an RFC 3339 string")
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Timestamp, E> {
+                Timestamp::parse_rfc3339(s).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Rfc3339Visitor)
+    }
+}
+
+/// Define `$name` as the format of `$inner` for `Option<Timestamp>` fields, with `None` as null.
+macro_rules! option_format {
+    ($(#[$attr:meta])* $name:ident => $inner:ident) => {
+        #[doc = concat!("Like [`", stringify!($inner), "`] for `Option<Timestamp>` fields.")]
+        $(#[$attr])*
+        pub mod $name {
+            use super::*;
+
+            struct Field<T>(T);
+
+            impl Serialize for Field<&Timestamp> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    super::$inner::serialize(self.0, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for Field<Timestamp> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    super::$inner::deserialize(deserializer).map(Field)
+                }
+            }
+
+            /// Write `None` as null.
+            pub fn serialize<S>(ts: &Option<Timestamp>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                ts.as_ref().map(Field).serialize(serializer)
+            }
+
+            /// Read null as `None`.
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Timestamp>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Ok(Option::<Field<Timestamp>>::deserialize(deserializer)?.map(|field| field.0))
+            }
+        }
+    };
+}
+
+option_format!(ts_nanos_option => ts_nanos);
+option_format!(ts_millis_option => ts_millis);
+option_format!(ts_seconds_option => ts_seconds);
+option_format!(js_millis_option => js_millis);
+option_format!(rfc3339_option => rfc3339);
+
+// ============================================================================================== //
+// [chrono compatibility]                                                                         //
+// ============================================================================================== //
+
+/// Drop-in replacements for chrono's `serde::ts_*` modules, writing and reading the same signed
+/// integers as for `DateTime<Utc>` fields, so that fields can switch to [`Timestamp`] without
+/// touching stored data.
+///
+/// Unlike the other modules here, these write `i64` like chrono does, which matters for binary
+/// formats with variable length integers. Times before the epoch fail to deserialize. chrono's
+/// default RFC 3339 strings need no module, the default [`Timestamp`] representation reads them.
+///
+/// ```
+/// use fast_utc::Timestamp;
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Fill {
+///     // Was `#[serde(with = "chrono::serde::ts_milliseconds")] at: DateTime<Utc>`.
+///     #[serde(with = "fast_utc::serde::chrono_compat::ts_milliseconds")]
+///     at: Timestamp,
+/// }
+/// ```
+pub mod chrono_compat {
+    use super::*;
+
+    /// Reads signed or unsigned integers of a unit, like chrono's visitors.
+    struct UnitVisitor {
+        nanos_per_unit: u64,
+    }
+
+    impl Visitor<'_> for UnitVisitor {
+        type Value = Timestamp;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a unix timestamp")
+        }
+
+        fn visit_i64<E: de::Error>(self, int: i64) -> Result<Timestamp, E> {
+            let int = u64::try_from(int).map_err(|_| E::custom(TimeError::BeforeEpoch))?;
+            self.visit_u64(int)
+        }
+
+        fn visit_u64<E: de::Error>(self, int: u64) -> Result<Timestamp, E> {
+            int.checked_mul(self.nanos_per_unit).map(Timestamp).ok_or_else(|| E::custom(TimeError::Overflow))
+        }
+    }
+
+    /// Define `$name` writing whole `$unit`s as `i64` and reading them back.
+    macro_rules! chrono_format {
+        ($name:ident, $option:ident, $unit:literal, $nanos_per_unit:literal) => {
+            #[doc = concat!(
+                "Like chrono's `serde::", stringify!($name), "`, integer ", $unit, " since the epoch."
+            )]
+            pub mod $name {
+                use super::*;
+
+                #[doc = concat!("Write signed integer ", $unit, ", truncated.")]
+                pub fn serialize<S: Serializer>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+                    let int = i64::try_from(ts.0 / $nanos_per_unit).map_err(|_| {
+                        ::serde::ser::Error::custom(concat!("value out of range for a timestamp in ", $unit))
+                    })?;
+                    serializer.serialize_i64(int)
+                }
+
+                #[doc = concat!("Read integer ", $unit, ", failing before the epoch.")]
+                pub fn deserialize<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    deserializer.deserialize_i64(UnitVisitor { nanos_per_unit: $nanos_per_unit })
+                }
+            }
+
+            option_format!($option => $name);
+        };
+    }
+
+    chrono_format!(ts_nanoseconds, ts_nanoseconds_option, "nanoseconds", 1);
+    chrono_format!(ts_microseconds, ts_microseconds_option, "microseconds", 1_000);
+    chrono_format!(ts_milliseconds, ts_milliseconds_option, "milliseconds", 1_000_000);
+    chrono_format!(ts_seconds, ts_seconds_option, "seconds", 1_000_000_000);
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    struct Tick {
+        at: Timestamp,
+        #[serde(with = "crate::serde::lenient")]
+        lenient: Timestamp,
+    }
+
+    #[test]
+    fn deserialize_any_representation() {
+        let ts = Timestamp::from_seconds(1_714_570_200) + TimeDelta::from_milliseconds(500);
+        let parse = |json: &str| serde_json::from_str::<Timestamp>(json);
+        assert_eq!(parse("1714570200500000000").unwrap(), ts);
+        if cfg!(feature = "std") {
+            assert_eq!(parse("1714570200.5").unwrap(), ts);
+        }
+        assert_eq!(parse(r#""1714570200500000000""#).unwrap(), ts);
+        assert_eq!(parse(r#""2024-05-01T09:30:00.5-04:00""#).unwrap(), ts);
+        assert_eq!(parse("1714570200500").unwrap(), Timestamp::from_nanoseconds(1_714_570_200_500));
+
+        assert!(parse("-1").is_err());
+        assert!(parse("-0.5").is_err());
+        assert!(parse("1e300").is_err());
+        assert!(parse(r#""yesterday""#).is_err());
+        assert!(parse("true").is_err());
+    }
+
+    #[test]
+    fn deserialize_lenient() {
+        let ts = Timestamp::from_milliseconds(1_714_570_200_500);
+        let tick: Tick = serde_json::from_str(r#"{"at": 0, "lenient": 1714570200500}"#).unwrap();
+        assert_eq!(tick.lenient, ts);
+        let tick: Tick = serde_json::from_str(r#"{"at": 0, "lenient": "1714570200500000000"}"#).unwrap();
+        assert_eq!(tick.lenient, ts);
+
+        let tick = Tick { at: ts, lenient: ts };
+        let json = serde_json::to_string(&tick).unwrap();
+        assert!(json.ends_with(r#","lenient":1714570200500000000}"#));
+        assert_eq!(serde_json::from_str::<Tick>(&json).unwrap(), tick);
+    }
+
+    #[test]
+    fn js_safe_millis() {
+        #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+        struct Js {
+            #[serde(with = "crate::serde::js_millis")]
+            at: Timestamp,
+            #[serde(with = "crate::serde::js_millis_option")]
+            max: Option<Timestamp>,
+        }
+
+        let js = Js { at: Timestamp::from_nanoseconds(1_714_570_200_500_999_999), max: Some(Timestamp::MAX) };
+        let json = serde_json::to_string(&js).unwrap();
+        assert_eq!(json, r#"{"at":1714570200500,"max":18446744073709}"#);
+        let js = serde_json::from_str::<Js>(&json).unwrap();
+        assert_eq!(js.at, Timestamp::from_milliseconds(1_714_570_200_500));
+
+        assert!(serde_json::from_str::<Js>(r#"{"at":9007199254740991,"max":null}"#).is_err());
+        assert!(serde_json::from_str::<Js>(r#"{"at":9007199254740992,"max":null}"#).is_err());
+        assert!(serde_json::from_str::<Js>(r#"{"at":18446744073709,"max":null}"#).is_ok());
+    }
+
+    #[cfg(feature = "chrono-support")]
+    #[test]
+    fn chrono_wire_compatible() {
+        use chrono::{DateTime, Utc};
+
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
+        struct Chrono {
+            #[serde(with = "chrono::serde::ts_nanoseconds")]
+            nanos: DateTime<Utc>,
+            #[serde(with = "chrono::serde::ts_milliseconds")]
+            millis: DateTime<Utc>,
+            #[serde(with = "chrono::serde::ts_seconds_option")]
+            seconds: Option<DateTime<Utc>>,
+            rfc3339: DateTime<Utc>,
+        }
+
+        #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+        struct Fast {
+            #[serde(with = "crate::serde::chrono_compat::ts_nanoseconds")]
+            nanos: Timestamp,
+            #[serde(with = "crate::serde::chrono_compat::ts_milliseconds")]
+            millis: Timestamp,
+            #[serde(with = "crate::serde::chrono_compat::ts_seconds_option")]
+            seconds: Option<Timestamp>,
+            #[serde(with = "crate::serde::rfc3339")]
+            rfc3339: Timestamp,
+        }
+
+        let ts = Timestamp::from_seconds(1_714_570_200);
+        let dt = DateTime::<Utc>::from(ts);
+        let chrono = Chrono { nanos: dt, millis: dt, seconds: Some(dt), rfc3339: dt };
+        let fast = Fast { nanos: ts, millis: ts, seconds: Some(ts), rfc3339: ts };
+
+        let json = serde_json::to_string(&chrono).unwrap();
+        assert_eq!(serde_json::from_str::<Fast>(&json).unwrap(), fast);
+        let bytes = bincode::serialize(&fast).unwrap();
+        assert_eq!(bytes[..24], bincode::serialize(&chrono).unwrap()[..24]);
+        let json = serde_json::to_string(&fast).unwrap();
+        let chrono = serde_json::from_str::<Chrono>(&json).unwrap();
+        assert_eq!((chrono.nanos, chrono.millis, chrono.seconds, chrono.rfc3339), (dt, dt, Some(dt), dt));
+
+        let json = r#"{"nanos":-1,"millis":0,"seconds":null,"rfc3339":"1970-01-01T00:00:00Z"}"#;
+        assert!(serde_json::from_str::<Fast>(json).is_err());
+        let fast = Fast { nanos: Timestamp::MAX, ..fast };
+        assert!(serde_json::to_string(&fast).is_err());
+    }
+
+    #[test]
+    fn human_readable_or_compact() {
+        let ts = Timestamp::from_milliseconds(1_714_570_200_500);
+        let json = serde_json::to_string(&ts).unwrap();
+        if cfg!(feature = "serde-nanos") {
+            assert_eq!(json, "1714570200500000000");
+        } else {
+            assert_eq!(json, r#""2024-05-01T13:30:00.500000000Z""#);
+        }
+        assert_eq!(serde_json::from_str::<Timestamp>(&json).unwrap(), ts);
+
+        let bytes = bincode::serialize(&ts).unwrap();
+        assert_eq!(bytes, 1_714_570_200_500_000_000u64.to_le_bytes());
+        assert_eq!(bincode::deserialize::<Timestamp>(&bytes).unwrap(), ts);
+        let tick = Tick { at: ts, lenient: ts };
+        let bytes = bincode::serialize(&tick).unwrap();
+        assert_eq!(bincode::deserialize::<Tick>(&bytes).unwrap(), tick);
+    }
+
+    #[test]
+    fn field_formats() {
+        #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+        struct Fields {
+            #[serde(with = "crate::serde::ts_nanos")]
+            nanos: Timestamp,
+            #[serde(with = "crate::serde::ts_millis")]
+            millis: Timestamp,
+            #[serde(with = "crate::serde::ts_seconds")]
+            seconds: Timestamp,
+            #[serde(with = "crate::serde::rfc3339")]
+            rfc3339: Timestamp,
+            #[serde(with = "crate::serde::ts_millis_option")]
+            some: Option<Timestamp>,
+            #[serde(with = "crate::serde::rfc3339_option")]
+            none: Option<Timestamp>,
+        }
+
+        let ts = Timestamp::from_seconds(1_714_570_200);
+        let fields = Fields { nanos: ts, millis: ts, seconds: ts, rfc3339: ts, some: Some(ts), none: None };
+        let json = serde_json::to_string(&fields).unwrap();
+        let expected = concat!(
+            r#"{"nanos":1714570200000000000,"millis":1714570200000,"seconds":1714570200,"#,
+            r#""rfc3339":"2024-05-01T13:30:00.000000000Z","some":1714570200000,"none":null}"#
+        );
+        assert_eq!(json, expected);
+        assert_eq!(serde_json::from_str::<Fields>(&json).unwrap(), fields);
+
+        let json = r#"{"nanos":0,"millis":0,"seconds":18446744074,"rfc3339":"1970-01-01T00:00:00Z","#;
+        assert!(serde_json::from_str::<Fields>(&format!(r#"{json}"some":null,"none":null}}"#)).is_err());
+        let json = r#"{"nanos":0,"millis":0,"seconds":0,"rfc3339":"2024-05-01T09:30:00-04:00","#;
+        let fields = serde_json::from_str::<Fields>(&format!(r#"{json}"some":null,"none":null}}"#)).unwrap();
+        assert_eq!(fields.rfc3339, ts);
+        assert!(serde_json::from_str::<Fields>(r#"{"rfc3339":1714570200}"#).is_err());
+    }
+}
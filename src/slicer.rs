@@ -0,0 +1,151 @@
+//! Round-robin assignment of time slices to a fixed set of keys.
+
+use crate::{Interval, TimeDelta, Timestamp};
+
+// ============================================================================================== //
+// [TimeSlicer]                                                                                   //
+// ============================================================================================== //
+
+/// Hands out consecutive time slices to keys in turn, e.g. to stagger batch work across workers.
+///
+/// Slices are `slice` long and aligned to an anchor, the epoch unless set otherwise. Slice
+/// number `n` counted from the anchor belongs to the `n % keys.len()`th key, so every party
+/// sharing the same keys, slice length and anchor agrees on the schedule without coordination.
+/// Slices before the anchor continue the rotation backwards rather than restarting at it.
+///
+/// ```
+/// use fast_utc::{TimeDelta, TimeSlicer, Timestamp};
+///
+/// let slicer = TimeSlicer::new(["a", "b", "c"], TimeDelta::from_minutes(1));
+/// let ts = Timestamp::from_seconds(3_725); // Minute 62.
+/// assert_eq!(slicer.on_duty(ts), Some(&"c"));
+/// assert!(slicer.is_on_duty(&"c", ts));
+/// assert_eq!(slicer.next_duty(&"a", ts).unwrap().start(), Timestamp::from_seconds(3_780));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TimeSlicer<K> {
+    keys: Vec<K>,
+    slice: TimeDelta,
+    anchor: Timestamp,
+}
+
+impl<K> TimeSlicer<K> {
+    /// Rotate through `keys` in the given order, `slice` at a time.
+    ///
+    /// # Panics
+    ///
+    /// If `slice` isn't positive.
+    pub fn new(keys: impl IntoIterator<Item = K>, slice: TimeDelta) -> Self {
+        assert!(slice.0 > 0, "slice length must be positive");
+        TimeSlicer { keys: keys.into_iter().collect(), slice, anchor: Timestamp::zero() }
+    }
+
+    /// Align the slices to `anchor` instead of the epoch.
+    pub fn with_anchor(mut self, anchor: Timestamp) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// The keys in rotation order.
+    pub fn keys(&self) -> &[K] {
+        &self.keys
+    }
+
+    /// Length of a single slice.
+    pub fn slice_length(&self) -> TimeDelta {
+        self.slice
+    }
+
+    /// The slice containing `ts`. Slices starting before the epoch are cut off at the epoch.
+    pub fn slice_at(&self, ts: Timestamp) -> Interval {
+        self.nth_slice(self.slice_index(ts))
+    }
+
+    /// The key on duty at `ts`, `None` if there are no keys.
+    pub fn on_duty(&self, ts: Timestamp) -> Option<&K> {
+        let n = self.keys.len() as i64;
+        if n == 0 {
+            return None;
+        }
+        self.keys.get(self.slice_index(ts).rem_euclid(n) as usize)
+    }
+
+    /// Signed number of slices from the anchor to the slice containing `ts`.
+    fn slice_index(&self, ts: Timestamp) -> i64 {
+        // Euclidean division, truncation would merge the slices on both sides of the anchor.
+        (ts.0 as i64 - self.anchor.0 as i64).div_euclid(self.slice.0)
+    }
+
+    fn nth_slice(&self, index: i64) -> Interval {
+        let start = self.anchor.0 as i64 + index * self.slice.0;
+        Interval::new(Timestamp(start.max(0) as u64), Timestamp((start + self.slice.0).max(0) as u64))
+    }
+}
+
+impl<K: PartialEq> TimeSlicer<K> {
+    /// Check whether `key` is on duty at `ts`.
+    pub fn is_on_duty(&self, key: &K, ts: Timestamp) -> bool {
+        self.on_duty(ts) == Some(key)
+    }
+
+    /// The first slice of `key` ending after `ts`, i.e. its current slice if it is on duty.
+    ///
+    /// `None` if `key` isn't part of the rotation. Keys listed more than once get the earliest
+    /// of their slices.
+    pub fn next_duty(&self, key: &K, ts: Timestamp) -> Option<Interval> {
+        let n = self.keys.len() as i64;
+        let index = self.slice_index(ts);
+        let current = index.rem_euclid(n.max(1));
+        let ahead = self
+            .keys
+            .iter()
+            .enumerate()
+            .filter(|&(_, k)| k == key)
+            .map(|(pos, _)| (pos as i64 - current).rem_euclid(n))
+            .min()?;
+        Some(self.nth_slice(index + ahead))
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn anchored_rotation() {
+        let secs = Timestamp::from_seconds;
+        let slicer = TimeSlicer::new(['a', 'b', 'c'], TimeDelta::from_seconds(10)).with_anchor(secs(105));
+
+        assert_eq!(slicer.on_duty(secs(105)), Some(&'a'));
+        assert_eq!(slicer.on_duty(secs(114)), Some(&'a'));
+        assert_eq!(slicer.on_duty(secs(115)), Some(&'b'));
+        assert_eq!(slicer.on_duty(secs(134)), Some(&'c'));
+        // Before the anchor the rotation runs backwards: ..., b, c | a, b, c, ...
+        assert_eq!(slicer.on_duty(secs(104)), Some(&'c'));
+        assert_eq!(slicer.on_duty(secs(94)), Some(&'b'));
+        assert_eq!(slicer.slice_at(secs(104)), Interval::new(secs(95), secs(105)));
+
+        assert_eq!(slicer.next_duty(&'a', secs(116)), Some(Interval::new(secs(135), secs(145))));
+        assert_eq!(slicer.next_duty(&'b', secs(116)), Some(Interval::new(secs(115), secs(125))));
+        assert_eq!(slicer.next_duty(&'a', secs(100)), Some(Interval::new(secs(105), secs(115))));
+        assert_eq!(slicer.next_duty(&'z', secs(100)), None);
+        assert!(!slicer.is_on_duty(&'a', secs(125)));
+
+        // Exactly one key is on duty at any time.
+        for s in 0..300 {
+            let on_duty = slicer.keys().iter().filter(|k| slicer.is_on_duty(k, secs(s))).count();
+            assert_eq!(on_duty, 1, "secs = {}", s);
+        }
+    }
+
+    #[test]
+    fn no_keys() {
+        let slicer = TimeSlicer::<u32>::new([], TimeDelta::from_seconds(1));
+        assert_eq!(slicer.on_duty(Timestamp::from_seconds(5)), None);
+        assert_eq!(slicer.next_duty(&1, Timestamp::from_seconds(5)), None);
+    }
+}
@@ -0,0 +1,141 @@
+//! sqlx column types for [`Timestamp`].
+//!
+//! Postgres and MySQL store timestamps in their native date time types through sqlx's chrono
+//! support, which keeps microseconds and truncates the rest. SQLite has no date time type, so
+//! timestamps are stored as `INTEGER` nanoseconds there, keeping full precision.
+
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::{Decode, Encode, MySql, Postgres, Sqlite, Type};
+
+use crate::{TimeError, Timestamp};
+
+type DateTime = chrono::DateTime<chrono::Utc>;
+
+// ============================================================================================== //
+// [Postgres]                                                                                     //
+// ============================================================================================== //
+
+/// A `TIMESTAMPTZ` column.
+impl Type<Postgres> for Timestamp {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        <DateTime as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        <DateTime as Type<Postgres>>::compatible(ty)
+    }
+}
+
+/// Fails with [`TimeError::Overflow`] past `2262-04-11`, the end of chrono's range.
+impl Encode<'_, Postgres> for Timestamp {
+    fn encode_by_ref(&self, buf: &mut sqlx::postgres::PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <DateTime as Encode<Postgres>>::encode(self.try_into_chrono()?, buf)
+    }
+}
+
+/// Fails with [`TimeError::BeforeEpoch`] for times before the epoch.
+impl Decode<'_, Postgres> for Timestamp {
+    fn decode(value: sqlx::postgres::PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(Timestamp::try_from_chrono(<DateTime as Decode<Postgres>>::decode(value)?)?)
+    }
+}
+
+// ============================================================================================== //
+// [MySQL]                                                                                        //
+// ============================================================================================== //
+
+/// A `DATETIME(6)` column holding UTC.
+impl Type<MySql> for Timestamp {
+    fn type_info() -> sqlx::mysql::MySqlTypeInfo {
+        <chrono::NaiveDateTime as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &sqlx::mysql::MySqlTypeInfo) -> bool {
+        <chrono::NaiveDateTime as Type<MySql>>::compatible(ty)
+    }
+}
+
+/// Fails with [`TimeError::Overflow`] past `2262-04-11`, the end of chrono's range.
+impl Encode<'_, MySql> for Timestamp {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        let naive = self.try_into_chrono()?.naive_utc();
+        <chrono::NaiveDateTime as Encode<MySql>>::encode(naive, buf)
+    }
+}
+
+/// Fails with [`TimeError::BeforeEpoch`] for times before the epoch.
+impl Decode<'_, MySql> for Timestamp {
+    fn decode(value: sqlx::mysql::MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        let naive = <chrono::NaiveDateTime as Decode<MySql>>::decode(value)?;
+        Ok(Timestamp::try_from_chrono(naive.and_utc())?)
+    }
+}
+
+// ============================================================================================== //
+// [SQLite]                                                                                       //
+// ============================================================================================== //
+
+/// An `INTEGER` column of nanoseconds since the epoch.
+impl Type<Sqlite> for Timestamp {
+    fn type_info() -> sqlx::sqlite::SqliteTypeInfo {
+        <i64 as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &sqlx::sqlite::SqliteTypeInfo) -> bool {
+        <i64 as Type<Sqlite>>::compatible(ty)
+    }
+}
+
+/// Fails with [`TimeError::Overflow`] past `2262-04-11`, the end of SQLite's `i64` range.
+impl<'q> Encode<'q, Sqlite> for Timestamp {
+    fn encode_by_ref(
+        &self,
+        buf: &mut Vec<sqlx::sqlite::SqliteArgumentValue<'q>>,
+    ) -> Result<IsNull, BoxDynError> {
+        let nanos = i64::try_from(self.0).map_err(|_| TimeError::Overflow)?;
+        <i64 as Encode<Sqlite>>::encode(nanos, buf)
+    }
+}
+
+/// Fails with [`TimeError::BeforeEpoch`] for negative values.
+impl Decode<'_, Sqlite> for Timestamp {
+    fn decode(value: sqlx::sqlite::SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+        let nanos = <i64 as Decode<Sqlite>>::decode(value)?;
+        Ok(u64::try_from(nanos).map(Timestamp).map_err(|_| TimeError::BeforeEpoch)?)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use sqlx::{Encode, MySql, Postgres, Sqlite, Type, TypeInfo};
+
+    #[test]
+    fn sqlx_types() {
+        assert_eq!(<Timestamp as Type<Postgres>>::type_info().name(), "TIMESTAMPTZ");
+        assert_eq!(<Timestamp as Type<MySql>>::type_info().name(), "DATETIME");
+        assert_eq!(<Timestamp as Type<Sqlite>>::type_info().name(), "INTEGER");
+    }
+
+    #[test]
+    fn sqlx_encode() {
+        let ts = Timestamp::from_nanoseconds(1_714_570_200_123_456_789);
+
+        // Microseconds since 2000-01-01.
+        let mut pg = sqlx::postgres::PgArgumentBuffer::default();
+        assert!(Encode::<Postgres>::encode_by_ref(&ts, &mut pg).is_ok());
+        assert_eq!(pg[..], (767_885_400_123_456i64).to_be_bytes());
+        assert!(Encode::<Postgres>::encode_by_ref(&Timestamp::MAX, &mut pg).is_err());
+
+        let mut sqlite = Vec::new();
+        assert!(Encode::<Sqlite>::encode_by_ref(&ts, &mut sqlite).is_ok());
+        let nanos = 1_714_570_200_123_456_789;
+        assert!(matches!(sqlite[..], [sqlx::sqlite::SqliteArgumentValue::Int64(n)] if n == nanos));
+        assert!(Encode::<Sqlite>::encode_by_ref(&Timestamp::MAX, &mut sqlite).is_err());
+    }
+}
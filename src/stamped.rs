@@ -0,0 +1,95 @@
+//! Values tagged with the time they were observed at.
+
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+use crate::Timestamp;
+
+// ============================================================================================== //
+// [Stamped]                                                                                      //
+// ============================================================================================== //
+
+/// A value together with the timestamp it belongs to.
+///
+/// Ordering compares the timestamps first and the values only to break ties, so sorting a
+/// `Vec<Stamped<T>>` puts it into time order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+pub struct Stamped<T> {
+    pub at: Timestamp,
+    pub value: T,
+}
+
+impl<T> Stamped<T> {
+    /// Tag `value` with the given timestamp.
+    #[inline]
+    pub const fn new(at: Timestamp, value: T) -> Self {
+        Stamped { at, value }
+    }
+
+    /// Tag `value` with [`Timestamp::now`].
+    #[inline]
+    pub fn now(value: T) -> Self {
+        Stamped { at: Timestamp::now(), value }
+    }
+
+    /// Transform the value, keeping the timestamp.
+    #[inline]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Stamped<U> {
+        Stamped { at: self.at, value: f(self.value) }
+    }
+
+    /// Borrow the value, keeping the timestamp.
+    #[inline]
+    pub const fn as_ref(&self) -> Stamped<&T> {
+        Stamped { at: self.at, value: &self.value }
+    }
+
+    /// Split into timestamp and value.
+    #[inline]
+    pub fn into_parts(self) -> (Timestamp, T) {
+        (self.at, self.value)
+    }
+}
+
+impl<T> From<(Timestamp, T)> for Stamped<T> {
+    fn from((at, value): (Timestamp, T)) -> Self {
+        Stamped { at, value }
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn stamped_orders_by_time() {
+        let mut events = [
+            Stamped::new(Timestamp::from_seconds(3), "c"),
+            Stamped::new(Timestamp::from_seconds(1), "z"),
+            Stamped::new(Timestamp::from_seconds(2), "a"),
+        ];
+        events.sort();
+
+        let values: Vec<_> = events.iter().map(|e| e.value).collect();
+        assert_eq!(values, ["z", "a", "c"]);
+        assert_eq!(events[0].map(str::len), Stamped::new(Timestamp::from_seconds(1), 1));
+        assert_eq!(Stamped::from((Timestamp::zero(), 5)).into_parts(), (Timestamp::zero(), 5));
+    }
+
+    #[cfg(feature = "serde-support")]
+    #[test]
+    fn stamped_serde() {
+        let event = Stamped::new(Timestamp::from_seconds(1), 42u8);
+        let json = serde_json::to_string(&event).unwrap();
+        #[cfg(not(feature = "serde-nanos"))]
+        assert_eq!(json, r#"{"at":"1970-01-01T00:00:01.000000000Z","value":42}"#);
+        #[cfg(feature = "serde-nanos")]
+        assert_eq!(json, r#"{"at":1000000000,"value":42}"#);
+        assert_eq!(serde_json::from_str::<Stamped<u8>>(&json).unwrap(), event);
+    }
+}
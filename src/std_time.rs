@@ -0,0 +1,116 @@
+//! Conversions between the crate's types and std's `Duration` and `SystemTime`.
+//!
+//! `Duration` lives in `core`, so its conversions are available without the `std` feature.
+
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{TimeDelta, TimeError, Timestamp};
+
+// ============================================================================================== //
+// [Duration]                                                                                     //
+// ============================================================================================== //
+
+/// Fails with [`TimeError::Overflow`] for durations longer than about 292 years.
+impl TryFrom<Duration> for TimeDelta {
+    type Error = TimeError;
+
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        i64::try_from(duration.as_nanos()).map(TimeDelta).map_err(|_| TimeError::Overflow)
+    }
+}
+
+/// Fails with [`TimeError::Negative`] for negative deltas.
+impl TryFrom<TimeDelta> for Duration {
+    type Error = TimeError;
+
+    fn try_from(delta: TimeDelta) -> Result<Self, Self::Error> {
+        u64::try_from(delta.0).map(Duration::from_nanos).map_err(|_| TimeError::Negative)
+    }
+}
+
+/// Interpret a duration as the offset from the epoch, e.g. `UNIX_EPOCH.elapsed()`. Saturates
+/// at [`Timestamp::MAX`].
+impl From<Duration> for Timestamp {
+    fn from(since_epoch: Duration) -> Self {
+        Timestamp(since_epoch.as_nanos().min(u64::MAX as u128) as u64)
+    }
+}
+
+/// The offset of the timestamp from the epoch.
+impl From<Timestamp> for Duration {
+    fn from(ts: Timestamp) -> Self {
+        Duration::from_nanos(ts.0)
+    }
+}
+
+// ============================================================================================== //
+// [SystemTime]                                                                                   //
+// ============================================================================================== //
+
+#[cfg(feature = "std")]
+impl Timestamp {
+    /// Read the current time from `SystemTime::now()`, bypassing chrono and the coarse clock.
+    #[inline]
+    pub fn now_system() -> Self {
+        Self::from(SystemTime::now())
+    }
+}
+
+/// Clamps times before the epoch to the epoch and times past [`Timestamp::MAX`] to it.
+#[cfg(feature = "std")]
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Self {
+        time.duration_since(UNIX_EPOCH).map_or(Timestamp(0), Timestamp::from)
+    }
+}
+
+/// Fails with [`TimeError::Overflow`] if the platform's `SystemTime` can't hold the timestamp.
+#[cfg(feature = "std")]
+impl TryFrom<Timestamp> for SystemTime {
+    type Error = TimeError;
+
+    fn try_from(ts: Timestamp) -> Result<Self, Self::Error> {
+        UNIX_EPOCH.checked_add(Duration::from(ts)).ok_or(TimeError::Overflow)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use core::time::Duration;
+
+    #[test]
+    fn duration_conversions() {
+        let delta = TimeDelta::from_milliseconds(1_500);
+        assert_eq!(TimeDelta::try_from(Duration::from_millis(1_500)), Ok(delta));
+        assert_eq!(TimeDelta::try_from(Duration::from_secs(u64::MAX)), Err(TimeError::Overflow));
+        assert_eq!(Duration::try_from(TimeDelta::from_microseconds(7)), Ok(Duration::from_micros(7)));
+        assert_eq!(Duration::try_from(TimeDelta::zero()), Ok(Duration::ZERO));
+        assert_eq!(Duration::try_from(-TimeDelta::NANOSECOND), Err(TimeError::Negative));
+
+        let ts = Timestamp::from_nanoseconds(1_700_000_000_123_456_789);
+        assert_eq!(Timestamp::from(Duration::from(ts)), ts);
+        assert_eq!(Timestamp::from(Duration::from_secs(u64::MAX)), Timestamp::MAX);
+    }
+
+    #[cfg(feature = "chrono-support")]
+    #[test]
+    fn system_time_conversions() {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let ts = Timestamp::from_nanoseconds(1_700_000_000_123_456_789);
+        let time = SystemTime::try_from(ts).unwrap();
+        assert_eq!(time, UNIX_EPOCH + Duration::from_nanos(1_700_000_000_123_456_789));
+        assert_eq!(Timestamp::from(time), ts);
+        assert_eq!(Timestamp::from(UNIX_EPOCH - Duration::from_secs(1)), Timestamp::zero());
+
+        let chrono_now = Timestamp::from(chrono::Utc::now());
+        assert_ts_approx_eq!(Timestamp::now_system(), chrono_now, TimeDelta::from_seconds(1));
+    }
+}
@@ -0,0 +1,168 @@
+//! Measuring elapsed time and enforcing time budgets.
+
+use std::time::Instant;
+
+use crate::TimeDelta;
+
+// ============================================================================================== //
+// [Stopwatch]                                                                                    //
+// ============================================================================================== //
+
+/// Measures elapsed time using the monotonic clock, unaffected by wall clock adjustments.
+#[derive(Copy, Clone, Debug)]
+pub struct Stopwatch {
+    started: Instant,
+}
+
+impl Stopwatch {
+    /// Start measuring.
+    #[inline]
+    pub fn start() -> Self {
+        Stopwatch { started: Instant::now() }
+    }
+
+    /// Time elapsed since the stopwatch was started.
+    #[inline]
+    pub fn elapsed(&self) -> TimeDelta {
+        let nanos = self.started.elapsed().as_nanos();
+        TimeDelta(nanos.min(i64::MAX as u128) as i64)
+    }
+
+    /// Return the elapsed time and start over.
+    #[inline]
+    pub fn lap(&mut self) -> TimeDelta {
+        let elapsed = self.elapsed();
+        *self = Self::start();
+        elapsed
+    }
+}
+
+// ============================================================================================== //
+// [DurationBudget]                                                                               //
+// ============================================================================================== //
+
+/// A fixed amount of time that work is charged against, e.g. a job's CPU time quota.
+///
+/// ```
+/// use fast_utc::{DurationBudget, TimeDelta};
+///
+/// let mut budget = DurationBudget::new(TimeDelta::from_seconds(10));
+/// budget.consume(TimeDelta::from_seconds(4));
+/// {
+///     let _scope = budget.scope(); // Charged with the time until it's dropped.
+/// }
+/// assert!(budget.remaining() <= TimeDelta::from_seconds(6));
+/// assert!(!budget.is_exhausted());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DurationBudget {
+    total: TimeDelta,
+    consumed: TimeDelta,
+}
+
+impl DurationBudget {
+    /// A budget of `total` time, none of it consumed.
+    #[inline]
+    pub const fn new(total: TimeDelta) -> Self {
+        DurationBudget { total, consumed: TimeDelta(0) }
+    }
+
+    /// Charge `delta` against the budget. Returns whether the budget still has time left.
+    ///
+    /// Negative deltas are ignored.
+    pub fn consume(&mut self, delta: TimeDelta) -> bool {
+        self.consumed = TimeDelta(self.consumed.0.saturating_add(delta.0.max(0)));
+        !self.is_exhausted()
+    }
+
+    /// Charge the budget with the time until the returned guard is dropped.
+    pub fn scope(&mut self) -> BudgetScope<'_> {
+        BudgetScope { budget: self, stopwatch: Stopwatch::start() }
+    }
+
+    /// The budget's total time.
+    #[inline]
+    pub const fn total(&self) -> TimeDelta {
+        self.total
+    }
+
+    /// Time charged so far, which may exceed the total.
+    #[inline]
+    pub const fn consumed(&self) -> TimeDelta {
+        self.consumed
+    }
+
+    /// Time left, zero once the budget is exhausted.
+    #[inline]
+    pub fn remaining(&self) -> TimeDelta {
+        (self.total - self.consumed).max(TimeDelta::zero())
+    }
+
+    /// Check whether all of the budget was consumed.
+    #[inline]
+    pub fn is_exhausted(&self) -> bool {
+        self.consumed >= self.total
+    }
+}
+
+/// Guard returned by [`DurationBudget::scope`], charging the budget when dropped.
+#[derive(Debug)]
+pub struct BudgetScope<'a> {
+    budget: &'a mut DurationBudget,
+    stopwatch: Stopwatch,
+}
+
+impl BudgetScope<'_> {
+    /// Time elapsed within the scope so far.
+    pub fn elapsed(&self) -> TimeDelta {
+        self.stopwatch.elapsed()
+    }
+
+    /// Time left in the budget after charging the scope's elapsed time so far.
+    pub fn remaining(&self) -> TimeDelta {
+        (self.budget.remaining() - self.elapsed()).max(TimeDelta::zero())
+    }
+}
+
+impl Drop for BudgetScope<'_> {
+    fn drop(&mut self) {
+        self.budget.consume(self.stopwatch.elapsed());
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn budget_consume() {
+        let mut budget = DurationBudget::new(TimeDelta::from_seconds(10));
+
+        assert!(budget.consume(TimeDelta::from_seconds(6)));
+        assert!(budget.consume(TimeDelta::from_seconds(-6)));
+        assert_eq!(budget.remaining(), TimeDelta::from_seconds(4));
+        assert!(!budget.consume(TimeDelta::from_seconds(5)));
+        assert_eq!(budget.remaining(), TimeDelta::zero());
+        assert_eq!(budget.consumed(), TimeDelta::from_seconds(11));
+        assert!(budget.is_exhausted());
+    }
+
+    #[test]
+    fn budget_scope_charges_elapsed_time() {
+        let mut budget = DurationBudget::new(TimeDelta::from_seconds(10));
+        {
+            let scope = budget.scope();
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            assert!(scope.elapsed() >= TimeDelta::from_milliseconds(20));
+        }
+        assert!(budget.consumed() >= TimeDelta::from_milliseconds(20));
+        assert!(budget.consumed() < TimeDelta::from_seconds(10));
+
+        let mut stopwatch = Stopwatch::start();
+        assert!(stopwatch.lap() >= TimeDelta::zero());
+    }
+}
@@ -0,0 +1,266 @@
+//! strftime-style formatting and parsing with pre-parsed format strings.
+
+use core::{fmt, str::FromStr};
+
+use crate::civil::NANOS_PER_DAY;
+use crate::format::{take_digits, take_literal, write_digits, Fields};
+use crate::{TimeError, Timestamp};
+
+// ============================================================================================== //
+// [strftime]                                                                                     //
+// ============================================================================================== //
+
+/// A pre-parsed strftime-style format string, for formatting or parsing many timestamps the
+/// same way.
+///
+/// Supported specifiers, which produce and expect exactly the digits shown:
+///
+/// | Spec  | Example     | Description                                   |
+/// |-------|-------------|-----------------------------------------------|
+/// | `%Y`  | `2024`      | Year, four digits                             |
+/// | `%m`  | `05`        | Month, two digits                             |
+/// | `%d`  | `01`        | Day of the month, two digits                  |
+/// | `%H`  | `13`        | Hour, two digits                              |
+/// | `%M`  | `30`        | Minute, two digits                            |
+/// | `%S`  | `00`        | Second, two digits                            |
+/// | `%f`  | `500000000` | Nanoseconds of the second, nine digits        |
+/// | `%3f` | `500`       | Fraction of the second, also `%6f` and `%9f`  |
+/// | `%z`  | `+0000`     | UTC offset, always `+0000` when formatting    |
+/// | `%%`  | `%`         | A literal `%`                                 |
+///
+/// ```
+/// use fast_utc::{FormatSpec, Timestamp};
+///
+/// let spec = FormatSpec::new("%Y%m%d-%H%M%S.%3f").unwrap();
+/// let ts = Timestamp::from_milliseconds(1_714_570_200_500);
+/// assert_eq!(spec.format(ts), "20240501-133000.500");
+/// assert_eq!(ts.format("%d/%m/%Y %z").unwrap(), "01/05/2024 +0000");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FormatSpec {
+    items: Vec<Item>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Item {
+    Literal(Box<str>),
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    /// Leading digits of the nanoseconds.
+    Fraction(usize),
+    Offset,
+}
+
+impl FormatSpec {
+    /// Parse a format string. Fails with [`TimeError::InvalidFormat`] on unknown specifiers.
+    pub fn new(fmt: &str) -> Result<Self, TimeError> {
+        let mut items = Vec::new();
+        let mut literal = String::new();
+        let mut chars = fmt.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                literal.push(c);
+                continue;
+            }
+            let item = match chars.next().ok_or(TimeError::InvalidFormat)? {
+                '%' => {
+                    literal.push('%');
+                    continue;
+                }
+                'Y' => Item::Year,
+                'm' => Item::Month,
+                'd' => Item::Day,
+                'H' => Item::Hour,
+                'M' => Item::Minute,
+                'S' => Item::Second,
+                'f' => Item::Fraction(9),
+                'z' => Item::Offset,
+                digits @ ('3' | '6' | '9') if chars.next() == Some('f') => {
+                    Item::Fraction(digits as usize - '0' as usize)
+                }
+                _ => return Err(TimeError::InvalidFormat),
+            };
+            if !literal.is_empty() {
+                items.push(Item::Literal(core::mem::take(&mut literal).into()));
+            }
+            items.push(item);
+        }
+        if !literal.is_empty() {
+            items.push(Item::Literal(literal.into()));
+        }
+        Ok(FormatSpec { items })
+    }
+
+    /// Format `ts` into a new string.
+    pub fn format(&self, ts: Timestamp) -> String {
+        let mut out = String::new();
+        self.write(ts, &mut out).expect("writing to a String doesn't fail");
+        out
+    }
+
+    /// Like [`format`](Self::format), but appending to an existing writer.
+    pub fn write<W: fmt::Write>(&self, ts: Timestamp, w: &mut W) -> fmt::Result {
+        let (year, month, day) = ts.civil_date();
+        let nanos = ts.0 % NANOS_PER_DAY;
+        let secs = nanos / 1_000_000_000;
+
+        let mut buf = [0; 9];
+        for item in &self.items {
+            let (value, width) = match *item {
+                Item::Literal(ref s) => {
+                    w.write_str(s)?;
+                    continue;
+                }
+                Item::Offset => {
+                    w.write_str("+0000")?;
+                    continue;
+                }
+                Item::Year => (year as u64, 4),
+                Item::Month => (month as u64, 2),
+                Item::Day => (day as u64, 2),
+                Item::Hour => (secs / 3_600, 2),
+                Item::Minute => (secs / 60 % 60, 2),
+                Item::Second => (secs % 60, 2),
+                Item::Fraction(digits) => (nanos % 1_000_000_000 / 10u64.pow(9 - digits as u32), digits),
+            };
+            write_digits(&mut buf[..width], value);
+            w.write_str(core::str::from_utf8(&buf[..width]).expect("digits are ASCII"))?;
+        }
+        Ok(())
+    }
+}
+
+impl FormatSpec {
+    /// Parse `input` according to the format.
+    ///
+    /// Fields missing from the format default to `1970-01-01 00:00:00` and a UTC offset of zero.
+    /// Fails with [`TimeError::InvalidFormat`] if `input` doesn't match the format or denotes an
+    /// invalid date or time, and with [`TimeError::BeforeEpoch`] or [`TimeError::Overflow`] if
+    /// the time isn't representable.
+    pub fn parse(&self, input: &str) -> Result<Timestamp, TimeError> {
+        let mut fields = Fields::default();
+        let mut rest = input.as_bytes();
+        for item in &self.items {
+            let width = match *item {
+                Item::Literal(ref s) => {
+                    take_literal(&mut rest, s.as_bytes())?;
+                    continue;
+                }
+                Item::Offset => {
+                    let sign = match rest.first() {
+                        Some(b'+') => 1,
+                        Some(b'-') => -1,
+                        _ => return Err(TimeError::InvalidFormat),
+                    };
+                    rest = &rest[1..];
+                    let hhmm = take_digits(&mut rest, 4)? as i64;
+                    fields.offset_secs = sign * (hhmm / 100 * 3_600 + hhmm % 100 * 60);
+                    continue;
+                }
+                Item::Year => 4,
+                Item::Fraction(digits) => digits,
+                _ => 2,
+            };
+            let value = take_digits(&mut rest, width)?;
+            match *item {
+                Item::Year => fields.year = value as i64,
+                Item::Month => fields.month = value as u32,
+                Item::Day => fields.day = value as u32,
+                Item::Hour => fields.hour = value,
+                Item::Minute => fields.minute = value,
+                Item::Second => fields.second = value,
+                Item::Fraction(digits) => fields.nanos = value * 10u64.pow(9 - digits as u32),
+                Item::Literal(_) | Item::Offset => unreachable!(),
+            }
+        }
+
+        if !rest.is_empty() {
+            return Err(TimeError::InvalidFormat);
+        }
+        fields.to_timestamp()
+    }
+}
+
+impl FromStr for FormatSpec {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl Timestamp {
+    /// Format with a strftime-style format string, see [`FormatSpec`] for the specifiers.
+    ///
+    /// Parses `fmt` on every call, prefer a [`FormatSpec`] to format many timestamps.
+    pub fn format(&self, fmt: &str) -> Result<String, TimeError> {
+        Ok(FormatSpec::new(fmt)?.format(*self))
+    }
+
+    /// Parse `input` with a strftime-style format string, see [`FormatSpec::parse`].
+    ///
+    /// Parses `fmt` on every call, prefer a [`FormatSpec`] to parse many timestamps.
+    pub fn parse_with(input: &str, fmt: &str) -> Result<Timestamp, TimeError> {
+        FormatSpec::new(fmt)?.parse(input)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[cfg(feature = "chrono-support")]
+    #[test]
+    fn format_spec_matches_chrono() {
+        let fmt = "%Y-%m-%d %H:%M:%S.%f %z %%|%3f|%6f|%9f";
+        let spec: FormatSpec = fmt.parse().unwrap();
+        for i in 0..1_000u64 {
+            let ts = Timestamp::from_nanoseconds(i.wrapping_mul(0x9e37_79b9_7f4a_7c15) % (i64::MAX as u64));
+            let dt = chrono::DateTime::<chrono::Utc>::from(ts);
+            assert_eq!(spec.format(ts), dt.format(fmt).to_string());
+        }
+
+        assert_eq!(Timestamp::zero().format("").unwrap(), "");
+        assert_eq!(Timestamp::zero().format("día %d").unwrap(), "día 01");
+        for invalid in ["%", "%q", "%4f", "%3", "abc%"] {
+            assert_eq!(FormatSpec::new(invalid), Err(TimeError::InvalidFormat), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn format_spec_parse() {
+        let spec = FormatSpec::new("%Y-%m-%d %H:%M:%S.%f %z").unwrap();
+        for i in 0..1_000u64 {
+            let ts = Timestamp::from_nanoseconds(i.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+            assert_eq!(spec.parse(&spec.format(ts)), Ok(ts));
+        }
+
+        let expected = Timestamp::from_milliseconds(1_714_570_200_500);
+        let input = "01/05/2024 09:30:00.500 -0400";
+        assert_eq!(Timestamp::parse_with(input, "%d/%m/%Y %H:%M:%S.%3f %z"), Ok(expected));
+        assert_eq!(Timestamp::parse_with("20240501", "%Y%m%d"), Ok(Timestamp::from_epoch_days(19_844)));
+        assert_eq!(Timestamp::parse_with("01:00", "%H:%M"), Ok(Timestamp::from_seconds(3_600)));
+
+        assert_eq!(Timestamp::parse_with("00:30 +0100", "%H:%M %z"), Err(TimeError::BeforeEpoch));
+        assert_eq!(Timestamp::parse_with("2555-01-01", "%Y-%m-%d"), Err(TimeError::Overflow));
+        let invalid = [
+            ("2024-5-01", "%Y-%m-%d"),
+            ("2023-02-29", "%Y-%m-%d"),
+            ("24:00", "%H:%M"),
+            ("12:00 0100", "%H:%M %z"),
+            ("2024x", "%Y"),
+            ("", "%Y"),
+        ];
+        for (input, fmt) in invalid {
+            assert_eq!(Timestamp::parse_with(input, fmt), Err(TimeError::InvalidFormat), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Timestamps with an error bound, for ordering events across machines with imperfect clocks.
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::{TimeDelta, Timestamp};
+
+// ============================================================================================== //
+// [UncertainTimestamp]                                                                           //
+// ============================================================================================== //
+
+/// The error bound of the local clock in nanoseconds, see [`UncertainTimestamp::set_error_bound`].
+static ERROR_BOUND: AtomicU64 = AtomicU64::new(UncertainTimestamp::DEFAULT_ERROR_BOUND.0 as u64);
+
+/// A time known to lie within `[earliest, latest]`, in the style of Spanner's TrueTime.
+///
+/// Two uncertain timestamps are only ordered if their intervals don't overlap. For commit wait,
+/// a transaction assigned `UncertainTimestamp::now().latest()` waits until
+/// [`definitely_after`](Self::definitely_after) holds for a later reading before acknowledging.
+///
+/// ```
+/// use fast_utc::{TimeDelta, Timestamp, UncertainTimestamp};
+///
+/// let ms = TimeDelta::from_milliseconds(1);
+/// let a = UncertainTimestamp::new(Timestamp::from_seconds(100), ms);
+/// let b = UncertainTimestamp::new(Timestamp::from_seconds(100) + ms, ms);
+/// assert!(!a.definitely_before(b));
+/// let c = UncertainTimestamp::new(Timestamp::from_seconds(101), ms);
+/// assert!(a.definitely_before(c) && c.definitely_after(a));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UncertainTimestamp {
+    earliest: Timestamp,
+    latest: Timestamp,
+}
+
+impl UncertainTimestamp {
+    /// The error bound until [`set_error_bound`](Self::set_error_bound) is called, 10 ms, a
+    /// conservative guess for a host synchronized with NTP.
+    pub const DEFAULT_ERROR_BOUND: TimeDelta = TimeDelta::from_milliseconds(10);
+
+    /// Create the interval of `error` around `ts`, either sign of `error` meaning the same and
+    /// the bounds saturating at the range of [`Timestamp`].
+    #[inline]
+    pub const fn new(ts: Timestamp, error: TimeDelta) -> Self {
+        let error = error.abs();
+        UncertainTimestamp { earliest: ts.saturating_sub(error), latest: ts.saturating_add(error) }
+    }
+
+    /// Read the current time with the process wide error bound.
+    pub fn now() -> Self {
+        Self::new(Timestamp::now(), Self::error_bound())
+    }
+
+    /// Set how far the local clock may be off from true time for the whole process, e.g. from the
+    /// estimate of an NTP or PTP daemon.
+    pub fn set_error_bound(error: TimeDelta) {
+        ERROR_BOUND.store(error.abs().0 as u64, Ordering::Relaxed);
+    }
+
+    /// The current process wide error bound.
+    pub fn error_bound() -> TimeDelta {
+        TimeDelta(ERROR_BOUND.load(Ordering::Relaxed) as i64)
+    }
+
+    /// The earliest the time could be.
+    #[inline]
+    pub const fn earliest(self) -> Timestamp {
+        self.earliest
+    }
+
+    /// The latest the time could be.
+    #[inline]
+    pub const fn latest(self) -> Timestamp {
+        self.latest
+    }
+
+    /// Check whether `ts` lies within the bounds.
+    #[inline]
+    pub const fn contains(self, ts: Timestamp) -> bool {
+        self.earliest.0 <= ts.0 && ts.0 <= self.latest.0
+    }
+
+    /// Check whether this time certainly precedes `other`, i.e. the intervals don't overlap.
+    #[inline]
+    pub const fn definitely_before(self, other: UncertainTimestamp) -> bool {
+        self.latest.0 < other.earliest.0
+    }
+
+    /// Check whether this time certainly follows `other`, i.e. the intervals don't overlap.
+    #[inline]
+    pub const fn definitely_after(self, other: UncertainTimestamp) -> bool {
+        other.definitely_before(self)
+    }
+}
+
+impl From<Timestamp> for UncertainTimestamp {
+    /// A time known exactly.
+    #[inline]
+    fn from(ts: Timestamp) -> Self {
+        UncertainTimestamp { earliest: ts, latest: ts }
+    }
+}
+
+impl Timestamp {
+    /// Read the current time as `(earliest, latest)` with the error bound set by
+    /// [`UncertainTimestamp::set_error_bound`].
+    pub fn now_with_bound() -> (Timestamp, Timestamp) {
+        let now = UncertainTimestamp::now();
+        (now.earliest, now.latest)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn uncertain_ordering() {
+        let ts = Timestamp::from_seconds(1_714_570_200);
+        let ms = TimeDelta::from_milliseconds(1);
+        let a = UncertainTimestamp::new(ts, -ms);
+        assert_eq!((a.earliest(), a.latest()), (ts - ms, ts + ms));
+        assert!(a.contains(ts) && a.contains(ts + ms) && !a.contains(ts - ms * 2));
+
+        let touching = UncertainTimestamp::new(ts + ms * 2, ms);
+        assert!(!a.definitely_before(touching) && !touching.definitely_after(a));
+        let apart = UncertainTimestamp::from(ts + ms * 2 + TimeDelta::from_nanoseconds(1));
+        assert!(a.definitely_before(apart) && apart.definitely_after(a));
+        assert!(!apart.definitely_before(a));
+
+        let edge = UncertainTimestamp::new(Timestamp::zero(), ms);
+        assert_eq!((edge.earliest(), edge.latest()), (Timestamp::zero(), Timestamp::zero() + ms));
+    }
+
+    #[test]
+    fn now_with_bound() {
+        assert_eq!(UncertainTimestamp::error_bound(), UncertainTimestamp::DEFAULT_ERROR_BOUND);
+        UncertainTimestamp::set_error_bound(TimeDelta::from_seconds(-1));
+        assert_eq!(UncertainTimestamp::error_bound(), TimeDelta::from_seconds(1));
+        let (earliest, latest) = Timestamp::now_with_bound();
+        assert!(earliest <= latest && latest - earliest <= TimeDelta::from_seconds(2));
+        UncertainTimestamp::set_error_bound(UncertainTimestamp::DEFAULT_ERROR_BOUND);
+    }
+}
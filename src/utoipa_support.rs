@@ -0,0 +1,100 @@
+//! OpenAPI schemas of the serde representations of timestamps and deltas in JSON.
+
+use utoipa::openapi::schema::{KnownFormat, ObjectBuilder, Schema, SchemaFormat, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::{TimeDelta, Timestamp};
+
+/// A `date-time` string, or with `serde-nanos` an `int64` of nanoseconds since the epoch, as
+/// serialized by serde to JSON.
+impl PartialSchema for Timestamp {
+    #[cfg(not(feature = "serde-nanos"))]
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::DateTime)))
+            .description(Some("RFC 3339 date time, written in UTC with nanoseconds"))
+            .into()
+    }
+
+    #[cfg(feature = "serde-nanos")]
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::Integer)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+            .minimum(Some(0))
+            .description(Some("Nanoseconds since 1970-01-01T00:00:00Z"))
+            .into()
+    }
+}
+
+impl ToSchema for Timestamp {}
+
+/// A signed `int64` of nanoseconds, as serialized by serde.
+impl PartialSchema for TimeDelta {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::Integer)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+            .description(Some("Signed duration in nanoseconds"))
+            .into()
+    }
+}
+
+impl ToSchema for TimeDelta {}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use utoipa::{PartialSchema, ToSchema};
+
+    #[test]
+    fn openapi_schemas() {
+        assert_eq!(Timestamp::name(), "Timestamp");
+        let schema = serde_json::to_value(Timestamp::schema()).unwrap();
+        let expected = if cfg!(feature = "serde-nanos") {
+            serde_json::json!({
+                "type": "integer",
+                "format": "int64",
+                "minimum": 0,
+                "description": "Nanoseconds since 1970-01-01T00:00:00Z",
+            })
+        } else {
+            serde_json::json!({
+                "type": "string",
+                "format": "date-time",
+                "description": "RFC 3339 date time, written in UTC with nanoseconds",
+            })
+        };
+        assert_eq!(schema, expected);
+
+        assert_eq!(TimeDelta::name(), "TimeDelta");
+        let schema = serde_json::to_value(TimeDelta::schema()).unwrap();
+        assert_eq!(schema["format"], "int64");
+        assert_eq!(schema.get("minimum"), None);
+    }
+
+    #[test]
+    fn openapi_derived_fields() {
+        #[allow(dead_code)]
+        #[derive(ToSchema)]
+        struct Tick {
+            at: Timestamp,
+            latency: Option<TimeDelta>,
+        }
+
+        let schema = serde_json::to_value(Tick::schema()).unwrap();
+        assert_eq!(schema["properties"]["at"]["$ref"], "#/components/schemas/Timestamp");
+        assert_eq!(schema["required"], serde_json::json!(["at"]));
+
+        let mut components = Vec::new();
+        Tick::schemas(&mut components);
+        let names: Vec<_> = components.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["Timestamp", "TimeDelta"]);
+    }
+}
@@ -0,0 +1,137 @@
+//! Checking series of timestamps against ingest rules in a single pass.
+
+use std::collections::HashSet;
+
+use crate::{TimeDelta, Timestamp};
+
+// ============================================================================================== //
+// [Validation]                                                                                   //
+// ============================================================================================== //
+
+/// Rules for [`validate_series`]. The default enables no checks.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ValidationRules {
+    /// Report timestamps before this one.
+    pub min: Option<Timestamp>,
+    /// Report timestamps after this one.
+    pub max: Option<Timestamp>,
+    /// Report consecutive timestamps further apart than this.
+    pub max_gap: Option<TimeDelta>,
+    /// Report timestamps earlier than their predecessor.
+    pub monotonic: bool,
+    /// Report timestamps that already occurred earlier in the series.
+    pub duplicates: bool,
+}
+
+/// Violations found by [`validate_series`], as indices into the series in ascending order.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ValidationReport {
+    /// Timestamps before [`ValidationRules::min`].
+    pub below_min: Vec<usize>,
+    /// Timestamps after [`ValidationRules::max`].
+    pub above_max: Vec<usize>,
+    /// Timestamps more than [`ValidationRules::max_gap`] after their predecessor.
+    pub gaps: Vec<usize>,
+    /// Timestamps earlier than their predecessor.
+    pub out_of_order: Vec<usize>,
+    /// Repetitions of an earlier timestamp, not counting the first occurrence.
+    pub duplicates: Vec<usize>,
+}
+
+impl ValidationReport {
+    /// Check whether no violations were found.
+    pub fn is_valid(&self) -> bool {
+        self.violation_count() == 0
+    }
+
+    /// Total number of violations. A timestamp breaking several rules is counted once per rule.
+    pub fn violation_count(&self) -> usize {
+        self.below_min.len()
+            + self.above_max.len()
+            + self.gaps.len()
+            + self.out_of_order.len()
+            + self.duplicates.len()
+    }
+}
+
+/// Check `series` against all enabled `rules` in one pass.
+///
+/// ```
+/// use fast_utc::{validate_series, TimeDelta, Timestamp, ValidationRules};
+///
+/// let series = [0, 10, 10, 5, 60].map(Timestamp::from_seconds);
+/// let rules = ValidationRules {
+///     max_gap: Some(TimeDelta::from_seconds(30)),
+///     monotonic: true,
+///     duplicates: true,
+///     ..Default::default()
+/// };
+///
+/// let report = validate_series(&series, rules);
+/// assert_eq!(report.duplicates, [2]);
+/// assert_eq!(report.out_of_order, [3]);
+/// assert_eq!(report.gaps, [4]);
+/// assert_eq!(report.violation_count(), 3);
+/// ```
+pub fn validate_series(series: &[Timestamp], rules: ValidationRules) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    let mut seen = HashSet::new();
+
+    for (i, &ts) in series.iter().enumerate() {
+        if rules.min.is_some_and(|min| ts < min) {
+            report.below_min.push(i);
+        }
+        if rules.max.is_some_and(|max| ts > max) {
+            report.above_max.push(i);
+        }
+        if rules.duplicates && !seen.insert(ts) {
+            report.duplicates.push(i);
+        }
+
+        let Some(&prev) = i.checked_sub(1).and_then(|p| series.get(p)) else {
+            continue;
+        };
+        if rules.monotonic && ts < prev {
+            report.out_of_order.push(i);
+        }
+        if let Some(max_gap) = rules.max_gap {
+            // Gaps larger than any `TimeDelta` still count.
+            if ts > prev && ts.checked_duration_since(prev).is_none_or(|gap| gap > max_gap) {
+                report.gaps.push(i);
+            }
+        }
+    }
+    report
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn all_rules() {
+        let series = [100, 101, 101, 150, 99, 120, 300, 101].map(Timestamp::from_seconds);
+        let rules = ValidationRules {
+            min: Some(Timestamp::from_seconds(100)),
+            max: Some(Timestamp::from_seconds(200)),
+            max_gap: Some(TimeDelta::from_seconds(40)),
+            monotonic: true,
+            duplicates: true,
+        };
+
+        let report = validate_series(&series, rules);
+        assert_eq!(report.below_min, [4]);
+        assert_eq!(report.above_max, [6]);
+        assert_eq!(report.gaps, [3, 6]);
+        assert_eq!(report.out_of_order, [4, 7]);
+        assert_eq!(report.duplicates, [2, 7]);
+        assert_eq!(report.violation_count(), 8);
+
+        assert!(validate_series(&series, ValidationRules::default()).is_valid());
+        assert_eq!(validate_series(&[Timestamp::zero(), Timestamp::MAX], rules).gaps, [1]);
+    }
+}
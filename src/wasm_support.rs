@@ -0,0 +1,111 @@
+//! Conversions between timestamps and JavaScript `Date` objects, and the methods of the
+//! `Timestamp` class exported to JavaScript.
+
+use js_sys::Date;
+use wasm_bindgen::prelude::*;
+
+use crate::{TimeError, Timestamp};
+
+/// Convert milliseconds since the epoch as JavaScript numbers them, failing for `NaN`, the
+/// value of invalid dates.
+fn from_millis_f64(millis: f64) -> Result<Timestamp, TimeError> {
+    if millis.is_nan() {
+        return Err(TimeError::InvalidFormat);
+    }
+    if millis < 0.0 {
+        return Err(TimeError::BeforeEpoch);
+    }
+    // JavaScript dates are whole milliseconds, within `u64` once checked for sign.
+    Timestamp::try_from_milliseconds(millis as u64)
+}
+
+/// Convert to a date, truncating to the milliseconds JavaScript can represent.
+impl From<Timestamp> for Date {
+    fn from(ts: Timestamp) -> Self {
+        Date::new(&JsValue::from_f64(ts.as_milliseconds() as f64))
+    }
+}
+
+/// Convert a date, failing with [`TimeError::InvalidFormat`] for invalid dates, with
+/// [`TimeError::BeforeEpoch`] for dates before 1970 and with [`TimeError::Overflow`] past
+/// `2554-07-21`.
+impl TryFrom<&Date> for Timestamp {
+    type Error = TimeError;
+
+    fn try_from(date: &Date) -> Result<Self, Self::Error> {
+        from_millis_f64(date.get_time())
+    }
+}
+
+impl TryFrom<Date> for Timestamp {
+    type Error = TimeError;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        Self::try_from(&date)
+    }
+}
+
+#[wasm_bindgen]
+impl Timestamp {
+    /// Convert a JavaScript date, throwing for invalid dates or dates before 1970.
+    #[wasm_bindgen(js_name = fromDate)]
+    pub fn from_js_date(date: &Date) -> Result<Timestamp, JsError> {
+        Ok(Self::try_from(date)?)
+    }
+
+    /// Convert milliseconds since the epoch, e.g. from `Date.now()`.
+    #[wasm_bindgen(js_name = fromMillis)]
+    pub fn from_js_millis(millis: f64) -> Result<Timestamp, JsError> {
+        Ok(from_millis_f64(millis)?)
+    }
+
+    /// Convert to a JavaScript date, truncated to milliseconds.
+    #[wasm_bindgen(js_name = toDate)]
+    pub fn to_js_date(&self) -> Date {
+        (*self).into()
+    }
+
+    /// Milliseconds since the epoch, for arithmetic with other JavaScript times.
+    #[wasm_bindgen(getter, js_name = epochMillis)]
+    pub fn js_epoch_millis(&self) -> f64 {
+        self.as_milliseconds() as f64
+    }
+
+    /// Nanoseconds since the epoch as a `BigInt`, the full precision of the timestamp.
+    #[wasm_bindgen(getter, js_name = epochNanos)]
+    pub fn js_epoch_nanos(&self) -> u64 {
+        self.as_nanoseconds()
+    }
+
+    /// Format as RFC 3339 with nanoseconds, e.g. `2024-05-01T13:30:00.500000000Z`.
+    #[wasm_bindgen(js_name = toISOString)]
+    pub fn to_js_iso_string(&self) -> String {
+        self.to_rfc3339()
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use super::from_millis_f64;
+    use crate::*;
+
+    #[test]
+    fn js_millis() {
+        let ts = Timestamp::from_js_millis(1_714_570_200_123.0).unwrap();
+        assert_eq!(ts, Timestamp::from_milliseconds(1_714_570_200_123));
+        assert_eq!(ts.js_epoch_millis(), 1_714_570_200_123.0);
+        assert_eq!(ts.js_epoch_nanos(), 1_714_570_200_123_000_000);
+
+        let ts = ts + TimeDelta::from_microseconds(999);
+        assert_eq!(ts.js_epoch_millis(), 1_714_570_200_123.0);
+        assert_eq!(ts.to_js_iso_string(), "2024-05-01T13:30:00.123999000Z");
+
+        assert_eq!(from_millis_f64(f64::NAN), Err(TimeError::InvalidFormat));
+        assert_eq!(from_millis_f64(-1.0), Err(TimeError::BeforeEpoch));
+        assert_eq!(from_millis_f64(8.64e15), Err(TimeError::Overflow));
+    }
+}
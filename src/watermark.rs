@@ -0,0 +1,199 @@
+//! Tracking the minimum progress of concurrent producers.
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::{AtomicTimestamp, Timestamp};
+
+// ============================================================================================== //
+// [LowWatermark]                                                                                 //
+// ============================================================================================== //
+
+/// The global minimum of the progress of up to `capacity` concurrent producers.
+///
+/// Each producer registers for a slot and publishes the timestamp up to which it has completed
+/// its work. Consumers read the minimum over all slots, i.e. the time up to which every
+/// producer is done, e.g. to decide what can be checkpointed. Publishing is a single atomic
+/// operation and reading scans the slots without locking.
+///
+/// Every slot carries an epoch counter that is bumped on registration and release, so the
+/// holder of a [`WatermarkSlot`] can tell registrations of the same slot apart. Free slots hold
+/// [`Timestamp::MAX`] and thus never hold back the watermark.
+///
+/// ```
+/// use fast_utc::{LowWatermark, Timestamp};
+///
+/// let watermark = LowWatermark::new(8);
+/// let a = watermark.register(Timestamp::from_seconds(10)).unwrap();
+/// let b = watermark.register(Timestamp::from_seconds(10)).unwrap();
+///
+/// a.publish(Timestamp::from_seconds(30));
+/// b.publish(Timestamp::from_seconds(20));
+/// assert_eq!(watermark.get(), Some(Timestamp::from_seconds(20)));
+///
+/// drop(b);
+/// assert_eq!(watermark.get(), Some(Timestamp::from_seconds(30)));
+/// ```
+#[derive(Debug)]
+pub struct LowWatermark {
+    slots: Box<[Slot]>,
+}
+
+#[derive(Debug)]
+struct Slot {
+    progress: AtomicTimestamp,
+    /// Incremented on registration and release: odd while the slot is taken.
+    epoch: AtomicU64,
+}
+
+impl LowWatermark {
+    /// Create a watermark with room for `capacity` producers.
+    pub fn new(capacity: usize) -> Self {
+        let slots = (0..capacity)
+            .map(|_| Slot { progress: AtomicTimestamp::new(Timestamp::MAX), epoch: AtomicU64::new(0) })
+            .collect();
+        LowWatermark { slots }
+    }
+
+    /// Maximum number of concurrently registered producers.
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Register a producer starting at `start`, `None` if all slots are taken.
+    ///
+    /// To keep the watermark from moving backwards, `start` shouldn't be earlier than the
+    /// current [`get`](Self::get).
+    pub fn register(&self, start: Timestamp) -> Option<WatermarkSlot<'_>> {
+        for (index, slot) in self.slots.iter().enumerate() {
+            let epoch = slot.epoch.load(Ordering::Relaxed);
+            if epoch % 2 == 1 {
+                continue;
+            }
+            if slot.epoch.compare_exchange(epoch, epoch + 1, Ordering::Acquire, Ordering::Relaxed).is_ok() {
+                slot.progress.store(start, Ordering::Release);
+                return Some(WatermarkSlot { watermark: self, index, epoch: epoch + 1 });
+            }
+        }
+        None
+    }
+
+    /// The minimum progress over all registered producers, `None` if there are none.
+    ///
+    /// Producers that have published [`Timestamp::MAX`] count as unregistered.
+    pub fn get(&self) -> Option<Timestamp> {
+        let min = self.slots.iter().map(|slot| slot.progress.load(Ordering::Acquire)).min()?;
+        (min != Timestamp::MAX).then_some(min)
+    }
+}
+
+/// A producer's registration with a [`LowWatermark`], released on drop.
+#[derive(Debug)]
+pub struct WatermarkSlot<'a> {
+    watermark: &'a LowWatermark,
+    index: usize,
+    epoch: u64,
+}
+
+impl WatermarkSlot<'_> {
+    /// Publish the producer's progress. Progress never moves backwards, earlier values are ignored.
+    #[inline]
+    pub fn publish(&self, progress: Timestamp) {
+        self.slot().progress.fetch_max(progress, Ordering::Release);
+    }
+
+    /// The progress last published by this producer.
+    #[inline]
+    pub fn progress(&self) -> Timestamp {
+        self.slot().progress.load(Ordering::Relaxed)
+    }
+
+    /// Position of the slot within the watermark.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The slot's epoch at registration, distinct for every registration of the same slot.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    fn slot(&self) -> &Slot {
+        &self.watermark.slots[self.index]
+    }
+}
+
+impl Drop for WatermarkSlot<'_> {
+    fn drop(&mut self) {
+        let slot = self.slot();
+        slot.progress.store(Timestamp::MAX, Ordering::Release);
+        slot.epoch.store(self.epoch + 1, Ordering::Release);
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn slot_reuse() {
+        let watermark = LowWatermark::new(2);
+        assert_eq!(watermark.get(), None);
+
+        let a = watermark.register(Timestamp::from_seconds(5)).unwrap();
+        let b = watermark.register(Timestamp::from_seconds(7)).unwrap();
+        assert!(watermark.register(Timestamp::zero()).is_none());
+        assert_eq!(watermark.get(), Some(Timestamp::from_seconds(5)));
+
+        a.publish(Timestamp::from_seconds(9));
+        a.publish(Timestamp::from_seconds(8));
+        assert_eq!(a.progress(), Timestamp::from_seconds(9));
+        assert_eq!(watermark.get(), Some(Timestamp::from_seconds(7)));
+
+        let (index, epoch) = (a.index(), a.epoch());
+        drop(a);
+        let c = watermark.register(Timestamp::from_seconds(8)).unwrap();
+        assert_eq!(c.index(), index);
+        assert_ne!(c.epoch(), epoch);
+        assert_eq!(c.progress(), Timestamp::from_seconds(8));
+
+        drop((b, c));
+        assert_eq!(watermark.get(), None);
+    }
+
+    #[test]
+    fn concurrent_producers() {
+        let watermark = LowWatermark::new(4);
+        // Register up front: a late registration at zero would move the watermark backwards.
+        let slots: Vec<_> = (0..4).map(|_| watermark.register(Timestamp::zero()).unwrap()).collect();
+
+        std::thread::scope(|s| {
+            let producers: Vec<_> = (1..=4u64)
+                .zip(slots)
+                .map(|(speed, slot)| {
+                    let watermark = &watermark;
+                    s.spawn(move || {
+                        for secs in 1..=1_000 {
+                            slot.publish(Timestamp::from_seconds(secs * speed));
+                            // Our own progress bounds the global minimum.
+                            assert!(watermark.get().unwrap() <= slot.progress());
+                        }
+                    })
+                })
+                .collect();
+
+            let mut last = Timestamp::zero();
+            while !producers.iter().all(|producer| producer.is_finished()) {
+                if let Some(current) = watermark.get() {
+                    assert!(current >= last, "{} < {}", current, last);
+                    last = current;
+                }
+                std::thread::yield_now();
+            }
+        });
+        assert_eq!(watermark.get(), None);
+    }
+}
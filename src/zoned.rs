@@ -0,0 +1,252 @@
+//! Formatting timestamps in a local time zone without resolving the offset on every call.
+
+use core::cell::Cell;
+use core::fmt;
+
+use chrono::{FixedOffset, Offset, TimeZone};
+
+use crate::civil::civil_from_days;
+use crate::Timestamp;
+
+/// How far around a refresh point the offset is assumed to have at most one transition.
+const PROBE_SECS: u64 = 24 * 60 * 60;
+
+/// The last whole second up to [`Timestamp::MAX`].
+const MAX_SECS: u64 = Timestamp::MAX.0 / 1_000_000_000;
+
+// ============================================================================================== //
+// [ZonedFormatter]                                                                               //
+// ============================================================================================== //
+
+/// Formats timestamps in a time zone, caching the offset between two transitions.
+///
+/// Resolving a zone's offset (e.g. through `chrono-tz`) dominates the cost of rendering local
+/// times. The formatter instead remembers the span of seconds around the last formatted
+/// timestamp in which the offset is constant and only consults the zone again once a timestamp
+/// falls outside of it. Transitions are located by bisection, assuming that a zone doesn't
+/// change its offset more than once per day, which holds for all real world zones.
+///
+/// ```
+/// use fast_utc::{Timestamp, ZonedFormatter};
+/// use chrono::FixedOffset;
+///
+/// let fmt = ZonedFormatter::new(FixedOffset::west_opt(4 * 3600).unwrap());
+/// let ts = Timestamp::from_seconds(1_714_570_200);
+/// assert_eq!(fmt.format(ts), "2024-05-01T09:30:00.000000000-04:00");
+/// ```
+#[derive(Debug)]
+pub struct ZonedFormatter<Tz: TimeZone> {
+    tz: Tz,
+    window: Cell<OffsetWindow>,
+}
+
+/// Half-open span `[start, end)` of seconds since the epoch sharing one UTC offset.
+#[derive(Copy, Clone, Debug)]
+struct OffsetWindow {
+    start: u64,
+    end: u64,
+    offset: i32,
+}
+
+impl<Tz: TimeZone> ZonedFormatter<Tz> {
+    /// Create a formatter for `tz`, precomputing the offset around the current time.
+    pub fn new(tz: Tz) -> Self {
+        let formatter = ZonedFormatter {
+            tz,
+            window: Cell::new(OffsetWindow { start: 0, end: 0, offset: 0 }),
+        };
+        formatter.offset_secs(Timestamp::now());
+        formatter
+    }
+
+    /// The time zone used for formatting.
+    pub fn time_zone(&self) -> &Tz {
+        &self.tz
+    }
+
+    /// UTC offset of the zone at the given timestamp.
+    pub fn offset(&self, ts: Timestamp) -> FixedOffset {
+        FixedOffset::east_opt(self.offset_secs(ts)).expect("zone offsets are less than a day")
+    }
+
+    /// Format as RFC 3339 in local time with nanosecond precision,
+    /// e.g. `2024-05-01T09:30:00.000000000-04:00`.
+    pub fn format(&self, ts: Timestamp) -> String {
+        let mut out = String::with_capacity(35);
+        self.write(ts, &mut out).expect("writing to a String doesn't fail");
+        out
+    }
+
+    /// Like [`format`](Self::format), but appending to an existing writer.
+    pub fn write<W: fmt::Write>(&self, ts: Timestamp, w: &mut W) -> fmt::Result {
+        let offset = self.offset_secs(ts);
+        let local_secs = (ts.0 / 1_000_000_000) as i64 + offset as i64;
+        let (year, month, day) = civil_from_days(local_secs.div_euclid(86_400));
+        let secs_of_day = local_secs.rem_euclid(86_400);
+        let (sign, offset_abs) = if offset < 0 { ('-', -offset) } else { ('+', offset) };
+
+        write!(
+            w,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}{}{:02}:{:02}",
+            year,
+            month,
+            day,
+            secs_of_day / 3_600,
+            secs_of_day / 60 % 60,
+            secs_of_day % 60,
+            ts.0 % 1_000_000_000,
+            sign,
+            offset_abs / 3_600,
+            offset_abs / 60 % 60,
+        )
+    }
+
+    /// Offset in seconds at `ts`, refreshing the cached window if `ts` lies outside of it.
+    fn offset_secs(&self, ts: Timestamp) -> i32 {
+        let secs = ts.0 / 1_000_000_000;
+        let window = self.window.get();
+        if window.start <= secs && secs < window.end {
+            return window.offset;
+        }
+
+        let window = self.resolve_window(secs);
+        self.window.set(window);
+        window.offset
+    }
+
+    /// Locate the transitions enclosing `secs`, looking at most `PROBE_SECS` in each direction.
+    fn resolve_window(&self, secs: u64) -> OffsetWindow {
+        let offset = self.offset_at(secs);
+
+        let hi = (secs + PROBE_SECS).min(MAX_SECS);
+        let end = if self.offset_at(hi) == offset {
+            hi + 1
+        } else {
+            // Invariant: `lo` has `offset`, `hi` doesn't.
+            let (mut lo, mut hi) = (secs, hi);
+            while hi - lo > 1 {
+                let mid = lo + (hi - lo) / 2;
+                if self.offset_at(mid) == offset { lo = mid } else { hi = mid }
+            }
+            hi
+        };
+
+        let lo = secs.saturating_sub(PROBE_SECS);
+        let start = if self.offset_at(lo) == offset {
+            lo
+        } else {
+            // Invariant: `lo` doesn't have `offset`, `hi` does.
+            let (mut lo, mut hi) = (lo, secs);
+            while hi - lo > 1 {
+                let mid = lo + (hi - lo) / 2;
+                if self.offset_at(mid) == offset { hi = mid } else { lo = mid }
+            }
+            hi
+        };
+
+        OffsetWindow { start, end, offset }
+    }
+
+    fn offset_at(&self, secs: u64) -> i32 {
+        // Not through `Timestamp`, whose chrono conversion stops at 2262.
+        let utc = chrono::DateTime::from_timestamp(secs as i64, 0).expect("chrono covers all timestamps");
+        self.tz.offset_from_utc_datetime(&utc.naive_utc()).fix().local_minus_utc()
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use chrono::{
+        offset::{LocalResult, TimeZone},
+        FixedOffset, NaiveDate, NaiveDateTime, NaiveTime,
+    };
+
+    /// Zone switching from UTC-5 to UTC-4 at a fixed instant.
+    #[derive(Clone, Debug)]
+    struct Stepped {
+        switch_at: i64,
+    }
+
+    impl Stepped {
+        fn offset_at(&self, utc_secs: i64) -> FixedOffset {
+            let hours = if utc_secs < self.switch_at { 5 } else { 4 };
+            FixedOffset::west_opt(hours * 3_600).unwrap()
+        }
+    }
+
+    impl TimeZone for Stepped {
+        type Offset = FixedOffset;
+
+        fn from_offset(_: &FixedOffset) -> Self {
+            Stepped { switch_at: 0 }
+        }
+
+        fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+            self.offset_from_local_datetime(&local.and_time(NaiveTime::MIN))
+        }
+
+        fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+            let local_secs = local.and_utc().timestamp();
+            let [before, after] = [5, 4].map(|hours| {
+                let offset = FixedOffset::west_opt(hours * 3_600).unwrap();
+                let utc_secs = local_secs - offset.local_minus_utc() as i64;
+                Some(offset).filter(|&offset| self.offset_at(utc_secs) == offset)
+            });
+            match (before, after) {
+                (Some(before), Some(after)) => LocalResult::Ambiguous(before, after),
+                (Some(offset), None) | (None, Some(offset)) => LocalResult::Single(offset),
+                (None, None) => LocalResult::None,
+            }
+        }
+
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+            self.offset_from_utc_datetime(&utc.and_time(NaiveTime::MIN))
+        }
+
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+            self.offset_at(utc.and_utc().timestamp())
+        }
+    }
+
+    #[test]
+    fn zoned_formatter_crosses_transition() {
+        // 2024-03-10 07:00:00 UTC, the 2024 US DST switch.
+        let switch_at = 1_710_054_000;
+        let fmt = ZonedFormatter::new(Stepped { switch_at });
+
+        let before = Timestamp::from_seconds(switch_at as u64 - 1);
+        let after = Timestamp::from_seconds(switch_at as u64);
+        assert_eq!(fmt.format(before), "2024-03-10T01:59:59.000000000-05:00");
+        assert_eq!(fmt.format(after), "2024-03-10T03:00:00.000000000-04:00");
+        assert_eq!(fmt.format(before - TimeDelta::from_hours(30)), "2024-03-08T19:59:59.000000000-05:00");
+        assert_eq!(fmt.offset(after + TimeDelta::from_hours(30)), FixedOffset::west_opt(4 * 3_600).unwrap());
+        assert_eq!(fmt.time_zone().with_ymd_and_hms(2024, 3, 10, 2, 30, 0), LocalResult::None);
+        assert_eq!(
+            fmt.time_zone().with_ymd_and_hms(2024, 3, 10, 1, 30, 0).map(|dt| dt.timestamp()),
+            LocalResult::Single(switch_at - 1_800),
+        );
+
+        // Every second around the switch agrees with a direct lookup.
+        for secs in switch_at - 100_000..switch_at + 100_000 {
+            let ts = Timestamp::from_seconds(secs as u64);
+            assert_eq!(fmt.offset(ts), fmt.time_zone().offset_at(secs), "secs = {}", secs);
+        }
+    }
+
+    #[test]
+    fn zoned_formatter_past_chrono_range() {
+        let fmt = ZonedFormatter::new(Stepped { switch_at: 1_710_054_000 });
+        assert_eq!(fmt.format(Timestamp::MAX), "2554-07-21T19:34:33.709551615-04:00");
+        let post_chrono = Timestamp::from_seconds(10_000_000_000);
+        assert_eq!(fmt.format(post_chrono), "2286-11-20T13:46:40.000000000-04:00");
+
+        let fmt = ZonedFormatter::new(FixedOffset::east_opt(3_600).unwrap());
+        assert_eq!(fmt.format(Timestamp::MAX), "2554-07-22T00:34:33.709551615+01:00");
+        assert_eq!(fmt.format(Timestamp::zero()), "1970-01-01T01:00:00.000000000+01:00");
+    }
+}
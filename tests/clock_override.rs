@@ -0,0 +1,43 @@
+//! The clock override is process wide, so these tests run in their own binary rather than next
+//! to the unit tests reading the real clock.
+
+#![cfg(feature = "clock-override")]
+
+use fast_utc::clock::{Clock, ClockSource, clear_global, freeze_time_at, set_global, set_source};
+use fast_utc::{TimeDelta, Timestamp};
+
+struct Epoch;
+
+impl Clock for Epoch {
+    fn now(&self) -> Timestamp {
+        Timestamp::zero()
+    }
+}
+
+fn reads_real_clock() -> bool {
+    (Timestamp::now() - Timestamp::now_system()).abs() < TimeDelta::from_seconds(1)
+}
+
+#[test]
+fn frozen_now() {
+    // Nothing updates the coarse clock in this binary.
+    set_source(ClockSource::Precise);
+
+    let ts = Timestamp::from_seconds(1_714_570_200);
+    {
+        let frozen = freeze_time_at(ts);
+        assert_eq!(Timestamp::now(), ts);
+        {
+            let _nested = freeze_time_at(Timestamp::zero());
+            assert_eq!(Timestamp::now(), Timestamp::zero());
+        }
+        assert_eq!(frozen.advance(TimeDelta::from_seconds(1)), ts + TimeDelta::from_seconds(1));
+        assert_eq!(Timestamp::now(), frozen.clock().now());
+    }
+    assert!(reads_real_clock());
+
+    set_global(&Epoch);
+    assert_eq!(Timestamp::now(), Timestamp::zero());
+    clear_global();
+    assert!(reads_real_clock());
+}
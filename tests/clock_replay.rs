@@ -0,0 +1,25 @@
+//! Replaying is process wide, so these tests run in their own binary rather than next to the
+//! unit tests reading the real clock.
+
+#![cfg(feature = "clock-replay")]
+
+use fast_utc::clock::{self, ClockLog};
+use fast_utc::Timestamp;
+
+#[test]
+fn record_encode_decode_replay() {
+    clock::start_recording();
+    let recorded: Vec<_> = (0..100).map(|_| Timestamp::now()).collect();
+    let log = clock::stop_recording();
+    assert_eq!(log.reads(), &recorded[..]);
+
+    let bytes = log.encode();
+    clock::start_replay(ClockLog::decode(&bytes).unwrap());
+    let replayed: Vec<_> = (0..100).map(|_| Timestamp::now()).collect();
+    assert!(clock::stop_replay().is_empty());
+    assert_eq!(replayed, recorded);
+
+    clock::start_replay(ClockLog::decode(&bytes).unwrap());
+    assert_eq!(Timestamp::now(), recorded[0]);
+    assert_eq!(clock::stop_replay().len(), 99);
+}